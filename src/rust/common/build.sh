@@ -0,0 +1,4 @@
+# shared helpers used by the templates and sources, there is nothing to package
+# usage: ./build.sh [-a]
+
+cargo +nightly build --release
@@ -0,0 +1 @@
+eval(function(p,a,c,k,e,d){e=function(c){return(c<a?"":e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1;};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p;}('0 b=3;0 c=4;0 d=e;0 f="/g/";0 h="5.6";i 7(){0 8="j://k.5.6/l/4/3";0 1=["/m.9","/n.9"];o(0 2=p;2<1.q;2++){1[2]=8+1[2]+"?r=3&s=t&u="}v 1}0 a;a=7();',62,32,'var|pvalue|i|210734|73|mangabz|com|dm5imagefun|pix|jpg|d|MANGABZ_CID|MANGABZ_MID|MANGABZ_IMAGE_COUNT|18|MANGABZ_CURL|m210734|MANGABZ_COOKIEDOMAIN|function|https|image|1|1_9871|2_3364|for|0|length|cid|key|8a7d3b|uk|return'.split('|'),0,{}))
@@ -0,0 +1 @@
+var MANGABZ_CID=210734;var MANGABZ_MID=73;var MANGABZ_IMAGE_COUNT=18;var MANGABZ_CURL="/m210734/";var MANGABZ_COOKIEDOMAIN="mangabz.com";function dm5imagefun(){var pix="https://image.mangabz.com/1/73/210734";var pvalue=["/1_9871.jpg","/2_3364.jpg"];for(var i=0;i<pvalue.length;i++){pvalue[i]=pix+pvalue[i]+"?cid=210734&key=8a7d3b&uk="}return pvalue}var d;d=dm5imagefun();
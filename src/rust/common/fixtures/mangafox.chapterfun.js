@@ -0,0 +1 @@
+eval(function(p,a,c,k,e,d){e=function(c){return(c<a?"":e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1;};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p;}('c 3(){2 4="//d.e.f/g/h/i/j-k.5/l";2 0=["/m.6?7=8&9=a","/n.6?7=8&9=a"];o(2 1=5;1<0.p;1++){0[1]=4+0[1]}q 0}2 b;b=3();',62,27,'pvalue|i|var|dm5imagefun|pix|0|jpg|token|8c2c1e0d5b4ad3e5a2b1f0c8e4d6a9b7|ttl|1760900000|d|function|zjcdn|mangafox|me|store|manga|11362|01|001|compressed|i001|i002|for|length|return'.split('|'),0,{}))
@@ -0,0 +1 @@
+window["\x65\x76\x61\x6c"](function(p,a,c,k,e,d){e=function(c){return(c<a?"":e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1;};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p;}('4.5({"6":2,"7":"進擊的巨人","8":"2.0","9":a,"b":"第3回","c":["d.0.1","e.0.1","f.0.1"],"g":h,"i":j,"k":"/l/m/n/第3回/","o":p,"q":"","r":s,"t":u,"v":{"w":x,"y":"z"}}).A();',62,37,'FYBw5gPg7gpgRiCBGAnAFgMwAYJaRAZQFkAJCASwFswARAQwBc6I5yATFgOzsphZHIBjCIPYQATGgAcAVgDsANhHdeEAGbkANjADOuPPvH6M68p3I6AFjA4MATgFc+2zhBMhGliCB0ngEYGA2YDsIHSYGBz18OE0Ae0EAawB9QWFOGAAPBgBJDklZOTlvOxgANzzcMM0IPiQ5LAwUGXEsNohKCESwHKwaByIATxQ6ADECOLoANQBFOwBVTXFBSBBSnPMGIA='['\x73\x70\x6c\x69\x63']('\x7c'),0,{}))
@@ -0,0 +1 @@
+SMH.imgData({"bid":19430,"bname":"進擊的巨人","bpic":"19430.jpg","cid":248576,"cname":"第01回","files":["001.jpg.webp","002.jpg.webp","003.jpg.webp"],"finished":true,"len":3,"path":"/ps3/j/jjdjr/第01回/","status":1,"block_cc":"","nextId":248577,"prevId":0,"sl":{"e":1703952000,"m":"kgI0DuMy9aFSoaVQrUl2cg"}}).preInit();
@@ -0,0 +1,78 @@
+//! Recognition of anti-bot challenge pages.
+//!
+//! Cloudflare and DDoS-Guard answer with an interstitial page instead of the
+//! content until a browser solves it. Parsing that page only produces empty
+//! lists or confusing parse errors, so the request helpers in [`crate::net`]
+//! stop early with [`challenge_error`] instead.
+use aidoku::{error::AidokuError, std::net::Request};
+
+use crate::error::SourceError;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Challenge {
+	Cloudflare,
+	DdosGuard,
+}
+
+const CLOUDFLARE_MARKERS: [&str; 5] = [
+	"<title>Just a moment",
+	"cf-browser-verification",
+	"/cdn-cgi/challenge-platform/",
+	"window._cf_chl_opt",
+	"Attention Required! | Cloudflare",
+];
+const DDOS_GUARD_MARKERS: [&str; 3] = ["DDoS-Guard", "ddos-guard.net", "/.well-known/ddos-guard/"];
+
+/// The error returned for a challenge page, a [`SourceError::Challenge`].
+pub fn challenge_error(challenge: Challenge) -> AidokuError {
+	SourceError::Challenge(challenge).into()
+}
+
+/// Whether the status code is one challenge pages are served with.
+pub fn is_challenge_status(status: i32) -> bool {
+	matches!(status, 403 | 429 | 503)
+}
+
+/// Checks the headers of a sent request.
+pub fn detect_from_headers(request: &Request) -> Option<Challenge> {
+	let header = |name: &str| {
+		request
+			.get_header(name)
+			.map(|v| v.read().to_ascii_lowercase())
+			.unwrap_or_default()
+	};
+
+	if header("cf-mitigated") == "challenge" {
+		return Some(Challenge::Cloudflare);
+	}
+	if !is_challenge_status(request.status_code()) {
+		return None;
+	}
+	match header("server").as_str() {
+		"ddos-guard" => Some(Challenge::DdosGuard),
+		_ => None,
+	}
+}
+
+fn contains(haystack: &[u8], needle: &str) -> bool {
+	haystack
+		.windows(needle.len())
+		.any(|window| window == needle.as_bytes())
+}
+
+/// Checks the body of a response for the markers of a challenge page.
+pub fn detect_from_body(body: &[u8]) -> Option<Challenge> {
+	if CLOUDFLARE_MARKERS
+		.iter()
+		.any(|marker| contains(body, marker))
+	{
+		Some(Challenge::Cloudflare)
+	} else if DDOS_GUARD_MARKERS
+		.iter()
+		.any(|marker| contains(body, marker))
+	{
+		Some(Challenge::DdosGuard)
+	} else {
+		None
+	}
+}
@@ -0,0 +1,282 @@
+//! Extraction of the volume and chapter numbers from chapter names like
+//! "Vol.2 Ch.10.5 - The End", "Chapter 12-13", "第12话", "Глава 5" or "Tập 3".
+//!
+//! A number is read as a volume or a chapter from the keyword right before it
+//! ("Vol.", "Chapter", "Глава", ...) or the counter right after it ("卷",
+//! "话", "화", ...). When no number has a chapter keyword, the first one
+//! standing on its own is the chapter, so "Manga Title 12" is chapter 12 but
+//! "S2" and "2nd" are left alone. What remains once the numbers and their
+//! keywords are taken out is the title.
+use aidoku::std::{String, Vec};
+
+/// Keywords before a volume number, lowercase.
+const VOLUME_WORDS: &[&str] = &[
+	"volume", "volumen", "vol", "v", "tome", "tomo", "band", "cilt", "quyển", "том", "tom",
+];
+
+/// Keywords before a chapter number, lowercase.
+const CHAPTER_WORDS: &[&str] = &[
+	"chapter",
+	"chap",
+	"ch",
+	"chapitre",
+	"capítulo",
+	"capitulo",
+	"cap",
+	"capitolo",
+	"kapitel",
+	"bölüm",
+	"rozdział",
+	"episode",
+	"ep",
+	"chương",
+	"chuong",
+	"tập",
+	"tap",
+	"hồi",
+	"глава",
+	"гл",
+	"ตอน",
+];
+
+/// Counters after a volume number, as in "第2卷" or "2권".
+const VOLUME_COUNTERS: &[char] = &['卷', '巻', '册', '冊', '권'];
+
+/// Counters after a chapter number, as in "第12话" or "12화".
+const CHAPTER_COUNTERS: &[char] = &['话', '話', '回', '章', '集', '화'];
+
+/// Ordinal prefixes before a counted number, as in "第12话" or "제12화".
+const ORDINALS: &[char] = &['第', '제'];
+
+/// Characters between the numbers of a range like "12-13".
+const RANGE_SEPARATORS: &[char] = &['-', '–', '—', '~', '〜'];
+
+/// Characters trimmed from the ends of the title.
+const TITLE_SEPARATORS: &[char] = &[
+	' ', ':', '：', '-', '–', '—', '.', ',', '|', '~', '#', '(', ')', '[', ']', '（', '）',
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedChapter {
+	/// The volume, -1 without one.
+	pub volume: f32,
+	/// The chapter, or the first one of a range, -1 without one.
+	pub chapter: f32,
+	/// The last chapter of a range like "Chapter 12-13".
+	pub chapter_end: Option<f32>,
+	/// The name without the numbers, which may be empty.
+	pub title: String,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+	Volume,
+	Chapter,
+	Unlabeled,
+}
+
+struct Number {
+	kind: Kind,
+	value: f32,
+	end_value: Option<f32>,
+	/// Byte range of the number with its keyword or counter.
+	span: (usize, usize),
+	/// Whether the number is stuck to a word that isn't a keyword.
+	glued: bool,
+}
+
+fn is_separator(c: char) -> bool {
+	c.is_whitespace() || matches!(c, '.' | ':' | '#' | '_' | '-')
+}
+
+/// Reads the number at `start`, returning its value and end.
+fn read_number(text: &str, start: usize) -> Option<(f32, usize)> {
+	let bytes = text.as_bytes();
+	let mut end = start;
+	while end < bytes.len() && bytes[end].is_ascii_digit() {
+		end += 1;
+	}
+	if end == start {
+		return None;
+	}
+	if end + 1 < bytes.len()
+		&& (bytes[end] == b'.' || bytes[end] == b',')
+		&& bytes[end + 1].is_ascii_digit()
+	{
+		end += 1;
+		while end < bytes.len() && bytes[end].is_ascii_digit() {
+			end += 1;
+		}
+	}
+	let value = text[start..end].replace(',', ".").parse::<f32>().ok()?;
+	Some((value, end))
+}
+
+/// The keyword right before `start` and where it begins.
+fn keyword_before(text: &str, start: usize) -> Option<(Kind, usize)> {
+	let before = text[..start].trim_end_matches(is_separator);
+	let word_start = before
+		.char_indices()
+		.rev()
+		.take_while(|(_, c)| c.is_alphabetic())
+		.last()
+		.map(|(idx, _)| idx)?;
+	let word = before[word_start..].to_lowercase();
+	if VOLUME_WORDS.contains(&word.as_str()) {
+		Some((Kind::Volume, word_start))
+	} else if CHAPTER_WORDS.contains(&word.as_str()) {
+		Some((Kind::Chapter, word_start))
+	} else {
+		None
+	}
+}
+
+/// The end of a range like "-13" following a number, with its last number.
+fn range_after(text: &str, end: usize, value: f32) -> Option<(f32, usize)> {
+	let rest = &text[end..];
+	let after_space = rest.trim_start();
+	let separator = after_space.chars().next()?;
+	if !RANGE_SEPARATORS.contains(&separator) {
+		return None;
+	}
+	let after_separator = after_space[separator.len_utf8()..].trim_start();
+	let start = text.len() - after_separator.len();
+	let (end_value, range_end) = read_number(text, start)?;
+	let next = text[range_end..].chars().next();
+	if end_value <= value
+		|| next.is_some_and(|c| c.is_alphabetic() && !CHAPTER_COUNTERS.contains(&c))
+	{
+		return None;
+	}
+	Some((end_value, range_end))
+}
+
+fn find_numbers(text: &str) -> Vec<Number> {
+	let bytes = text.as_bytes();
+	let mut numbers = Vec::new();
+	let mut idx = 0;
+	while idx < bytes.len() {
+		if !bytes[idx].is_ascii_digit() {
+			idx += 1;
+			continue;
+		}
+		let start = idx;
+		let Some((value, mut end)) = read_number(text, start) else {
+			idx += 1;
+			continue;
+		};
+
+		let mut kind = Kind::Unlabeled;
+		let mut span_start = start;
+		if let Some((keyword, keyword_start)) = keyword_before(text, start) {
+			kind = keyword;
+			span_start = keyword_start;
+		} else if text[..start].ends_with('#') {
+			kind = Kind::Chapter;
+			span_start = start - 1;
+		}
+
+		let mut end_value = None;
+		if kind != Kind::Volume {
+			if let Some((range_value, range_end)) = range_after(text, end, value) {
+				end_value = Some(range_value);
+				end = range_end;
+			}
+		}
+
+		// counters, with the ordinal before the number
+		let after = text[end..].trim_start();
+		if let Some(counter) = after.chars().next() {
+			let counter_kind = if VOLUME_COUNTERS.contains(&counter) {
+				Some(Kind::Volume)
+			} else if CHAPTER_COUNTERS.contains(&counter) {
+				Some(Kind::Chapter)
+			} else {
+				None
+			};
+			if let Some(counter_kind) = counter_kind {
+				kind = counter_kind;
+				end = text.len() - after.len() + counter.len_utf8();
+				let before = text[..span_start].trim_end();
+				if let Some(ordinal) = before.chars().next_back().filter(|c| ORDINALS.contains(c)) {
+					span_start = before.len() - ordinal.len_utf8();
+				}
+			}
+		}
+		if kind == Kind::Volume {
+			end_value = None;
+		}
+
+		let glued = kind == Kind::Unlabeled
+			&& (text[..start]
+				.chars()
+				.next_back()
+				.is_some_and(char::is_alphabetic)
+				|| text[end..].chars().next().is_some_and(char::is_alphabetic));
+
+		numbers.push(Number {
+			kind,
+			value,
+			end_value,
+			span: (span_start, end),
+			glued,
+		});
+		idx = end;
+	}
+	numbers
+}
+
+fn clean_title(text: &str, spans: &[(usize, usize)]) -> String {
+	let mut title = String::with_capacity(text.len());
+	let mut last = 0;
+	for &(start, end) in spans {
+		if start < last {
+			continue;
+		}
+		title.push_str(&text[last..start]);
+		title.push(' ');
+		last = end;
+	}
+	title.push_str(&text[last..]);
+
+	let mut title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+	for empty in ["()", "[]", "（）", "( )", "[ ]"] {
+		title = title.replace(empty, "");
+	}
+	String::from(title.trim_matches(TITLE_SEPARATORS))
+}
+
+/// Parses a chapter name, after taking `manga_title` out of it if it isn't
+/// empty.
+pub fn parse_chapter(text: &str, manga_title: &str) -> ParsedChapter {
+	let text = if manga_title.is_empty() {
+		String::from(text)
+	} else {
+		text.replace(manga_title, " ")
+	};
+	let numbers = find_numbers(&text);
+
+	let volume = numbers.iter().find(|number| number.kind == Kind::Volume);
+	let chapter = numbers
+		.iter()
+		.find(|number| number.kind == Kind::Chapter)
+		.or_else(|| {
+			numbers
+				.iter()
+				.find(|number| number.kind == Kind::Unlabeled && !number.glued)
+		});
+
+	let mut spans = volume
+		.iter()
+		.chain(chapter.iter())
+		.map(|number| number.span)
+		.collect::<Vec<_>>();
+	spans.sort_unstable();
+
+	ParsedChapter {
+		volume: volume.map_or(-1.0, |number| number.value),
+		chapter: chapter.map_or(-1.0, |number| number.value),
+		chapter_end: chapter.and_then(|number| number.end_value),
+		title: clean_title(&text, &spans),
+	}
+}
@@ -0,0 +1,263 @@
+//! Picks the viewer and content rating of a series from what its site says
+//! about it.
+//!
+//! A site may give an explicit type ("Manhwa", "Манга", "VnComic"), genre tags,
+//! the language the series was first published in, or nothing at all, so the
+//! hints are tried from the most to the least specific:
+//!
+//! 1. the type string, matched by containment, so "Manhwa (Korean)" counts;
+//! 2. the tags, matched as a whole;
+//! 3. the original language, `ko` and `zh` being read top to bottom and `ja`
+//!    right to left;
+//! 4. the language of the site, for Korean sites;
+//! 5. the default viewer of the classifier.
+//!
+//! When several hints of the same step disagree, scrolling beats left to right,
+//! which beats right to left, as manga tags are often left on webtoons.
+//!
+//! The rating is the highest one found in the type string and the tags, and
+//! never lower than the default rating of the classifier.
+//!
+//! Keywords are compared lowercased with everything but letters, digits and
+//! `+` removed, so "Long Strip", "long_strip" and "LongStrip" are the same.
+use aidoku::{
+	std::{String, Vec},
+	MangaContentRating, MangaViewer,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Hint {
+	Rtl,
+	Ltr,
+	Scroll,
+	Safe,
+	Suggestive,
+	Nsfw,
+}
+
+impl Hint {
+	fn viewer_rank(self) -> u8 {
+		match self {
+			Hint::Scroll => 3,
+			Hint::Ltr => 2,
+			Hint::Rtl => 1,
+			_ => 0,
+		}
+	}
+
+	fn rating_rank(self) -> u8 {
+		match self {
+			Hint::Nsfw => 3,
+			Hint::Suggestive => 2,
+			Hint::Safe => 1,
+			_ => 0,
+		}
+	}
+
+	pub fn from_viewer(viewer: &MangaViewer) -> Self {
+		match viewer {
+			MangaViewer::Ltr => Hint::Ltr,
+			MangaViewer::Scroll | MangaViewer::Vertical => Hint::Scroll,
+			_ => Hint::Rtl,
+		}
+	}
+
+	pub fn from_rating(rating: &MangaContentRating) -> Self {
+		match rating {
+			MangaContentRating::Nsfw => Hint::Nsfw,
+			MangaContentRating::Suggestive => Hint::Suggestive,
+			_ => Hint::Safe,
+		}
+	}
+
+	fn viewer(self) -> MangaViewer {
+		match self {
+			Hint::Ltr => MangaViewer::Ltr,
+			Hint::Scroll => MangaViewer::Scroll,
+			_ => MangaViewer::Rtl,
+		}
+	}
+
+	fn rating(self) -> MangaContentRating {
+		match self {
+			Hint::Nsfw => MangaContentRating::Nsfw,
+			Hint::Suggestive => MangaContentRating::Suggestive,
+			_ => MangaContentRating::Safe,
+		}
+	}
+}
+
+/// Built-in keywords, already normalised.
+const KEYWORDS: &[(&str, Hint)] = &[
+	// read top to bottom
+	("manhwa", Hint::Scroll),
+	("manhua", Hint::Scroll),
+	("webtoon", Hint::Scroll),
+	("webcomic", Hint::Scroll),
+	("longstrip", Hint::Scroll),
+	("vertical", Hint::Scroll),
+	("korean", Hint::Scroll),
+	("chinese", Hint::Scroll),
+	("манхва", Hint::Scroll),
+	("маньхуа", Hint::Scroll),
+	("маньхуя", Hint::Scroll),
+	("вебтун", Hint::Scroll),
+	("韩漫", Hint::Scroll),
+	("韓漫", Hint::Scroll),
+	("条漫", Hint::Scroll),
+	("條漫", Hint::Scroll),
+	("웹툰", Hint::Scroll),
+	// read left to right
+	("comic", Hint::Ltr),
+	("western", Hint::Ltr),
+	("vncomic", Hint::Ltr),
+	("novel", Hint::Ltr),
+	("oel", Hint::Ltr),
+	("комикс", Hint::Ltr),
+	("руманга", Hint::Ltr),
+	// read right to left
+	("manga", Hint::Rtl),
+	("japan", Hint::Rtl),
+	("japanese", Hint::Rtl),
+	("манга", Hint::Rtl),
+	("日漫", Hint::Rtl),
+	// not safe for work
+	("adult", Hint::Nsfw),
+	("mature", Hint::Nsfw),
+	("smut", Hint::Nsfw),
+	("hentai", Hint::Nsfw),
+	("doujinshi", Hint::Nsfw),
+	("doujin", Hint::Nsfw),
+	("porn", Hint::Nsfw),
+	("18+", Hint::Nsfw),
+	("r18", Hint::Nsfw),
+	("секс", Hint::Nsfw),
+	("18плюс", Hint::Nsfw),
+	("хентай", Hint::Nsfw),
+	("成人", Hint::Nsfw),
+	("同人", Hint::Nsfw),
+	// suggestive
+	("ecchi", Hint::Suggestive),
+	("16+", Hint::Suggestive),
+	("эротика", Hint::Suggestive),
+	("этти", Hint::Suggestive),
+];
+
+fn normalize(text: &str) -> String {
+	text.chars()
+		.filter(|c| c.is_alphanumeric() || *c == '+')
+		.flat_map(char::to_lowercase)
+		.collect()
+}
+
+#[derive(Clone, Copy)]
+pub struct Classifier<'a> {
+	/// Language code of the site.
+	pub lang: &'a str,
+	/// Keywords of the source, checked before the built-in ones. A keyword
+	/// mapped to [`Hint::Safe`] stops a tag from raising the rating.
+	pub overrides: &'a [(&'a str, Hint)],
+	/// The viewer when no hint decides.
+	pub viewer: Hint,
+	/// The lowest rating of every series of the site.
+	pub rating: Hint,
+}
+
+impl<'a> Classifier<'a> {
+	pub const fn new(lang: &'a str) -> Self {
+		Self {
+			lang,
+			overrides: &[],
+			viewer: Hint::Rtl,
+			rating: Hint::Safe,
+		}
+	}
+
+	pub const fn with_overrides(self, overrides: &'a [(&'a str, Hint)]) -> Self {
+		Self { overrides, ..self }
+	}
+
+	pub const fn with_viewer(self, viewer: Hint) -> Self {
+		Self { viewer, ..self }
+	}
+
+	pub const fn with_rating(self, rating: Hint) -> Self {
+		Self { rating, ..self }
+	}
+
+	/// The hints `text` carries. Overrides of the source replace the
+	/// built-in keywords when any of them matches.
+	fn hints(&self, text: &str, contains: bool) -> Vec<Hint> {
+		let text = normalize(text);
+		if text.is_empty() {
+			return Vec::new();
+		}
+		let matches = |keyword: &str| {
+			!keyword.is_empty()
+				&& if contains {
+					text.contains(keyword)
+				} else {
+					text == keyword
+				}
+		};
+		let overridden: Vec<Hint> = self
+			.overrides
+			.iter()
+			.filter(|(keyword, _)| matches(&normalize(keyword)))
+			.map(|&(_, hint)| hint)
+			.collect();
+		if !overridden.is_empty() {
+			return overridden;
+		}
+		KEYWORDS
+			.iter()
+			.filter(|(keyword, _)| matches(keyword))
+			.map(|&(_, hint)| hint)
+			.collect()
+	}
+
+	fn language_viewer(lang: &str) -> Option<Hint> {
+		let lang = lang.trim().to_lowercase();
+		match lang.split(['-', '_']).next().unwrap_or_default() {
+			"" => None,
+			"ko" | "zh" => Some(Hint::Scroll),
+			"ja" => Some(Hint::Rtl),
+			_ => Some(Hint::Ltr),
+		}
+	}
+
+	/// Classifies a series from its tags, its type string and the language
+	/// it was first published in, either of which may be empty.
+	pub fn classify(
+		&self,
+		tags: &[String],
+		series_type: &str,
+		original_lang: &str,
+	) -> (MangaContentRating, MangaViewer) {
+		let type_hints = self.hints(series_type, true);
+		let tag_hints: Vec<Hint> = tags.iter().flat_map(|tag| self.hints(tag, false)).collect();
+
+		let best_viewer = |hints: &[Hint]| {
+			hints
+				.iter()
+				.copied()
+				.filter(|hint| hint.viewer_rank() > 0)
+				.max_by_key(|hint| hint.viewer_rank())
+		};
+		let viewer = best_viewer(&type_hints)
+			.or_else(|| best_viewer(&tag_hints))
+			.or_else(|| Self::language_viewer(original_lang))
+			.or_else(|| self.lang.starts_with("ko").then_some(Hint::Scroll))
+			.unwrap_or(self.viewer);
+
+		let rating = type_hints
+			.iter()
+			.chain(tag_hints.iter())
+			.copied()
+			.chain(core::iter::once(self.rating))
+			.max_by_key(|hint| hint.rating_rank())
+			.unwrap_or(self.rating);
+
+		(rating.rating(), viewer.viewer())
+	}
+}
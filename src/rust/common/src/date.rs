@@ -0,0 +1,702 @@
+//! Parsing of the chapter dates sites show, relative ("3 giờ trước",
+//! "2 days ago", "hace 1 semana", "вчера", "昨天", "il y a 2 jours") as well as
+//! absolute ones in the formats of their locale.
+//!
+//! Relative dates need a unit and a word for "ago" in any of the supported
+//! languages, so that an absolute date is never mistaken for one. A missing
+//! number counts as one, months as 30 days and years as 365.
+use aidoku::{
+	prelude::format,
+	std::{current_date, String, StringRef, Vec},
+};
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const WEEK: f64 = 7.0 * DAY;
+const MONTH: f64 = 30.0 * DAY;
+const YEAR: f64 = 365.0 * DAY;
+
+/// Words that stand for a day relative to today, with how many days ago it
+/// is. Longer words come first where one contains another.
+const DAYS: &[(&str, f64)] = &[
+	("day before yesterday", 2.0),
+	("anteayer", 2.0),
+	("antes de ayer", 2.0),
+	("avant-hier", 2.0),
+	("anteontem", 2.0),
+	("позавчера", 2.0),
+	("vorgestern", 2.0),
+	("l'altro ieri", 2.0),
+	("hôm kia", 2.0),
+	("前天", 2.0),
+	("一昨日", 2.0),
+	("おととい", 2.0),
+	("그저께", 2.0),
+	("yesterday", 1.0),
+	("ayer", 1.0),
+	("hier", 1.0),
+	("ontem", 1.0),
+	("вчера", 1.0),
+	("gestern", 1.0),
+	("ieri", 1.0),
+	("hôm qua", 1.0),
+	("kemarin", 1.0),
+	("dün", 1.0),
+	("昨天", 1.0),
+	("昨日", 1.0),
+	("어제", 1.0),
+	("today", 0.0),
+	("hoy", 0.0),
+	("aujourd'hui", 0.0),
+	("hoje", 0.0),
+	("сегодня", 0.0),
+	("heute", 0.0),
+	("oggi", 0.0),
+	("hôm nay", 0.0),
+	("hari ini", 0.0),
+	("bugün", 0.0),
+	("今天", 0.0),
+	("今日", 0.0),
+	("오늘", 0.0),
+	("just now", 0.0),
+	("vừa xong", 0.0),
+	("только что", 0.0),
+	("à l'instant", 0.0),
+	("ahora mismo", 0.0),
+	("agora mesmo", 0.0),
+	("baru saja", 0.0),
+	("刚刚", 0.0),
+	("剛剛", 0.0),
+	("たった今", 0.0),
+	("방금", 0.0),
+];
+
+/// Words for "ago".
+const AGO: &[&str] = &[
+	"ago",
+	"trước",
+	"đây",
+	"назад",
+	"hace",
+	"atrás",
+	"há",
+	"lalu",
+	"vor",
+	"fa",
+	"önce",
+];
+
+/// Phrases for "ago" that are more than one word or not separated by spaces.
+const AGO_PHRASES: &[&str] = &["il y a", "前", "전"];
+
+/// Beginnings of the words for each unit, in languages that separate words
+/// with spaces.
+const UNITS: &[(&str, f64)] = &[
+	("second", 1.0),
+	("sec", 1.0),
+	("segundo", 1.0),
+	("giây", 1.0),
+	("секунд", 1.0),
+	("detik", 1.0),
+	("sekunde", 1.0),
+	("saniye", 1.0),
+	// before "min"
+	("minggu", WEEK),
+	("min", MINUTE),
+	("phút", MINUTE),
+	("минут", MINUTE),
+	("menit", MINUTE),
+	("dakika", MINUTE),
+	("hour", HOUR),
+	("hr", HOUR),
+	("hora", HOUR),
+	("heure", HOUR),
+	("giờ", HOUR),
+	("tiếng", HOUR),
+	("час", HOUR),
+	("jam", HOUR),
+	("stunde", HOUR),
+	("ora", HOUR),
+	("ore", HOUR),
+	("saat", HOUR),
+	("day", DAY),
+	("día", DAY),
+	("dia", DAY),
+	("jour", DAY),
+	("ngày", DAY),
+	("день", DAY),
+	("дн", DAY),
+	("hari", DAY),
+	("tag", DAY),
+	("giorn", DAY),
+	("gün", DAY),
+	("week", WEEK),
+	("semana", WEEK),
+	("semaine", WEEK),
+	("tuần", WEEK),
+	("недел", WEEK),
+	("woche", WEEK),
+	("settiman", WEEK),
+	("hafta", WEEK),
+	("month", MONTH),
+	("mes", MONTH),
+	("mês", MONTH),
+	("mois", MONTH),
+	("tháng", MONTH),
+	("месяц", MONTH),
+	("bulan", MONTH),
+	("monat", MONTH),
+	("year", YEAR),
+	("año", YEAR),
+	("ano", YEAR),
+	("année", YEAR),
+	("năm", YEAR),
+	("год", YEAR),
+	("лет", YEAR),
+	("tahun", YEAR),
+	("jahr", YEAR),
+	("anno", YEAR),
+	("anni", YEAR),
+	("yıl", YEAR),
+];
+
+/// Abbreviations, matched as whole words.
+const UNIT_ABBREVIATIONS: &[(&str, f64)] = &[
+	("s", 1.0),
+	("m", MINUTE),
+	("h", HOUR),
+	("d", DAY),
+	("w", WEEK),
+	("wk", WEEK),
+	("wks", WEEK),
+	("mo", MONTH),
+	("y", YEAR),
+	("yr", YEAR),
+	("yrs", YEAR),
+];
+
+/// Units in languages that don't separate words, matched anywhere in a word.
+/// Longer units come first where one contains another.
+const CJK_UNITS: &[(&str, f64)] = &[
+	("秒", 1.0),
+	("초", 1.0),
+	("分", MINUTE),
+	("분", MINUTE),
+	("小时", HOUR),
+	("小時", HOUR),
+	("時間", HOUR),
+	("时", HOUR),
+	("시간", HOUR),
+	("星期", WEEK),
+	("周", WEEK),
+	("週", WEEK),
+	("주", WEEK),
+	("天", DAY),
+	("日", DAY),
+	("일", DAY),
+	("月", MONTH),
+	("개월", MONTH),
+	("달", MONTH),
+	("年", YEAR),
+	("년", YEAR),
+];
+
+const CJK_NUMBERS: &[(char, f64)] = &[
+	('一', 1.0),
+	('两', 2.0),
+	('兩', 2.0),
+	('二', 2.0),
+	('三', 3.0),
+	('四', 4.0),
+	('五', 5.0),
+	('六', 6.0),
+	('七', 7.0),
+	('八', 8.0),
+	('九', 9.0),
+	('十', 10.0),
+	('半', 0.5),
+];
+
+/// Splits `text` into words, also between digits and letters so that "3小时前"
+/// and "5m" are a number followed by a word.
+fn words(text: &str) -> Vec<String> {
+	let mut words: Vec<String> = Vec::new();
+	let mut last_digit = None;
+	for c in text.chars() {
+		if c.is_whitespace() || (c.is_ascii_punctuation() && c != '.' && c != '\'') {
+			last_digit = None;
+			continue;
+		}
+		let digit = c.is_ascii_digit() || (c == '.' && last_digit == Some(true));
+		match words.last_mut() {
+			Some(word) if last_digit == Some(digit) => word.push(c),
+			_ => words.push(String::from(c)),
+		}
+		last_digit = Some(digit);
+	}
+	words
+}
+
+fn unit_of(word: &str, after_number: bool) -> Option<f64> {
+	// "an" is a year in French, but "an hour" in English
+	if after_number && (word == "an" || word == "ans") {
+		return Some(YEAR);
+	}
+	UNIT_ABBREVIATIONS
+		.iter()
+		.filter(|_| after_number)
+		.find(|(abbreviation, _)| word == *abbreviation)
+		.or_else(|| UNITS.iter().find(|(unit, _)| word.starts_with(unit)))
+		.or_else(|| CJK_UNITS.iter().find(|(unit, _)| word.contains(unit)))
+		.map(|&(_, seconds)| seconds)
+}
+
+fn cjk_number(word: &str) -> Option<f64> {
+	let first = word.chars().next()?;
+	CJK_NUMBERS
+		.iter()
+		.find(|(c, _)| *c == first)
+		.map(|&(_, n)| n)
+}
+
+fn parse_day(text: &str, now: f64) -> Option<f64> {
+	DAYS.iter()
+		.find(|(word, _)| text.contains(word))
+		.map(|(_, days)| now - days * DAY)
+}
+
+/// Parses a relative date, returning its timestamp counted back from `now`.
+pub fn parse_relative(text: &str, now: f64) -> Option<f64> {
+	let text = text.trim().to_lowercase();
+	if text.is_empty() {
+		return None;
+	}
+
+	// "前天" has the "ago" of "3天前" in it
+	let has_digit = text.chars().any(|c| c.is_ascii_digit());
+	if !has_digit {
+		if let Some(date) = parse_day(&text, now) {
+			return Some(date);
+		}
+	}
+
+	let words = words(&text);
+	let ago = words.iter().any(|word| AGO.contains(&word.as_str()))
+		|| AGO_PHRASES.iter().any(|phrase| text.contains(phrase));
+	if !ago {
+		// "yesterday 10:30"
+		return parse_day(&text, now);
+	}
+
+	let number_idx = words.iter().position(|word| word.parse::<f64>().is_ok());
+	let start = number_idx.map_or(0, |idx| idx + 1);
+	let (seconds, word) = words[start..].iter().enumerate().find_map(|(idx, word)| {
+		unit_of(word, number_idx.is_some() && idx == 0).map(|seconds| (seconds, word))
+	})?;
+	// "минуту назад" and "hace una semana" go without a number
+	let number = number_idx
+		.and_then(|idx| words[idx].parse::<f64>().ok())
+		.or_else(|| cjk_number(word))
+		.unwrap_or(1.0);
+
+	Some(now - number * seconds)
+}
+
+/// The year of a timestamp, in UTC.
+fn year_of(timestamp: f64) -> i64 {
+	// days to civil date, from Howard Hinnant's date algorithms
+	let days = (timestamp as i64).div_euclid(DAY as i64) + 719_468;
+	let era = days.div_euclid(146_097);
+	let doe = days.rem_euclid(146_097);
+	let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	yoe + era * 400 + i64::from(month <= 2)
+}
+
+/// Reads a date without a year as the latest one that isn't in the future,
+/// `parse` giving its timestamp in a given year.
+fn latest_before(now: f64, parse: impl Fn(i64) -> Option<f64>) -> Option<f64> {
+	let year = year_of(now);
+	let date = parse(year)?;
+	if date > now + DAY {
+		parse(year - 1)
+	} else {
+		Some(date)
+	}
+}
+
+/// Absolute date formats of a site, tried in order after relative dates.
+///
+/// Formats without a year, like `HH:mm dd/MM`, are read as the latest such
+/// date that isn't in the future.
+#[derive(Clone, Copy)]
+pub struct DateParser<'a> {
+	pub formats: &'a [&'a str],
+	pub locale: Option<&'a str>,
+	pub timezone: Option<&'a str>,
+}
+
+impl<'a> DateParser<'a> {
+	pub const fn new(formats: &'a [&'a str], locale: Option<&'a str>) -> Self {
+		Self {
+			formats,
+			locale,
+			timezone: None,
+		}
+	}
+
+	pub const fn with_timezone(self, timezone: &'a str) -> Self {
+		Self {
+			timezone: Some(timezone),
+			..self
+		}
+	}
+
+	/// The formats usually seen on sites in `lang`.
+	pub fn for_lang(lang: &str) -> DateParser<'static> {
+		match lang.split(['-', '_']).next().unwrap_or_default() {
+			"vi" => DateParser::new(
+				&["dd/MM/yyyy", "dd/MM/yy", "HH:mm dd/MM", "dd/MM"],
+				Some("en_US"),
+			)
+			.with_timezone("Asia/Ho_Chi_Minh"),
+			"es" => DateParser::new(
+				&[
+					"d 'de' MMMM 'de' yyyy",
+					"dd/MM/yyyy",
+					"MMMM d, yyyy",
+					"d MMMM, yyyy",
+					"yyyy-MM-dd",
+				],
+				Some("es_ES"),
+			),
+			"pt" => DateParser::new(
+				&["d 'de' MMMM 'de' yyyy", "dd/MM/yyyy", "MMMM d, yyyy"],
+				Some("pt_BR"),
+			),
+			"fr" => DateParser::new(
+				&["d MMMM yyyy", "dd MMM yyyy", "dd/MM/yyyy", "MMMM d, yyyy"],
+				Some("fr_FR"),
+			),
+			"it" => DateParser::new(
+				&["d MMMM yyyy", "dd/MM/yyyy", "MMMM d, yyyy"],
+				Some("it_IT"),
+			),
+			"de" => DateParser::new(
+				&["d. MMMM yyyy", "dd.MM.yyyy", "MMMM d, yyyy"],
+				Some("de_DE"),
+			),
+			"ru" => DateParser::new(&["dd.MM.yyyy", "d MMMM yyyy", "dd.MM.yy"], Some("ru_RU")),
+			"uk" => DateParser::new(&["dd.MM.yyyy", "d MMMM yyyy", "dd.MM.yy"], Some("uk_UA")),
+			"ar" => DateParser::new(&["MMMM d, yyyy", "dd/MM/yyyy"], Some("ar_EH")),
+			"tr" => DateParser::new(
+				&["d MMMM yyyy", "dd.MM.yyyy", "MMMM d, yyyy"],
+				Some("tr_TR"),
+			),
+			"id" => DateParser::new(
+				&["MMMM d, yyyy", "d MMMM yyyy", "dd/MM/yyyy"],
+				Some("id_ID"),
+			),
+			"zh" => DateParser::new(
+				&[
+					"yyyy-MM-dd HH:mm:ss",
+					"yyyy-MM-dd",
+					"yyyy/MM/dd",
+					"yyyy年M月d日",
+					"MM-dd",
+				],
+				Some("zh_CN"),
+			)
+			.with_timezone("Asia/Shanghai"),
+			"ja" => DateParser::new(
+				&[
+					"yyyy-MM-dd HH:mm:ss",
+					"yyyy/MM/dd",
+					"yyyy-MM-dd",
+					"yyyy年M月d日",
+					// sites on english themes
+					"MMMM d, yyyy",
+				],
+				Some("ja_JP"),
+			)
+			.with_timezone("Asia/Tokyo"),
+			"ko" => DateParser::new(&["yyyy.MM.dd", "yy.MM.dd", "yyyy-MM-dd"], Some("ko_KR"))
+				.with_timezone("Asia/Seoul"),
+			_ => DateParser::new(
+				&[
+					"MMM d, yyyy",
+					"MMMM d, yyyy",
+					"MM/dd/yyyy",
+					"yyyy-MM-dd",
+					"dd MMM yyyy",
+					"dd-MM-yyyy",
+				],
+				Some("en_US"),
+			),
+		}
+	}
+
+	fn parse_format(&self, text: &str, format: &str) -> Option<f64> {
+		StringRef::from(text)
+			.0
+			.as_date(format, self.locale, self.timezone)
+			.ok()
+			.filter(|&date| date > 0.0)
+	}
+
+	/// Parses `text` as a relative date, or else with the first format that
+	/// fits.
+	pub fn parse(&self, text: &str) -> Option<f64> {
+		let text = text.trim();
+		if text.is_empty() {
+			return None;
+		}
+		let now = current_date();
+		if let Some(date) = parse_relative(text, now) {
+			return Some(date);
+		}
+		self.formats.iter().find_map(|format| {
+			if format.contains('y') {
+				return self.parse_format(text, format);
+			}
+			let format = format!("{format} yyyy");
+			latest_before(now, |year| {
+				self.parse_format(&format!("{text} {year}"), &format)
+			})
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// 2023-11-14 22:13:20 UTC
+	const NOW: f64 = 1_700_000_000.0;
+
+	#[test]
+	fn parses_relative_dates() {
+		for (text, seconds_ago) in [
+			("3 giờ trước", 3.0 * HOUR),
+			("2 days ago", 2.0 * DAY),
+			("hace 1 semana", WEEK),
+			("вчера", DAY),
+			("昨天", DAY),
+			("3天前", 3.0 * DAY),
+			("il y a 2 jours", 2.0 * DAY),
+			("vor 2 Tagen", 2.0 * DAY),
+			("3日前", 3.0 * DAY),
+			("3일 전", 3.0 * DAY),
+			("минуту назад", MINUTE),
+			("5m ago", 5.0 * MINUTE),
+		] {
+			assert_eq!(parse_relative(text, NOW), Some(NOW - seconds_ago), "{text}");
+		}
+	}
+
+	#[test]
+	fn leaves_absolute_dates_alone() {
+		for text in [
+			"2023-11-01",
+			"01/11/2023",
+			"November 1, 2023",
+			"10:30 28/12",
+			"",
+		] {
+			assert_eq!(parse_relative(text, NOW), None, "{text}");
+		}
+	}
+
+	#[test]
+	fn reads_the_year_of_a_timestamp() {
+		assert_eq!(year_of(NOW), 2023);
+		assert_eq!(year_of(0.0), 1970);
+		// 2024-01-01 00:00:00 UTC and the second before it
+		assert_eq!(year_of(1_704_067_200.0), 2024);
+		assert_eq!(year_of(1_704_067_199.0), 2023);
+	}
+
+	/// Days since 1970-01-01 of a civil date, from Howard Hinnant's date
+	/// algorithms.
+	fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+		let year = if month <= 2 { year - 1 } else { year };
+		let era = year.div_euclid(400);
+		let yoe = year.rem_euclid(400);
+		let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+		let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+		era * 146_097 + doe - 719_468
+	}
+
+	/// Reads "HH:mm dd/MM" in UTC, standing in for the host.
+	fn parse_time_day_month(text: &str, year: i64) -> Option<f64> {
+		let (time, date) = text.split_once(' ')?;
+		let (hour, minute) = time.split_once(':')?;
+		let (day, month) = date.split_once('/')?;
+		let days = days_from_civil(year, month.parse().ok()?, day.parse().ok()?);
+		Some(
+			days as f64 * DAY
+				+ hour.parse::<f64>().ok()? * HOUR
+				+ minute.parse::<f64>().ok()? * MINUTE,
+		)
+	}
+
+	#[test]
+	fn rolls_yearless_dates_back() {
+		// 2024-01-05 12:00:00 UTC
+		let now = 1_704_456_000.0;
+		for (text, expected) in [
+			// earlier this year
+			("08:00 03/01", 1_704_268_800.0),
+			// later in the year, so last year
+			("10:30 28/12", 1_703_759_400.0),
+		] {
+			let date = latest_before(now, |year| parse_time_day_month(text, year));
+			assert_eq!(date, Some(expected), "{text}");
+		}
+	}
+
+	/// Whether `text` has the shape of `format`, standing in for the host,
+	/// which is what reads the dates. Month names are any word, so the
+	/// language of the text is up to the locale.
+	fn fits(text: &str, format: &str) -> bool {
+		let mut text = text;
+		let mut format = format;
+		while let Some(c) = format.chars().next() {
+			if c == '\'' {
+				let Some(end) = format[1..].find('\'') else {
+					return false;
+				};
+				let Some(rest) = text.strip_prefix(&format[1..end + 1]) else {
+					return false;
+				};
+				text = rest;
+				format = &format[end + 2..];
+				continue;
+			}
+			if !c.is_ascii_alphabetic() {
+				let Some(rest) = text.strip_prefix(c) else {
+					return false;
+				};
+				text = rest;
+				format = &format[c.len_utf8()..];
+				continue;
+			}
+			let len = format.find(|f| f != c).unwrap_or(format.len());
+			format = &format[len..];
+			let (min, max) = match (c, len) {
+				('y', 4) => (4, 4),
+				('M', 3) | ('M', 4) => {
+					let end = text
+						.find(|t: char| !t.is_alphabetic())
+						.unwrap_or(text.len());
+					if end == 0 {
+						return false;
+					}
+					// abbreviations may end with a dot, e.g. "oct."
+					text = text[end..].strip_prefix('.').unwrap_or(&text[end..]);
+					continue;
+				}
+				(_, 1) => (1, 2),
+				_ => (2, 2),
+			};
+			let digits = text
+				.find(|t: char| !t.is_ascii_digit())
+				.unwrap_or(text.len());
+			if digits < min || digits > max {
+				return false;
+			}
+			text = &text[digits..];
+		}
+		text.is_empty()
+	}
+
+	#[test]
+	fn fits_formats() {
+		assert!(fits("09 oct. 2024", "dd MMM yyyy"));
+		assert!(fits("9 de octubre de 2024", "d 'de' MMMM 'de' yyyy"));
+		assert!(!fits("2024-10-09", "dd/MM/yyyy"));
+		assert!(!fits("9/10/2024", "dd/MM/yyyy"));
+		assert!(!fits("October 9, 2024 10:00", "MMMM d, yyyy"));
+	}
+
+	/// Chapter dates as sources show them, in the formats they declare, with
+	/// the language they pass to [`DateParser::for_lang`].
+	const ABSOLUTE_DATES: &[(&str, &str)] = &[
+		// wpcomics sites, "14:30 12/05" within the year
+		("vi", "14:30 12/05"),
+		("vi", "12/05/24"),
+		("vi", "12/05/2024"),
+		// madara
+		("en", "October 9, 2024"),
+		("en", "Oct 9, 2024"),
+		// rizzfables
+		("en", "09 Oct 2024"),
+		// mangatx
+		("en", "09-10-2024"),
+		// kiryuu, tenshi
+		("id", "Oktober 9, 2024"),
+		// senpaiediciones
+		("es", "octubre 9, 2024"),
+		// tumangaonline
+		("es", "2024-10-09"),
+		// phenixscans
+		("fr", "09 oct. 2024"),
+		// walpurgiscan
+		("it", "ottobre 9, 2024"),
+		// aresmanga, ozulscans
+		("ar", "أكتوبر 9, 2024"),
+		// mangabuff
+		("ru", "09.10.2024"),
+		// mangainua
+		("uk", "09.10.2024"),
+		// boylove
+		("zh", "2024-10-09 12:30:00"),
+		// copymanga
+		("zh", "2024-10-09"),
+		// senmanga
+		("ja", "2024-10-09 12:30:00"),
+		// rawkuma
+		("ja", "October 9, 2024"),
+	];
+
+	/// Relative chapter dates as sources show them, with how long ago they
+	/// are.
+	const RELATIVE_DATES: &[(&str, &str, f64)] = &[
+		// wpcomics sites
+		("vi", "3 giờ trước", 3.0 * HOUR),
+		("vi", "2 ngày trước", 2.0 * DAY),
+		// madara
+		("en", "2 hours ago", 2.0 * HOUR),
+		("en", "5 mins ago", 5.0 * MINUTE),
+		// mangastream
+		("id", "2 jam yang lalu", 2.0 * HOUR),
+		("es", "hace 2 días", 2.0 * DAY),
+		("fr", "il y a 2 jours", 2.0 * DAY),
+		("it", "2 giorni fa", 2.0 * DAY),
+		("ru", "2 дня назад", 2.0 * DAY),
+		("zh", "3天前", 3.0 * DAY),
+		("zh", "昨天", DAY),
+		("ja", "3日前", 3.0 * DAY),
+		("ko", "3일 전", 3.0 * DAY),
+	];
+
+	#[test]
+	fn reads_the_dates_of_each_language() {
+		for &(lang, text) in ABSOLUTE_DATES {
+			let parser = DateParser::for_lang(lang);
+			assert_eq!(parse_relative(text, NOW), None, "{lang}: {text}");
+			assert!(
+				parser.formats.iter().any(|format| fits(text, format)),
+				"{lang}: {text}"
+			);
+		}
+		for &(lang, text, seconds_ago) in RELATIVE_DATES {
+			// the host stand-in is at the epoch
+			let date = DateParser::for_lang(lang).parse(text);
+			assert_eq!(date, Some(-seconds_ago), "{lang}: {text}");
+		}
+	}
+}
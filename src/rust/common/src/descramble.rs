@@ -0,0 +1,388 @@
+//! Descriptions of how scrambled page images are put back together.
+//!
+//! Sources can't edit images themselves, so they describe the scrambling and
+//! the app does the work. The description travels in `Page.base64` as JSON,
+//! produced by [`Scramble::serialize`].
+//!
+//! Only the formats the app knows how to apply are described:
+//!
+//! - [`Scramble::Rows`]: horizontal strips of the full image width, each moved
+//!   to another height. This serializes to the array of pieces the app has
+//!   always understood, so existing sources keep working as they are.
+//! - [`Scramble::Tiles`]: the image is cut into a grid and the tiles are
+//!   shuffled following a permutation. The grid is expanded into the same
+//!   pieces, so it needs the size of the image, which sites using this
+//!   scrambling give next to the image url.
+//!
+//! Images XOR-ed with a key have no geometry the pieces could describe, so
+//! the app can't put them back together and they are not described here.
+use alloc::vec;
+
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, Result},
+	prelude::*,
+	std::{String, Vec},
+};
+
+/// A strip of the scrambled image. Strips are laid out from the top of the
+/// scrambled image in order, `dy` is where the strip goes in the result.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RowBlock {
+	pub dy: i32,
+	pub height: i32,
+}
+
+/// A rectangle copied from `(sx, sy)` of the scrambled image to `(dx, dy)` of
+/// the result.
+///
+/// A `width` of 0 stands for the full image width, and an `sy` of -1 for the
+/// bottom of the previous piece.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Piece {
+	pub sx: i32,
+	pub sy: i32,
+	pub dx: i32,
+	pub dy: i32,
+	pub width: i32,
+	pub height: i32,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Scramble {
+	Rows(Vec<RowBlock>),
+	/// A grid of `cols` by `rows` tiles over an image of `width` by `height`.
+	/// `order[i]` is the index of the scrambled tile that belongs at position
+	/// `i` of the result, counting row by row from the top left.
+	///
+	/// Tiles are `width / cols` by `height / rows`, and what the division
+	/// leaves at the right and bottom edges stays in place.
+	Tiles {
+		cols: u32,
+		rows: u32,
+		order: Vec<u32>,
+		width: i32,
+		height: i32,
+	},
+}
+
+fn invalid_data() -> AidokuError {
+	AidokuError {
+		reason: AidokuErrorKind::JsonParseError,
+	}
+}
+
+impl Scramble {
+	/// Checks that the description can be applied, e.g. that no strip has a
+	/// negative height or that a tile order is a permutation of the grid.
+	pub fn validate(&self) -> Result<()> {
+		let valid = match self {
+			Scramble::Rows(blocks) => blocks.iter().all(|b| b.height >= 0 && b.dy >= 0),
+			Scramble::Tiles {
+				cols,
+				rows,
+				order,
+				width,
+				height,
+			} => {
+				let count = (*cols as usize) * (*rows as usize);
+				let mut seen = vec![false; count];
+				count > 0
+					&& *width >= *cols as i32
+					&& *height >= *rows as i32
+					&& order.len() == count
+					&& order.iter().all(|&idx| {
+						let idx = idx as usize;
+						idx < count && !core::mem::replace(&mut seen[idx], true)
+					})
+			}
+		};
+		if valid {
+			Ok(())
+		} else {
+			Err(invalid_data())
+		}
+	}
+
+	/// The pieces to copy to put the image back together.
+	pub fn pieces(&self) -> Vec<Piece> {
+		match self {
+			Scramble::Rows(blocks) => blocks
+				.iter()
+				.map(|block| Piece {
+					sx: 0,
+					sy: -1,
+					dx: 0,
+					dy: block.dy,
+					width: 0,
+					height: block.height,
+				})
+				.collect(),
+			Scramble::Tiles {
+				cols,
+				rows,
+				order,
+				width,
+				height,
+			} => {
+				let cols = *cols as i32;
+				let rows = *rows as i32;
+				let tile_width = width / cols;
+				let tile_height = height / rows;
+				let mut pieces: Vec<Piece> = order
+					.iter()
+					.enumerate()
+					.map(|(i, &src)| {
+						let (i, src) = (i as i32, src as i32);
+						Piece {
+							sx: (src % cols) * tile_width,
+							sy: (src / cols) * tile_height,
+							dx: (i % cols) * tile_width,
+							dy: (i / cols) * tile_height,
+							width: tile_width,
+							height: tile_height,
+						}
+					})
+					.collect();
+				let right = width - tile_width * cols;
+				if right > 0 {
+					let x = tile_width * cols;
+					pieces.push(Piece {
+						sx: x,
+						sy: 0,
+						dx: x,
+						dy: 0,
+						width: right,
+						height: *height,
+					});
+				}
+				let bottom = height - tile_height * rows;
+				if bottom > 0 {
+					let y = tile_height * rows;
+					pieces.push(Piece {
+						sx: 0,
+						sy: y,
+						dx: 0,
+						dy: y,
+						width: tile_width * cols,
+						height: bottom,
+					});
+				}
+				pieces
+			}
+		}
+	}
+
+	/// Serializes the description for `Page.base64`.
+	pub fn serialize(&self) -> String {
+		let mut out = String::from("[");
+		for (i, piece) in self.pieces().iter().enumerate() {
+			if i > 0 {
+				out.push(',');
+			}
+			out.push_str(&format!(
+				r#"{{"sx":{},"sy":{},"dx":{},"dy":{},"width":{},"height":{}}}"#,
+				piece.sx, piece.sy, piece.dx, piece.dy, piece.width, piece.height
+			));
+		}
+		out.push(']');
+		out
+	}
+}
+
+/// Decodes standard base64, ignoring whitespace.
+pub fn decode_base64(input: &str) -> Result<Vec<u8>> {
+	let mut out = Vec::with_capacity(input.len() * 3 / 4 + 1);
+	let mut buffer: u32 = 0;
+	let mut bits_collected = 0;
+
+	for &b in input.as_bytes() {
+		let val = match b {
+			b'A'..=b'Z' => b - b'A',
+			b'a'..=b'z' => b - b'a' + 26,
+			b'0'..=b'9' => b - b'0' + 52,
+			b'+' => 62,
+			b'/' => 63,
+			b'=' => break,
+			b'\n' | b'\r' | b'\t' | b' ' => continue,
+			_ => return Err(invalid_data()),
+		} as u32;
+
+		buffer = (buffer << 6) | val;
+		bits_collected += 6;
+
+		if bits_collected >= 8 {
+			bits_collected -= 8;
+			out.push((buffer >> bits_collected) as u8);
+			buffer &= (1 << bits_collected) - 1;
+		}
+	}
+
+	Ok(out)
+}
+
+/// XORs `data` with `key` repeated over its whole length.
+pub fn xor_with_key(data: &[u8], key: &[u8]) -> Vec<u8> {
+	if key.is_empty() {
+		return data.to_vec();
+	}
+	data.iter()
+		.zip(key.iter().cycle())
+		.map(|(b, k)| b ^ k)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn serializes_rows_as_pieces() {
+		let scramble = Scramble::Rows(vec![
+			RowBlock { dy: 20, height: 10 },
+			RowBlock { dy: 0, height: 20 },
+		]);
+		assert_eq!(
+			scramble.serialize(),
+			concat!(
+				r#"[{"sx":0,"sy":-1,"dx":0,"dy":20,"width":0,"height":10},"#,
+				r#"{"sx":0,"sy":-1,"dx":0,"dy":0,"width":0,"height":20}]"#
+			)
+		);
+		assert_eq!(Scramble::Rows(Vec::new()).serialize(), "[]");
+	}
+
+	#[test]
+	fn serializes_tiles_as_pieces() {
+		let scramble = Scramble::Tiles {
+			cols: 2,
+			rows: 1,
+			order: vec![1, 0],
+			width: 21,
+			height: 10,
+		};
+		assert!(scramble.validate().is_ok());
+		assert_eq!(
+			scramble.serialize(),
+			concat!(
+				r#"[{"sx":10,"sy":0,"dx":0,"dy":0,"width":10,"height":10},"#,
+				r#"{"sx":0,"sy":0,"dx":10,"dy":0,"width":10,"height":10},"#,
+				r#"{"sx":20,"sy":0,"dx":20,"dy":0,"width":1,"height":10}]"#
+			)
+		);
+
+		let scramble = Scramble::Tiles {
+			cols: 2,
+			rows: 2,
+			order: vec![3, 2, 1, 0],
+			width: 4,
+			height: 5,
+		};
+		assert_eq!(
+			scramble.pieces(),
+			[
+				Piece {
+					sx: 2,
+					sy: 2,
+					dx: 0,
+					dy: 0,
+					width: 2,
+					height: 2
+				},
+				Piece {
+					sx: 0,
+					sy: 2,
+					dx: 2,
+					dy: 0,
+					width: 2,
+					height: 2
+				},
+				Piece {
+					sx: 2,
+					sy: 0,
+					dx: 0,
+					dy: 2,
+					width: 2,
+					height: 2
+				},
+				Piece {
+					sx: 0,
+					sy: 0,
+					dx: 2,
+					dy: 2,
+					width: 2,
+					height: 2
+				},
+				Piece {
+					sx: 0,
+					sy: 4,
+					dx: 0,
+					dy: 4,
+					width: 4,
+					height: 1
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn rejects_tile_orders_that_are_not_permutations() {
+		let tiles = |order: Vec<u32>| Scramble::Tiles {
+			cols: 2,
+			rows: 2,
+			order,
+			width: 100,
+			height: 100,
+		};
+		assert!(tiles(vec![0, 1, 2, 3]).validate().is_ok());
+		assert!(tiles(vec![0, 1, 2]).validate().is_err());
+		assert!(tiles(vec![0, 1, 1, 3]).validate().is_err());
+		assert!(tiles(vec![0, 1, 2, 4]).validate().is_err());
+		let too_small = Scramble::Tiles {
+			cols: 2,
+			rows: 2,
+			order: vec![0, 1, 2, 3],
+			width: 1,
+			height: 100,
+		};
+		assert!(too_small.validate().is_err());
+	}
+
+	#[test]
+	fn rejects_negative_rows() {
+		assert!(Scramble::Rows(vec![RowBlock { dy: 0, height: 5 }])
+			.validate()
+			.is_ok());
+		assert!(Scramble::Rows(vec![RowBlock { dy: -1, height: 5 }])
+			.validate()
+			.is_err());
+		assert!(Scramble::Rows(vec![RowBlock { dy: 0, height: -5 }])
+			.validate()
+			.is_err());
+	}
+
+	#[test]
+	fn decodes_base64() {
+		for (input, output) in [
+			("", ""),
+			("Zg==", "f"),
+			("Zm8=", "fo"),
+			("Zm9v", "foo"),
+			("Zm9vYg==", "foob"),
+			("Zm9v\nYmFy", "foobar"),
+			(" Zm9v YmE=\r\n", "fooba"),
+		] {
+			assert_eq!(decode_base64(input).unwrap(), output.as_bytes());
+		}
+		assert_eq!(decode_base64("//+/").unwrap(), [0xff, 0xff, 0xbf]);
+		assert!(decode_base64("Zm9v-YmFy").is_err());
+	}
+
+	#[test]
+	fn xors_with_a_repeating_key() {
+		assert_eq!(xor_with_key(&[1, 2, 3, 4, 5], &[1, 2]), [0, 0, 2, 6, 4]);
+		assert_eq!(xor_with_key(&[1, 2, 3], &[]), [1, 2, 3]);
+		let key = b"3141592653589793";
+		let data = b"#v4|0-120|120-80";
+		assert_eq!(xor_with_key(&xor_with_key(data, key), key), data);
+	}
+}
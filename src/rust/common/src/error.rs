@@ -0,0 +1,127 @@
+//! Failures of the shared helpers that sources need to tell apart.
+//!
+//! `AidokuError` only carries a kind, and sources already return
+//! `Unimplemented` for unsupported urls and `JsonParseError` or a node parse
+//! error for pages that don't parse. The failures here are all returned as a
+//! node modification error, which no source produces otherwise since none
+//! edits nodes, and the failure itself is kept aside so [`SourceError::of`]
+//! can read it back.
+//!
+//! The app only shows that a request failed, so for chapters the templates
+//! turn these failures into a text page telling the user what to do, see
+//! [`pages_or_notice`].
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, NodeError, Result},
+	std::{String, Vec},
+	Page,
+};
+
+use crate::challenge::Challenge;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SourceError {
+	/// An anti-bot challenge page came back instead of the content.
+	Challenge(Challenge),
+	/// None of the site's domains could be reached.
+	Unreachable,
+	/// The site kept redirecting without reaching the content.
+	TooManyRedirects,
+	/// The chapter is for members or has to be bought.
+	Locked,
+}
+
+static mut LAST_ERROR: Option<SourceError> = None;
+
+impl SourceError {
+	/// The failure `error` was created from, `None` for any other error.
+	pub fn of(error: &AidokuError) -> Option<SourceError> {
+		if matches!(
+			error.reason,
+			AidokuErrorKind::NodeError(NodeError::ModifyError)
+		) {
+			unsafe { LAST_ERROR }
+		} else {
+			None
+		}
+	}
+
+	/// What the user can do about the failure.
+	pub fn message(&self) -> &'static str {
+		match self {
+			SourceError::Challenge(Challenge::Cloudflare) => {
+				"The site asks for a Cloudflare check. Open it in the web view to pass the check, then try again."
+			}
+			SourceError::Challenge(Challenge::DdosGuard) => {
+				"The site asks for a DDoS-Guard check. Open it in the web view to pass the check, then try again."
+			}
+			SourceError::Unreachable => {
+				"The site can't be reached. Check the domain and the mirrors in the source settings, or set a relay."
+			}
+			SourceError::TooManyRedirects => {
+				"The site kept redirecting without showing the chapter. Open it in the web view, then try again."
+			}
+			SourceError::Locked => {
+				"This chapter is locked. Log in from the source settings, or unlock it on the site."
+			}
+		}
+	}
+}
+
+impl From<SourceError> for AidokuError {
+	fn from(error: SourceError) -> Self {
+		unsafe {
+			LAST_ERROR = Some(error);
+		}
+		AidokuError {
+			reason: AidokuErrorKind::NodeError(NodeError::ModifyError),
+		}
+	}
+}
+
+/// Passes the pages of a chapter through, but turns a [`SourceError`] into a
+/// single text page with its [`SourceError::message`], so the reader explains
+/// what went wrong instead of failing without a word.
+pub fn pages_or_notice(pages: Result<Vec<Page>>) -> Result<Vec<Page>> {
+	match pages {
+		Err(error) => match SourceError::of(&error) {
+			Some(source_error) => Ok(Vec::from([Page {
+				index: 0,
+				text: String::from(source_error.message()),
+				..Default::default()
+			}])),
+			None => Err(error),
+		},
+		pages => pages,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn reads_back_the_failure_and_shows_it() {
+		let error: AidokuError = SourceError::Challenge(Challenge::Cloudflare).into();
+		assert_eq!(
+			SourceError::of(&error),
+			Some(SourceError::Challenge(Challenge::Cloudflare))
+		);
+		let error: AidokuError = SourceError::Locked.into();
+		assert_eq!(SourceError::of(&error), Some(SourceError::Locked));
+
+		let other = AidokuError {
+			reason: AidokuErrorKind::Unimplemented,
+		};
+		assert_eq!(SourceError::of(&other), None);
+
+		// one test, as the failure is kept in a single static
+		let pages = pages_or_notice(Err(SourceError::Unreachable.into())).unwrap();
+		assert_eq!(pages.len(), 1);
+		assert_eq!(pages[0].text, SourceError::Unreachable.message());
+
+		let other = pages_or_notice(Err(AidokuError {
+			reason: AidokuErrorKind::JsonParseError,
+		}));
+		assert!(other.is_err());
+	}
+}
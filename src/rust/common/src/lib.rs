@@ -0,0 +1,15 @@
+//! Helpers shared by the templates and sources that would otherwise be copied
+//! into each of them.
+#![no_std]
+extern crate alloc;
+
+pub mod challenge;
+pub mod chapter;
+pub mod classify;
+pub mod date;
+pub mod descramble;
+pub mod error;
+pub mod lzstring;
+pub mod net;
+pub mod packer;
+pub mod zh;
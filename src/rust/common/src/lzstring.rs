@@ -0,0 +1,204 @@
+//! Decompression of strings compressed with the
+//! [LZString](https://github.com/pieroxy/lz-string) JavaScript library.
+use aidoku::std::{String, Vec};
+use alloc::vec;
+
+const BASE64_KEY: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=";
+const U8_CODE: u8 = 0;
+const U16_CODE: u8 = 1;
+const CLOSE_CODE: u8 = 2;
+const START_CODE_BITS: u8 = 2;
+
+#[derive(Debug)]
+struct DecompressContext<I> {
+	val: u16,
+	compressed_data: I,
+	position: u16,
+	reset_val: u16,
+}
+
+impl<I> DecompressContext<I>
+where
+	I: Iterator<Item = u16>,
+{
+	#[inline]
+	fn new(mut compressed_data: I, bits_per_char: u8) -> Option<Self> {
+		let reset_val_pow = bits_per_char - 1;
+		let reset_val: u16 = 1 << reset_val_pow;
+
+		Some(DecompressContext {
+			val: compressed_data.next()?,
+			compressed_data,
+			position: reset_val,
+			reset_val,
+		})
+	}
+
+	#[inline]
+	fn read_bit(&mut self) -> Option<bool> {
+		let res = self.val & self.position;
+		self.position >>= 1;
+
+		if self.position == 0 {
+			self.position = self.reset_val;
+			self.val = self.compressed_data.next()?;
+		}
+
+		Some(res != 0)
+	}
+
+	#[inline]
+	fn read_bits(&mut self, n: u8) -> Option<u32> {
+		let mut res = 0;
+		let max_power: u32 = 1 << n;
+		let mut power: u32 = 1;
+		while power != max_power {
+			res |= u32::from(self.read_bit()?) * power;
+			power <<= 1;
+		}
+
+		Some(res)
+	}
+}
+
+/// Decompresses the output of `LZString.compressToBase64`, as UTF-16 code
+/// units.
+pub fn decompress_from_base64(compressed: &str) -> Option<Vec<u16>> {
+	let compressed: Option<Vec<u16>> = compressed
+		.encode_utf16()
+		.flat_map(|c| {
+			BASE64_KEY
+				.iter()
+				.position(|k| u8::try_from(c) == Ok(*k))
+				.map(|n| u16::try_from(n).ok())
+		})
+		.collect();
+
+	decompress_internal(compressed?.into_iter(), 6)
+}
+
+fn decompress_internal<I>(compressed: I, bits_per_char: u8) -> Option<Vec<u16>>
+where
+	I: Iterator<Item = u16>,
+{
+	let mut ctx = match DecompressContext::new(compressed, bits_per_char) {
+		Some(ctx) => ctx,
+		None => return Some(Vec::new()),
+	};
+
+	let mut dictionary: Vec<Vec<u16>> = Vec::with_capacity(16);
+	for i in 0_u16..3_u16 {
+		dictionary.push(vec![i]);
+	}
+
+	// u8::MAX > u2::MAX
+	let code = u8::try_from(ctx.read_bits(START_CODE_BITS)?).unwrap_or_default();
+	let first_entry = match code {
+		U8_CODE | U16_CODE => {
+			let bits_to_read = (code * 8) + 8;
+			// bits_to_read == 8 or 16 <= 16
+			u16::try_from(ctx.read_bits(bits_to_read)?).unwrap_or_default()
+		}
+		CLOSE_CODE => return Some(Vec::new()),
+		_ => return None,
+	};
+	dictionary.push(vec![first_entry]);
+
+	let mut w = vec![first_entry];
+	let mut result = vec![first_entry];
+	let mut num_bits: u8 = 3;
+	let mut enlarge_in: u64 = 4;
+	let mut entry;
+	loop {
+		let mut code = ctx.read_bits(num_bits)?;
+		match u8::try_from(code) {
+			Ok(code_u8 @ (U8_CODE | U16_CODE)) => {
+				let bits_to_read = (code_u8 * 8) + 8;
+				// bits_to_read == 8 or 16 <= 16
+				let bits = u16::try_from(ctx.read_bits(bits_to_read)?).unwrap_or_default();
+				dictionary.push(vec![bits]);
+				code = u32::try_from(dictionary.len() - 1).ok()?;
+				enlarge_in -= 1;
+			}
+			Ok(CLOSE_CODE) => return Some(result),
+			_ => {}
+		}
+
+		if enlarge_in == 0 {
+			enlarge_in = 1 << num_bits;
+			num_bits += 1;
+		}
+
+		// Return error if code cannot be converted to dictionary index
+		let code_usize = usize::try_from(code).ok()?;
+		if let Some(entry_value) = dictionary.get(code_usize) {
+			entry = entry_value.clone();
+		} else if code_usize == dictionary.len() {
+			entry = w.clone();
+			entry.push(*w.first()?);
+		} else {
+			return None;
+		}
+
+		result.extend(&entry);
+
+		// Add w+entry[0] to the dictionary.
+		let mut to_be_inserted = w.clone();
+		to_be_inserted.push(*entry.first()?);
+		dictionary.push(to_be_inserted);
+		enlarge_in -= 1;
+
+		w = entry;
+
+		if enlarge_in == 0 {
+			enlarge_in = 1 << num_bits;
+			num_bits += 1;
+		}
+	}
+}
+
+/// Decompresses the output of `LZString.compressToBase64` into a string.
+pub fn decompress_string_from_base64(compressed: &str) -> Option<String> {
+	String::from_utf16(&decompress_from_base64(compressed)?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn decompresses_utf16() {
+		assert_eq!(
+			decompress_string_from_base64(
+				"tvxm/FQhboF7qF1yA+DKBZAEjAlgWwOYBECGAXXGABwCcBTASQDtV8YALcgG2YHsACJ1z79oA==="
+			)
+			.as_deref(),
+			Some("进击的巨人|SMH|imgData|preInit|hello hello hello")
+		);
+	}
+
+	#[test]
+	fn decompresses_manhuagui_words() {
+		// the word list of fixtures/manhuagui.packed.js
+		let compressed = concat!(
+			"FYBw5gPg7gpgRiCBGAnAFgMwAYJaRAZQFkAJCASwFswARAQwBc6I5yATFgOzsphZHIBjCIPYQATGgAcA",
+			"VgDsANhHdeEAGbkANjADOuPPvH6M68p3I6AFjA4MATgFc+2zhBMhGliCB0ngEYGA2YDsIHSYGBz18OE0",
+			"Ae0EAawB9QWFOGAAPBgBJDklZOTlvOxgANzzcMM0IPiQ5LAwUGXEsNohKCESwHKwaByIATxQ6ADECOLo",
+			"ANQBFOwBVTXFBSBBSnPMGIA=",
+		);
+		let words = concat!(
+			"jpg|webp|19430|01|SMH|imgData|bid|bname|bpic|cid|248576|cname|files|001|002|003|",
+			"finished|true|len|3|path|ps3|j|jjdjr|status|1|block_cc|nextId|248577|prevId|0|sl",
+			"|e|1703952000|m|kgI0DuMy9aFSoaVQrUl2cg|preInit",
+		);
+		assert_eq!(
+			decompress_string_from_base64(compressed).as_deref(),
+			Some(words)
+		);
+	}
+
+	#[test]
+	fn decompresses_empty_input() {
+		assert_eq!(decompress_string_from_base64("").as_deref(), Some(""));
+	}
+}
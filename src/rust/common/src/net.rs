@@ -0,0 +1,205 @@
+//! Request building with optional relay support.
+//!
+//! Some networks block the hosts of certain sites. A relay forwards the
+//! requests for them, either by taking the target url as part of its own url
+//! (`https://relay.example/?url=`) or by receiving the original path with the
+//! target given in a header.
+//!
+//! Sources opt in by adding the `relayURL`, `relayMode` and `relayImages`
+//! settings to their `settings.json`; without them every request goes out
+//! unchanged.
+use aidoku::{
+	error::Result,
+	helpers::uri::encode_uri_component,
+	prelude::*,
+	std::{
+		defaults::defaults_get,
+		html::Node,
+		json,
+		net::{HttpMethod, Request},
+		String, ValueRef, Vec,
+	},
+};
+
+use crate::challenge::{self, detect_from_body, detect_from_headers, is_challenge_status};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RelayMode {
+	/// The encoded target url is appended to the relay url, or replaces a
+	/// `{url}` placeholder in it.
+	Prefix,
+	/// The request goes to the relay host with the same path and query, and the
+	/// target url is sent in the `X-Relay-Target` header.
+	Header,
+}
+
+pub struct Relay {
+	pub url: String,
+	pub mode: RelayMode,
+	/// Whether page images are loaded through the relay as well.
+	pub images: bool,
+}
+
+const RELAY_TARGET_HEADER: &str = "X-Relay-Target";
+
+fn read_string(key: &str) -> String {
+	defaults_get(key)
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.map(|v| String::from(v.trim()))
+		.unwrap_or_default()
+}
+
+/// Returns the relay configured in the settings, if any.
+pub fn get_relay() -> Option<Relay> {
+	let url = read_string("relayURL");
+	if !url.starts_with("http") {
+		return None;
+	}
+	let mode = match read_string("relayMode").as_str() {
+		"header" => RelayMode::Header,
+		_ => RelayMode::Prefix,
+	};
+	let images = defaults_get("relayImages")
+		.and_then(|v| v.as_bool())
+		.unwrap_or(false);
+	Some(Relay { url, mode, images })
+}
+
+fn split_origin(url: &str) -> Option<(&str, &str)> {
+	let scheme_end = url.find("://")? + 3;
+	let path_start = url[scheme_end..]
+		.find(['/', '?', '#'])
+		.map(|idx| scheme_end + idx)
+		.unwrap_or(url.len());
+	Some((&url[..path_start], &url[path_start..]))
+}
+
+impl Relay {
+	/// Rewrites `url` so that it points at the relay.
+	pub fn wrap_url(&self, url: &str) -> String {
+		if url.starts_with(&self.url) {
+			return String::from(url);
+		}
+		match self.mode {
+			RelayMode::Prefix => {
+				if self.url.contains("{url}") {
+					self.url.replace("{url}", &encode_uri_component(url))
+				} else {
+					format!("{}{}", self.url, encode_uri_component(url))
+				}
+			}
+			RelayMode::Header => match split_origin(url) {
+				Some((_, path)) => {
+					format!("{}{}", self.url.trim_end_matches('/'), path)
+				}
+				None => String::from(url),
+			},
+		}
+	}
+
+	/// Builds a request for `url` going through the relay.
+	pub fn request(&self, url: &str, method: HttpMethod) -> Request {
+		let request = Request::new(self.wrap_url(url), method);
+		match self.mode {
+			RelayMode::Header => request.header(RELAY_TARGET_HEADER, url),
+			RelayMode::Prefix => request,
+		}
+	}
+}
+
+/// Builds a request, routed through the relay when one is configured.
+///
+/// Headers such as `Referer` should still be set to the original site by the
+/// caller, relays forward them as they are.
+pub fn request<T: AsRef<str>>(url: T, method: HttpMethod) -> Request {
+	match get_relay() {
+		Some(relay) => relay.request(url.as_ref(), method),
+		None => Request::new(url.as_ref(), method),
+	}
+}
+
+/// Rewrites the url of a page image if images should go through the relay.
+///
+/// Only the prefix mode can be used here, the app doesn't let sources change
+/// where an image request goes beyond its url.
+pub fn relay_image_url(url: String) -> String {
+	match get_relay() {
+		Some(relay) if relay.images && relay.mode == RelayMode::Prefix => relay.wrap_url(&url),
+		_ => url,
+	}
+}
+
+enum Response {
+	/// Nothing suspicious, the request can be read as usual.
+	Unchecked(Request),
+	/// The body had to be read to rule out a challenge page, with the status
+	/// code it came with.
+	Body(i32, Vec<u8>),
+}
+
+/// Sends the request and fails with [`challenge::challenge_error`] if the
+/// response is a challenge page.
+fn inspect(request: Request) -> Result<Response> {
+	request.send();
+	if let Some(found) = detect_from_headers(&request) {
+		return Err(challenge::challenge_error(found));
+	}
+	if !is_challenge_status(request.status_code()) {
+		return Ok(Response::Unchecked(request));
+	}
+	let status = request.status_code();
+	let body = request.data();
+	match detect_from_body(&body) {
+		Some(found) => Err(challenge::challenge_error(found)),
+		None => Ok(Response::Body(status, body)),
+	}
+}
+
+/// Sends the request once and returns its status code and body, failing
+/// with [`challenge::challenge_error`] on a challenge page like
+/// [`CheckedRequest`].
+pub fn send_checked(request: Request) -> Result<(i32, Vec<u8>)> {
+	match inspect(request)? {
+		Response::Unchecked(request) => Ok((request.status_code(), request.data())),
+		Response::Body(status, body) => Ok((status, body)),
+	}
+}
+
+/// Response readers that recognise anti-bot challenge pages instead of
+/// parsing them as content.
+pub trait CheckedRequest {
+	fn checked_data(self) -> Result<Vec<u8>>;
+	fn checked_string(self) -> Result<String>;
+	fn checked_html(self) -> Result<Node>;
+	fn checked_json(self) -> Result<ValueRef>;
+}
+
+impl CheckedRequest for Request {
+	fn checked_data(self) -> Result<Vec<u8>> {
+		match inspect(self)? {
+			Response::Unchecked(request) => Ok(request.data()),
+			Response::Body(_, body) => Ok(body),
+		}
+	}
+
+	fn checked_string(self) -> Result<String> {
+		match inspect(self)? {
+			Response::Unchecked(request) => request.string(),
+			Response::Body(_, body) => Ok(String::from_utf8_lossy(&body).into_owned()),
+		}
+	}
+
+	fn checked_html(self) -> Result<Node> {
+		match inspect(self)? {
+			Response::Unchecked(request) => request.html(),
+			Response::Body(_, body) => Node::new(&body),
+		}
+	}
+
+	fn checked_json(self) -> Result<ValueRef> {
+		match inspect(self)? {
+			Response::Unchecked(request) => request.json(),
+			Response::Body(_, body) => json::parse(&body),
+		}
+	}
+}
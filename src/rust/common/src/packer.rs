@@ -0,0 +1,277 @@
+//! Unpacking of scripts packed with Dean Edwards' packer.
+//!
+//! Packed scripts look like
+//! `eval(function(p,a,c,k,e,d){...return p}('<payload>',62,120,'<words>'.split('|'),0,{}))`:
+//! every identifier of the payload is a number written in base `a` that
+//! indexes the `|`-separated words. Some sites compress the words with
+//! LZString and split them through a `splic` method they add to
+//! `String.prototype`, which is handled as well.
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, Result},
+	std::{String, Vec},
+};
+
+use crate::lzstring::decompress_string_from_base64;
+
+/// The arguments a packed script passes to its unpacking function.
+pub struct Packed {
+	pub payload: String,
+	pub radix: u32,
+	pub count: usize,
+	pub words: Vec<String>,
+}
+
+fn invalid_script() -> AidokuError {
+	AidokuError {
+		reason: AidokuErrorKind::JsonParseError,
+	}
+}
+
+/// Reads the JavaScript string literal at the start of `input` and returns it
+/// with the rest of the input.
+fn read_string_literal(input: &str) -> Option<(String, &str)> {
+	let mut chars = input.char_indices();
+	let (_, quote) = chars.next()?;
+	if quote != '\'' && quote != '"' {
+		return None;
+	}
+	let mut value = String::new();
+	while let Some((idx, c)) = chars.next() {
+		match c {
+			'\\' => {
+				let (_, escaped) = chars.next()?;
+				match escaped {
+					'n' => value.push('\n'),
+					'r' => value.push('\r'),
+					't' => value.push('\t'),
+					'x' | 'u' => {
+						let len = if escaped == 'x' { 2 } else { 4 };
+						let mut code = 0;
+						for _ in 0..len {
+							code = code * 16 + chars.next()?.1.to_digit(16)?;
+						}
+						value.push(char::from_u32(code)?);
+					}
+					c => value.push(c),
+				}
+			}
+			c if c == quote => return Some((value, &input[idx + 1..])),
+			c => value.push(c),
+		}
+	}
+	None
+}
+
+fn read_number(input: &str) -> Option<(usize, &str)> {
+	let input = input.trim_start();
+	let end = input
+		.find(|c: char| !c.is_ascii_digit())
+		.unwrap_or(input.len());
+	Some((input[..end].parse().ok()?, &input[end..]))
+}
+
+fn expect(input: &str, token: char) -> Option<&str> {
+	input.trim_start().strip_prefix(token)
+}
+
+/// Whether the word list is split through the LZString `splic` method. Its
+/// name is usually written with escapes, e.g. `['\x73\x70\x6c\x69\x63']`.
+fn is_splic(rest: &str) -> bool {
+	let rest = rest.trim_start();
+	let call = &rest[..rest.find('(').unwrap_or(rest.len())];
+	match read_string_literal(call.trim_start_matches('[')) {
+		Some((name, _)) => name == "splic",
+		None => call.contains("splic"),
+	}
+}
+
+impl Packed {
+	/// Finds the packed call in `script` and reads its arguments.
+	pub fn parse(script: &str) -> Result<Self> {
+		Self::parse_args(script).ok_or_else(invalid_script)
+	}
+
+	fn parse_args(script: &str) -> Option<Self> {
+		// the arguments follow the body of the unpacking function
+		let body_end = script.find("return p")? + "return p".len();
+		let rest = &script[body_end..];
+		let args_start = rest.find('(')?;
+		if !rest[..args_start]
+			.chars()
+			.all(|c| c == ';' || c == '}' || c == ')' || c.is_whitespace())
+		{
+			return None;
+		}
+		let rest = rest[args_start + 1..].trim_start();
+
+		let (payload, rest) = read_string_literal(rest)?;
+		let (radix, rest) = read_number(expect(rest, ',')?)?;
+		let (count, rest) = read_number(expect(rest, ',')?)?;
+		let (words, rest) = read_string_literal(expect(rest, ',')?.trim_start())?;
+
+		let words = if is_splic(rest) {
+			decompress_string_from_base64(&words)?
+		} else {
+			words
+		};
+
+		if !(2..=62).contains(&radix) {
+			return None;
+		}
+		Some(Packed {
+			payload,
+			radix: radix as u32,
+			count,
+			words: words.split('|').map(String::from).collect(),
+		})
+	}
+
+	/// Reads a word of the payload as the index it was encoded from, the
+	/// inverse of the `e` function of the packer.
+	fn decode_index(&self, word: &str) -> Option<usize> {
+		if word.len() > 1 && word.starts_with('0') {
+			return None;
+		}
+		let mut index: usize = 0;
+		for c in word.chars() {
+			let digit = match c {
+				'0'..='9' => c as u32 - '0' as u32,
+				'a'..='z' => c as u32 - 'a' as u32 + 10,
+				'A'..='Z' => c as u32 - 'A' as u32 + 36,
+				_ => return None,
+			};
+			if digit >= self.radix {
+				return None;
+			}
+			index = index
+				.checked_mul(self.radix as usize)?
+				.checked_add(digit as usize)?;
+		}
+		if index < self.count {
+			Some(index)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the original script.
+	pub fn unpack(&self) -> String {
+		let mut result = String::with_capacity(self.payload.len() * 2);
+		let mut word_start: Option<usize> = None;
+		let is_word = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+		let flush = |result: &mut String, word: &str| match self
+			.decode_index(word)
+			.and_then(|idx| self.words.get(idx))
+			.filter(|w| !w.is_empty())
+		{
+			Some(replacement) => result.push_str(replacement),
+			None => result.push_str(word),
+		};
+
+		for (idx, c) in self.payload.char_indices() {
+			if is_word(c) {
+				if word_start.is_none() {
+					word_start = Some(idx);
+				}
+				continue;
+			}
+			if let Some(start) = word_start.take() {
+				flush(&mut result, &self.payload[start..idx]);
+			}
+			result.push(c);
+		}
+		if let Some(start) = word_start {
+			flush(&mut result, &self.payload[start..]);
+		}
+		result
+	}
+}
+
+/// Encodes `value` the way the `e` function of the packer does: digits up to
+/// 35 as in base 36, the ones above as `A`-`Z`.
+pub fn encode_base(value: usize, radix: u32) -> String {
+	let radix = radix as usize;
+	let digit = value % radix;
+	let mut result = if value >= radix {
+		encode_base(value / radix, radix as u32)
+	} else {
+		String::new()
+	};
+	result.push(match digit {
+		0..=9 => (b'0' + digit as u8) as char,
+		10..=35 => (b'a' + (digit - 10) as u8) as char,
+		_ => (digit as u8 + 29) as char,
+	});
+	result
+}
+
+/// Unpacks the first packed script found in `script`.
+pub fn unpack(script: &str) -> Result<String> {
+	Ok(Packed::parse(script)?.unpack())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn unpacks_mangabz() {
+		let script = unpack(include_str!("../fixtures/mangabz.packed.js")).unwrap();
+		assert_eq!(
+			script,
+			include_str!("../fixtures/mangabz.unpacked.js").trim_end()
+		);
+	}
+
+	#[test]
+	fn unpacks_manhuagui_splic() {
+		let script = unpack(include_str!("../fixtures/manhuagui.packed.js")).unwrap();
+		assert_eq!(
+			script,
+			include_str!("../fixtures/manhuagui.unpacked.js").trim_end()
+		);
+	}
+
+	#[test]
+	fn encodes_like_the_packer() {
+		// values from the `e` function of the packer
+		for (value, word) in [(0, "0"), (35, "z"), (36, "10"), (1295, "zz"), (1296, "100")] {
+			assert_eq!(encode_base(value, 36), word);
+		}
+		for (value, word) in [
+			(0, "0"),
+			(9, "9"),
+			(10, "a"),
+			(35, "z"),
+			(36, "A"),
+			(61, "Z"),
+			(62, "10"),
+			(3843, "ZZ"),
+			(3844, "100"),
+			(238327, "ZZZ"),
+		] {
+			assert_eq!(encode_base(value, 62), word);
+		}
+	}
+
+	#[test]
+	fn decodes_what_it_encodes() {
+		for radix in [36, 62] {
+			let packed = Packed {
+				payload: String::new(),
+				radix,
+				count: usize::MAX,
+				words: Vec::new(),
+			};
+			for value in (0..10_000).chain([238_327, 1 << 20]) {
+				let word = encode_base(value, radix);
+				assert_eq!(
+					packed.decode_index(&word),
+					Some(value),
+					"{word} in base {radix}"
+				);
+			}
+		}
+	}
+}
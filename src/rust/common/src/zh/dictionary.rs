@@ -0,0 +1,316 @@
+//! Conversion tables between Simplified and Traditional Chinese.
+//!
+//! Traditional forms follow Taiwanese usage (`裡`, `著`), which is what the
+//! sources that offer a Traditional site serve. Characters with more than one
+//! Traditional form map to the most common one, and [`S2T_PHRASES`] overrides
+//! them in the words where another one is right.
+
+/// Pairs of a Simplified character followed by its Traditional form.
+pub const CHARACTERS: &str = "\
+	爱愛碍礙袄襖罢罷摆擺败敗办辦帮幫绑綁宝寶报報饱飽贝貝备備辈輩笔筆币幣毕畢闭閉边邊编編变變标標别別宾賓饼餅并並拨撥补補财財\
+	参參残殘惭慚惨慘灿燦仓倉苍蒼舱艙厕廁测測层層产產馋饞缠纏长長肠腸尝嘗偿償厂廠场場车車彻徹尘塵陈陳衬襯称稱惩懲诚誠齿齒冲衝\
+	虫蟲宠寵丑醜筹籌处處触觸传傳疮瘡闯闖创創锤錘纯純词詞辞辭聪聰从從丛叢窜竄错錯达達带帶贷貸单單担擔胆膽弹彈诞誕当當挡擋党黨\
+	导導岛島祷禱灯燈邓鄧敌敵递遞缔締点點电電垫墊钓釣调調叠疊顶頂订訂东東动動冻凍栋棟斗鬥独獨读讀赌賭镀鍍锻鍛断斷队隊对對吨噸\
+	夺奪堕墮鹅鵝额額恶惡饿餓儿兒尔爾饵餌贰貳发發罚罰阀閥烦煩饭飯访訪纺紡飞飛废廢费費纷紛坟墳奋奮愤憤粪糞丰豐风風枫楓疯瘋锋鋒\
+	冯馮缝縫讽諷凤鳳肤膚辐輻抚撫辅輔赋賦复復负負妇婦缚縛该該盖蓋干幹赶趕秆稈钢鋼纲綱岗崗搁擱鸽鴿阁閣个個给給龚龔巩鞏贡貢沟溝\
+	构構购購够夠顾顧关關观觀馆館惯慣贯貫广廣归歸龟龜规規轨軌贵貴柜櫃滚滾锅鍋国國过過骇駭韩韓汉漢号號轰轟红紅鸿鴻后後护護沪滬\
+	划劃画畫话話怀懷坏壞欢歡环環还還缓緩换換唤喚挥揮辉輝汇匯会會绘繪贿賄荤葷浑渾伙夥获獲货貨祸禍击擊机機积積饥飢鸡雞极極辑輯\
+	级級挤擠几幾计計记記际際继繼纪紀夹夾贾賈价價驾駕坚堅歼殲间間艰艱监監检檢减減简簡见見荐薦鉴鑑键鍵剑劍舰艦践踐贱賤溅濺渐漸\
+	将將奖獎讲講酱醬胶膠骄驕娇嬌脚腳饺餃较較轿轎阶階节節结結洁潔杰傑届屆紧緊谨謹锦錦进進尽盡劲勁晋晉惊驚经經鲸鯨镜鏡径徑竞競\
+	旧舊举舉剧劇据據惧懼觉覺决決绝絕军軍骏駿开開凯凱课課垦墾恳懇裤褲夸誇块塊宽寬矿礦亏虧馈饋扩擴阔闊腊臘蜡蠟来來赖賴兰蘭拦攔\
+	栏欄蓝藍篮籃览覽懒懶烂爛滥濫劳勞涝澇乐樂垒壘类類泪淚离離礼禮里裡历歷厉厲丽麗励勵隶隸俩倆联聯连連怜憐帘簾莲蓮脸臉练練炼煉\
+	恋戀链鏈凉涼粮糧两兩辆輛疗療辽遼猎獵邻鄰临臨灵靈龄齡铃鈴岭嶺领領刘劉浏瀏龙龍聋聾笼籠楼樓搂摟炉爐卢盧鲁魯录錄陆陸驴驢吕呂\
+	铝鋁屡屢缕縷虑慮滤濾绿綠乱亂伦倫轮輪论論罗羅逻邏锣鑼骡騾络絡妈媽马馬码碼骂罵吗嗎买買卖賣麦麥迈邁脉脈蛮蠻馒饅满滿瞒瞞猫貓\
+	贸貿么麼门門们們梦夢弥彌谜謎觅覓绵綿庙廟灭滅闽閩鸣鳴铭銘谋謀亩畝钠鈉纳納难難挠撓恼惱脑腦闹鬧腻膩鸟鳥聂聶宁寧拧擰农農浓濃\
+	脓膿诺諾欧歐盘盤庞龐赔賠喷噴鹏鵬骗騙飘飄频頻贫貧苹蘋凭憑评評泼潑颇頗扑撲铺鋪谱譜栖棲齐齊骑騎岂豈启啟气氣弃棄牵牽铅鉛迁遷\
+	签簽谦謙钱錢钳鉗潜潛浅淺谴譴枪槍墙牆蔷薔强強抢搶桥橋乔喬侨僑窍竅窃竊亲親轻輕氢氫倾傾顷頃请請庆慶琼瓊穷窮区區躯軀驱驅趋趨\
+	权權劝勸确確让讓扰擾热熱认認荣榮绒絨软軟锐銳润潤洒灑赛賽伞傘丧喪扫掃涩澀杀殺纱紗晒曬闪閃陕陝赡贍伤傷赏賞烧燒绍紹赊賒设設\
+	摄攝慑懾绅紳审審婶嬸肾腎渗滲声聲绳繩胜勝圣聖师師诗詩狮獅湿濕时時识識实實势勢适適释釋饰飾视視试試寿壽兽獸书書输輸赎贖属屬\
+	树樹数數帅帥双雙谁誰税稅顺順说說硕碩烁爍丝絲饲飼颂頌讼訟诵誦擞擻苏蘇诉訴肃肅虽雖随隨岁歲孙孫损損笋筍缩縮琐瑣锁鎖獭獺挞撻\
+	态態摊攤贪貪瘫癱滩灘坛壇谈談叹嘆汤湯烫燙涛濤讨討腾騰誊謄题題体體屉屜条條贴貼铁鐵厅廳听聽烃烴铜銅统統头頭图圖涂塗团團颓頹\
+	蜕蛻脱脫鸵鴕驮馱驼駝椭橢袜襪弯彎湾灣顽頑万萬网網韦韋违違围圍为為伪偽纬緯卫衛谓謂闻聞纹紋稳穩问問瓮甕挝撾窝窩乌烏诬誣无無\
+	芜蕪吴吳坞塢雾霧务務误誤锡錫牺犧袭襲习習铣銑戏戲细細虾蝦辖轄峡峽侠俠狭狹吓嚇厦廈鲜鮮纤纖咸鹹贤賢衔銜闲閒显顯险險现現献獻\
+	县縣馅餡羡羨宪憲线線厢廂镶鑲乡鄉详詳响響项項萧蕭销銷晓曉啸嘯蝎蠍协協挟挾携攜胁脅谐諧写寫泻瀉谢謝锌鋅衅釁兴興汹洶锈鏽绣繡\
+	须須许許续續绪緒轩軒悬懸选選癣癬绚絢学學勋勳寻尋驯馴训訓讯訊逊遜压壓鸦鴉鸭鴨哑啞亚亞讶訝阉閹烟煙盐鹽严嚴颜顏阎閻艳艷厌厭\
+	砚硯彦彥谚諺验驗鸯鴦杨楊扬揚疡瘍阳陽痒癢养養样樣钥鑰药藥爷爺叶葉页頁业業医醫铱銥颐頤遗遺仪儀蚁蟻艺藝亿億忆憶义義议議异異\
+	译譯谊誼阴陰银銀饮飲隐隱樱櫻婴嬰鹰鷹应應缨纓莹瑩萤螢营營荧熒蝇蠅赢贏颖穎哟喲拥擁佣傭痈癰踊踴咏詠优優忧憂邮郵铀鈾犹猶诱誘\
+	舆輿鱼魚渔漁娱娛与與屿嶼语語狱獄誉譽预預驭馭鸳鴛渊淵辕轅园園员員圆圓缘緣远遠愿願约約跃躍岳嶽粤粵悦悅阅閱云雲郧鄖匀勻陨隕\
+	运運酝醞晕暈韵韻杂雜灾災载載攒攢暂暫赞贊赃贓脏髒凿鑿枣棗灶竈责責择擇则則泽澤贼賊赠贈轧軋闸閘铡鍘诈詐斋齋债債毡氈盏盞斩斬\
+	辗輾崭嶄栈棧战戰绽綻张張涨漲帐帳账賬胀脹赵趙蛰蟄辙轍锗鍺这這贞貞针針侦偵诊診镇鎮阵陣挣掙睁睜狰猙争爭帧幀郑鄭证證织織职職\
+	执執纸紙挚摯掷擲帜幟质質滞滯钟鐘终終种種肿腫众眾诌謅轴軸皱皺昼晝骤驟猪豬诸諸诛誅烛燭瞩矚嘱囑贮貯铸鑄筑築驻駐专專砖磚转轉\
+	赚賺桩樁庄莊装裝妆妝壮壯状狀锥錐赘贅坠墜缀綴谆諄准準浊濁兹茲资資渍漬踪蹤综綜总總纵縱邹鄒诅詛组組钻鑽术術鳞鱗鲤鯉鳄鱷鲨鯊\
+	鹤鶴侣侶俭儉兑兌匮匱华華卤滷叙敘哗嘩啰囉埚堝壳殼娄婁娅婭娆嬈寝寢尧堯庐廬库庫忏懺户戶抛拋拟擬拢攏拣揀挂掛捞撈掳擄掺摻揽攬\
+	搀攙摇搖撑撐撵攆旷曠昙曇棂欞榄欖槛檻横橫毙斃没沒沣灃沦淪沧滄泞濘浆漿济濟涡渦溃潰滨濱潇瀟焕煥狈狽玛瑪畅暢痴癡眯瞇矫矯础礎\
+	秃禿箩籮纠糾绊絆绕繞维維翘翹耸聳芦蘆荡蕩莱萊萝蘿虚虛蚀蝕讳諱询詢谅諒谎謊谣謠贩販贺賀赐賜迟遲迹跡钙鈣钞鈔闷悶雏雛顿頓驶駛\
+	骚騷着著于於内內厨廚颈頸宫宮净淨黄黃温溫奥奧况況盗盜呕嘔册冊删刪栅柵蕴蘊嘘噓颠顛巅巔丢丟葱蔥凑湊凄淒凛凜刹剎奂奐殴毆鸥鷗\
+	枢樞抠摳碱鹼缆纜剂劑尸屍恒恆铠鎧绯緋绫綾绮綺缪繆缭繚魇魘髅髏祯禎禅禪玑璣珑瓏峦巒挛攣栾欒鸾鸞泷瀧镰鐮枥櫪岚嵐冈岡刚剛仅僅\
+	颤顫呗唄噜嚕叽嘰咙嚨喽嘍咛嚀哝噥呛嗆呜嗚唠嘮";
+
+/// Traditional variants that only appear on the Traditional side: forms
+/// merged into one Simplified character, and alternative forms. Pairs of the
+/// Traditional character followed by its Simplified form.
+pub const TRADITIONAL_VARIANTS: &str = "\
+	裏里沖冲甦苏糰团嚐尝檯台臺台颱台繫系係系鍾钟髮发鬚须穫获儘尽彙汇鬍胡製制鬱郁兇凶乾干麵面隻只鬆松複复曆历捲卷閑闲闆板噹当\
+	錶表遊游癥症摺折籤签採采鞦秋樸朴臟脏爲为衆众啓启峯峰羣群禦御籲吁範范徵征餘余佔占託托週周歎叹綫线僞伪";
+
+/// Words converted as a whole when going from Simplified to Traditional.
+pub const S2T_PHRASES: &[(&str, &str)] = &[
+	("头发", "頭髮"),
+	("白发", "白髮"),
+	("黑发", "黑髮"),
+	("金发", "金髮"),
+	("银发", "銀髮"),
+	("红发", "紅髮"),
+	("长发", "長髮"),
+	("短发", "短髮"),
+	("秀发", "秀髮"),
+	("毛发", "毛髮"),
+	("理发", "理髮"),
+	("发型", "髮型"),
+	("发丝", "髮絲"),
+	("假发", "假髮"),
+	("染发", "染髮"),
+	("卷发", "捲髮"),
+	("皇后", "皇后"),
+	("王后", "王后"),
+	("太后", "太后"),
+	("天后", "天后"),
+	("母后", "母后"),
+	("后妃", "后妃"),
+	("影后", "影后"),
+	("后羿", "后羿"),
+	("后土", "后土"),
+	("干净", "乾淨"),
+	("干杯", "乾杯"),
+	("干燥", "乾燥"),
+	("饼干", "餅乾"),
+	("干脆", "乾脆"),
+	("干枯", "乾枯"),
+	("干旱", "乾旱"),
+	("晒干", "曬乾"),
+	("风干", "風乾"),
+	("烘干", "烘乾"),
+	("擦干", "擦乾"),
+	("吹干", "吹乾"),
+	("口干", "口乾"),
+	("外强中干", "外強中乾"),
+	("干货", "乾貨"),
+	("干粮", "乾糧"),
+	("干涸", "乾涸"),
+	("干瘪", "乾癟"),
+	("干渴", "乾渴"),
+	("干咳", "乾咳"),
+	("干笑", "乾笑"),
+	("干瞪眼", "乾瞪眼"),
+	("干巴巴", "乾巴巴"),
+	("干果", "乾果"),
+	("干冰", "乾冰"),
+	("干柴", "乾柴"),
+	("干草", "乾草"),
+	("干洗", "乾洗"),
+	("干尸", "乾屍"),
+	("干电池", "乾電池"),
+	("干妈", "乾媽"),
+	("干爹", "乾爹"),
+	("干儿子", "乾兒子"),
+	("干女儿", "乾女兒"),
+	("干涉", "干涉"),
+	("若干", "若干"),
+	("干扰", "干擾"),
+	("干预", "干預"),
+	("相干", "相干"),
+	("干戈", "干戈"),
+	("天干物燥", "天乾物燥"),
+	("天干", "天干"),
+	("干支", "干支"),
+	("干系", "干係"),
+	("干犯", "干犯"),
+	("面条", "麵條"),
+	("拉面", "拉麵"),
+	("面包", "麵包"),
+	("方便面", "方便麵"),
+	("面粉", "麵粉"),
+	("泡面", "泡麵"),
+	("一只", "一隻"),
+	("两只", "兩隻"),
+	("这只", "這隻"),
+	("那只", "那隻"),
+	("几只", "幾隻"),
+	("船只", "船隻"),
+	("老板", "老闆"),
+	("公里", "公里"),
+	("千里", "千里"),
+	("万里", "萬里"),
+	("英里", "英里"),
+	("里程", "里程"),
+	("邻里", "鄰里"),
+	("故里", "故里"),
+	("乡里", "鄉里"),
+	("华里", "華里"),
+	("克里斯", "克里斯"),
+	("复杂", "複雜"),
+	("重复", "重複"),
+	("复制", "複製"),
+	("复数", "複數"),
+	("复习", "複習"),
+	("复印", "複印"),
+	("复合", "複合"),
+	("复式", "複式"),
+	("复方", "複方"),
+	("复姓", "複姓"),
+	("复眼", "複眼"),
+	("繁复", "繁複"),
+	("复述", "複述"),
+	("复审", "複審"),
+	("复赛", "複賽"),
+	("复核", "複核"),
+	("复查", "複查"),
+	("复选", "複選"),
+	("复试", "複試"),
+	("复利", "複利"),
+	("反复", "反覆"),
+	("答复", "答覆"),
+	("回复", "回覆"),
+	("批复", "批覆"),
+	("日历", "日曆"),
+	("农历", "農曆"),
+	("历法", "曆法"),
+	("阳历", "陽曆"),
+	("阴历", "陰曆"),
+	("公历", "公曆"),
+	("挂历", "掛曆"),
+	("万年历", "萬年曆"),
+	("台历", "檯曆"),
+	("历书", "曆書"),
+	("皇历", "皇曆"),
+	("黄历", "黃曆"),
+	("钟情", "鍾情"),
+	("钟爱", "鍾愛"),
+	("关系", "關係"),
+	("联系", "聯繫"),
+	("维系", "維繫"),
+	("尽管", "儘管"),
+	("尽量", "儘量"),
+	("尽快", "儘快"),
+	("收获", "收穫"),
+	("词汇", "詞彙"),
+	("汇编", "彙編"),
+	("胡子", "鬍子"),
+	("胡须", "鬍鬚"),
+	("制作", "製作"),
+	("制造", "製造"),
+	("制品", "製品"),
+	("绘制", "繪製"),
+	("研制", "研製"),
+	("炼制", "煉製"),
+	("忧郁", "憂鬱"),
+	("郁闷", "鬱悶"),
+	("抑郁", "抑鬱"),
+	("凶手", "兇手"),
+	("凶残", "兇殘"),
+	("凶恶", "兇惡"),
+	("凶猛", "兇猛"),
+	("标签", "標籤"),
+	("书签", "書籤"),
+	("抽签", "抽籤"),
+	("特征", "特徵"),
+	("象征", "象徵"),
+	("征兆", "徵兆"),
+	("采取", "採取"),
+	("采访", "採訪"),
+	("采用", "採用"),
+	("采集", "採集"),
+	("手表", "手錶"),
+	("心脏", "心臟"),
+	("内脏", "內臟"),
+	("肝脏", "肝臟"),
+	("其余", "其餘"),
+	("剩余", "剩餘"),
+	("多余", "多餘"),
+	("业余", "業餘"),
+	("斗罗", "斗羅"),
+	("北斗", "北斗"),
+	("斗篷", "斗篷"),
+	("漏斗", "漏斗"),
+	("熨斗", "熨斗"),
+	("斗笠", "斗笠"),
+	("星斗", "星斗"),
+	("斗胆", "斗膽"),
+	("泰斗", "泰斗"),
+	("南斗", "南斗"),
+	("烟斗", "煙斗"),
+	("车斗", "車斗"),
+	("翻斗", "翻斗"),
+	("筋斗", "筋斗"),
+	("阿斗", "阿斗"),
+	("斗室", "斗室"),
+	("斗拱", "斗拱"),
+	("斗转星移", "斗轉星移"),
+	("八斗", "八斗"),
+	("斗米", "斗米"),
+	("小丑", "小丑"),
+	("丑时", "丑時"),
+	("丑角", "丑角"),
+	("丑牛", "丑牛"),
+	("丑年", "丑年"),
+	("冲水", "沖水"),
+	("冲洗", "沖洗"),
+	("冲泡", "沖泡"),
+	("冲澡", "沖澡"),
+	("批准", "批准"),
+	("准许", "准許"),
+	("不准", "不准"),
+	("划船", "划船"),
+	("放松", "放鬆"),
+	("轻松", "輕鬆"),
+	("松开", "鬆開"),
+	("宽松", "寬鬆"),
+	("蓬松", "蓬鬆"),
+	("松懈", "鬆懈"),
+	("朴素", "樸素"),
+	("简朴", "簡樸"),
+	("纯朴", "純樸"),
+	("范围", "範圍"),
+	("模范", "模範"),
+	("示范", "示範"),
+	("规范", "規範"),
+	("典范", "典範"),
+	("防范", "防範"),
+	("范例", "範例"),
+	("范畴", "範疇"),
+	("卷入", "捲入"),
+	("席卷", "席捲"),
+	("卷起", "捲起"),
+	("防御", "防禦"),
+	("抵御", "抵禦"),
+	("呼吁", "呼籲"),
+	("游戏", "遊戲"),
+	("旅游", "旅遊"),
+	("游客", "遊客"),
+	("游乐", "遊樂"),
+	("游玩", "遊玩"),
+	("游荡", "遊蕩"),
+	("游历", "遊歷"),
+	("游记", "遊記"),
+	("杂志", "雜誌"),
+];
+
+/// Words converted as a whole when going from Traditional to Simplified.
+pub const T2S_PHRASES: &[(&str, &str)] = &[
+	("乾坤", "乾坤"),
+	("乾隆", "乾隆"),
+	("著名", "著名"),
+	("著作", "著作"),
+	("顯著", "显著"),
+	("名著", "名著"),
+	("原著", "原著"),
+	("著者", "著者"),
+	("編著", "编著"),
+	("著稱", "著称"),
+	("巨著", "巨著"),
+	("土著", "土著"),
+	("反覆", "反复"),
+	("答覆", "答复"),
+	("回覆", "回复"),
+	("批覆", "批复"),
+];
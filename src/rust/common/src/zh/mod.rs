@@ -0,0 +1,247 @@
+//! Conversion between Simplified and Traditional Chinese.
+//!
+//! Words listed in the phrase tables of [`dictionary`] are converted as a
+//! whole, longest match first, and everything else character by character,
+//! the way OpenCC does with its default dictionaries.
+//!
+//! Sources serving a single script let users pick the one they read with the
+//! `chineseScript` setting:
+//!
+//! ```json
+//! {
+//!     "type": "select",
+//!     "key": "chineseScript",
+//!     "title": "简繁转换",
+//!     "values": ["original", "simplified", "traditional"],
+//!     "titles": ["原文", "简体", "繁體"],
+//!     "default": "original"
+//! }
+//! ```
+//!
+//! Search queries are converted to the script of the site whatever the
+//! setting, so a title is found in either script.
+use aidoku::{
+	std::{defaults::defaults_get, String, Vec},
+	Chapter, Manga, MangaPageResult,
+};
+
+pub mod dictionary;
+
+use dictionary::{CHARACTERS, S2T_PHRASES, T2S_PHRASES, TRADITIONAL_VARIANTS};
+
+pub const SCRIPT_KEY: &str = "chineseScript";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Script {
+	Simplified,
+	Traditional,
+}
+
+struct Tables {
+	/// Sorted by the Simplified character.
+	s2t: Vec<(char, char)>,
+	/// Sorted by the Traditional character.
+	t2s: Vec<(char, char)>,
+}
+
+static mut TABLES: Option<Tables> = None;
+
+fn pairs(table: &str) -> impl Iterator<Item = (char, char)> + '_ {
+	let mut chars = table.chars();
+	core::iter::from_fn(move || Some((chars.next()?, chars.next()?)))
+}
+
+fn sorted(mut table: Vec<(char, char)>) -> Vec<(char, char)> {
+	// stable, so the first pair listed for a character wins
+	table.sort_by_key(|&(from, _)| from);
+	table.dedup_by_key(|&mut (from, _)| from);
+	table
+}
+
+#[allow(static_mut_refs)]
+fn tables() -> &'static Tables {
+	unsafe {
+		TABLES.get_or_insert_with(|| Tables {
+			s2t: sorted(pairs(CHARACTERS).collect()),
+			t2s: sorted(
+				pairs(CHARACTERS)
+					.map(|(simplified, traditional)| (traditional, simplified))
+					.chain(pairs(TRADITIONAL_VARIANTS))
+					.collect(),
+			),
+		})
+	}
+}
+
+fn convert_with(text: &str, phrases: &[(&str, &str)], table: &[(char, char)]) -> String {
+	let mut result = String::with_capacity(text.len());
+	let mut rest = text;
+	while let Some(c) = rest.chars().next() {
+		if c.is_ascii() {
+			result.push(c);
+			rest = &rest[1..];
+			continue;
+		}
+		let phrase = phrases
+			.iter()
+			.filter(|(from, _)| rest.starts_with(from))
+			.max_by_key(|(from, _)| from.len());
+		if let Some((from, to)) = phrase {
+			result.push_str(to);
+			rest = &rest[from.len()..];
+			continue;
+		}
+		match table.binary_search_by_key(&c, |&(from, _)| from) {
+			Ok(idx) => result.push(table[idx].1),
+			Err(_) => result.push(c),
+		}
+		rest = &rest[c.len_utf8()..];
+	}
+	result
+}
+
+pub fn to_traditional(text: &str) -> String {
+	convert_with(text, S2T_PHRASES, &tables().s2t)
+}
+
+pub fn to_simplified(text: &str) -> String {
+	convert_with(text, T2S_PHRASES, &tables().t2s)
+}
+
+/// Converts `text` to `script`. Text already in that script is left as it is,
+/// apart from Traditional variants being normalised.
+pub fn convert(text: &str, script: Script) -> String {
+	match script {
+		Script::Simplified => to_simplified(text),
+		Script::Traditional => to_traditional(text),
+	}
+}
+
+/// The script chosen with the `chineseScript` setting, `None` to keep the text
+/// of the site.
+pub fn preferred_script() -> Option<Script> {
+	let value = defaults_get(SCRIPT_KEY)
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.unwrap_or_default();
+	match value.as_str() {
+		"simplified" => Some(Script::Simplified),
+		"traditional" => Some(Script::Traditional),
+		_ => None,
+	}
+}
+
+/// Converts what a source returns from the script of its site to the one the
+/// user picked.
+#[derive(Clone, Copy)]
+pub struct Converter {
+	pub site: Script,
+	pub target: Option<Script>,
+}
+
+impl Converter {
+	/// A converter for a site serving `site`, targeting the script from the
+	/// settings.
+	pub fn new(site: Script) -> Self {
+		Self {
+			site,
+			target: preferred_script(),
+		}
+	}
+
+	fn target(&self) -> Option<Script> {
+		self.target.filter(|&target| target != self.site)
+	}
+
+	pub fn text(&self, text: &str) -> String {
+		match self.target() {
+			Some(target) => convert(text, target),
+			None => String::from(text),
+		}
+	}
+
+	fn apply(&self, text: &mut String) {
+		if let Some(target) = self.target() {
+			*text = convert(text, target);
+		}
+	}
+
+	/// Converts the title, credits, description and tags.
+	pub fn manga(&self, manga: &mut Manga) {
+		if self.target().is_none() {
+			return;
+		}
+		self.apply(&mut manga.title);
+		self.apply(&mut manga.author);
+		self.apply(&mut manga.artist);
+		self.apply(&mut manga.description);
+		for category in manga.categories.iter_mut() {
+			self.apply(category);
+		}
+	}
+
+	pub fn manga_page(&self, result: &mut MangaPageResult) {
+		for manga in result.manga.iter_mut() {
+			self.manga(manga);
+		}
+	}
+
+	/// Converts the chapter names and scanlators.
+	pub fn chapters(&self, chapters: &mut [Chapter]) {
+		if self.target().is_none() {
+			return;
+		}
+		for chapter in chapters.iter_mut() {
+			self.apply(&mut chapter.title);
+			self.apply(&mut chapter.scanlator);
+		}
+	}
+
+	/// Converts a search query to the script of the site.
+	pub fn query(&self, query: &str) -> String {
+		convert(query, self.site)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Words with a character that has more than one Traditional form.
+	const WORDS: &[(&str, &str)] = &[
+		("皇后", "皇后"),
+		("以后", "以後"),
+		("干净", "乾淨"),
+		("饼干", "餅乾"),
+		("干部", "幹部"),
+		("天干", "天干"),
+		("公里", "公里"),
+		("这里", "這裡"),
+		("复杂", "複雜"),
+		("反复", "反覆"),
+		("复仇", "復仇"),
+		("日历", "日曆"),
+		("历史", "歷史"),
+		("北斗", "北斗"),
+		("战斗", "戰鬥"),
+		("小丑", "小丑"),
+		("丑陋", "醜陋"),
+	];
+
+	#[test]
+	fn converts_to_traditional() {
+		for (simplified, traditional) in WORDS {
+			assert_eq!(to_traditional(simplified), *traditional, "{simplified}");
+		}
+		assert_eq!(to_traditional("皇后的头发很干净"), "皇后的頭髮很乾淨");
+		assert_eq!(to_traditional("Vol.2 第12话"), "Vol.2 第12話");
+	}
+
+	#[test]
+	fn converts_to_simplified() {
+		for (simplified, traditional) in WORDS {
+			assert_eq!(to_simplified(traditional), *simplified, "{traditional}");
+		}
+		assert_eq!(to_simplified("乾隆的著作"), "乾隆的著作");
+		assert_eq!(to_simplified("這裏"), "这里");
+	}
+}
@@ -1,9 +1,19 @@
 use aidoku::{
-	error::Result, prelude::*, std::html::Node, std::net::HttpMethod, std::net::Request,
-	std::ArrayRef, std::ObjectRef, std::String, std::Vec, Manga, MangaContentRating, MangaStatus,
-	MangaViewer,
+	error::Result, prelude::*, std::defaults::defaults_get, std::html::Node,
+	std::net::HttpMethod, std::net::Request, std::ArrayRef, std::ObjectRef, std::String,
+	std::Vec, Manga, MangaContentRating, MangaPageResult, MangaStatus, MangaViewer,
 };
 
+/// Collections of chapters, as the `type` of their tags and the path of their
+/// pages. Each of them is shown as a manga with its chapters as members.
+pub const COLLECTIONS: [(&str, &str); 5] = [
+	("Series", "series"),
+	("Anthology", "anthologies"),
+	("Issue", "issues"),
+	("Doujin", "doujins"),
+	("General", "tags"),
+];
+
 pub fn urlencode(string: String) -> String {
 	let mut result: Vec<u8> = Vec::with_capacity(string.len() * 3);
 	let hex = "0123456789abcdef".as_bytes();
@@ -90,7 +100,10 @@ pub fn get_manga_details(id: String) -> Result<Manga> {
 		Err(_) => String::new(),
 	};
 
-	let tags = json.get("tags").as_array()?;
+	let tags = match json.get("tags").as_array() {
+		Ok(tags) => tags,
+		Err(_) => ArrayRef::new(),
+	};
 
 	let author = match find_in_array(&tags, String::from("Author")) {
 		Ok(authors) => {
@@ -168,3 +181,64 @@ pub fn string_replace(string: String, replace: char, with: char) -> String {
 	}
 	result
 }
+
+pub fn skip_images() -> bool {
+	match defaults_get("skipImages") {
+		Ok(bool) => bool.as_bool().unwrap_or(false),
+		Err(_) => false,
+	}
+}
+
+/// Returns the manga of a collection, without fetching its details when
+/// images are skipped.
+pub fn get_collection_manga(id: String, title: String, skip_images: bool) -> Result<Manga> {
+	if skip_images {
+		Ok(Manga {
+			id,
+			title,
+			status: MangaStatus::Completed,
+			nsfw: MangaContentRating::Nsfw,
+			..Default::default()
+		})
+	} else {
+		get_manga_details(id)
+	}
+}
+
+/// Lists the collections of a type from its index, e.g. `/doujins.json`,
+/// where they are grouped by their first letter. An index page holds hundreds
+/// of them, so they are listed without their details whatever the
+/// `skipImages` setting.
+pub fn get_collection_list(path: &str, page: i32) -> Result<MangaPageResult> {
+	let url = format!(
+		"https://dynasty-scans.com/{}.json?page={}",
+		path,
+		i32_to_string(page)
+	);
+	let json = Request::new(url.as_str(), HttpMethod::Get)
+		.json()?
+		.as_object()?;
+
+	let mut manga_arr: Vec<Manga> = Vec::new();
+	for group in json.get("tags").as_array()? {
+		for entries in group.as_object()?.values() {
+			for entry in entries.as_array()? {
+				let entry_object = entry.as_object()?;
+				let id = format!(
+					"{}/{}",
+					path,
+					entry_object.get("permalink").as_string()?.read()
+				);
+				let title = entry_object.get("name").as_string()?.read();
+				if let Ok(manga) = get_collection_manga(id, title, true) {
+					manga_arr.push(manga);
+				}
+			}
+		}
+	}
+
+	Ok(MangaPageResult {
+		manga: manga_arr,
+		has_more: page < json.get("total_pages").as_int().unwrap_or(0) as i32,
+	})
+}
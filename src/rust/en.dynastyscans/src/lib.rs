@@ -1,8 +1,13 @@
 #![no_std]
 use aidoku::{
-	error::Result, prelude::*, std::defaults::defaults_get, std::net::HttpMethod,
-	std::net::Request, std::ArrayRef, std::String, std::Vec, Chapter, DeepLink, Filter, FilterType,
-	Listing, Manga, MangaContentRating, MangaPageResult, MangaStatus, Page,
+	error::{AidokuError, AidokuErrorKind, Result},
+	prelude::*,
+	std::net::HttpMethod,
+	std::net::Request,
+	std::ArrayRef,
+	std::String,
+	std::Vec,
+	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaPageResult, Page,
 };
 
 mod helper;
@@ -82,10 +87,7 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 		url.push_str("&classes%5B%5D=Series");
 	}
 
-	let skip_images = match defaults_get("skipImages") {
-		Ok(bool) => bool.as_bool().unwrap_or(false),
-		Err(_) => false,
-	};
+	let skip_images = helper::skip_images();
 
 	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
 	for result in html.select(".chapter-list a.name").array() {
@@ -97,22 +99,10 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 		if manga_url.is_empty() {
 			continue;
 		}
-		if skip_images {
-			let title = result_node.text().read();
-			manga_arr.push(Manga {
-				id: String::from(&manga_url[1..]),
-				title,
-				status: MangaStatus::Completed,
-				nsfw: MangaContentRating::Nsfw,
-				..Default::default()
-			});
-		} else {
-			match helper::get_manga_details(String::from(&manga_url[1..])) {
-				Ok(manga) => {
-					manga_arr.push(manga);
-				}
-				Err(_) => continue,
-			}
+		let id = String::from(&manga_url[1..]);
+		let title = result_node.text().read();
+		if let Ok(manga) = helper::get_collection_manga(id, title, skip_images) {
+			manga_arr.push(manga);
 		}
 	}
 
@@ -136,18 +126,19 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 
 #[get_manga_listing]
 fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
-	match listing.name.as_str() {
-		"Recently Added" => {}
-		_ => return get_manga_list(Vec::new(), page),
+	if listing.name != "Recently Added" {
+		// the other listings are named after the index of their collections
+		let path = listing.name.to_lowercase();
+		return match helper::COLLECTIONS.iter().find(|(_, p)| *p == path) {
+			Some((_, path)) => helper::get_collection_list(path, page),
+			None => get_manga_list(Vec::new(), page),
+		};
 	}
 
 	let mut added_ids: Vec<String> = Vec::new();
 	let mut manga_arr: Vec<Manga> = Vec::new();
 
-	let skip_images = match defaults_get("skipImages") {
-		Ok(bool) => bool.as_bool().unwrap_or(false),
-		Err(_) => false,
-	};
+	let skip_images = helper::skip_images();
 
 	let json = Request::new(
 		format!(
@@ -161,57 +152,30 @@ fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
 	.as_object()?;
 	for chapter in json.get("chapters").as_array()? {
 		let chapter_object = chapter.as_object()?;
-		let result_object;
 		let tags = chapter_object.get("tags").as_array()?;
-		let series = helper::find_in_array(&tags, String::from("Series"))?;
-		if !series.is_empty() {
-			result_object = series[0].clone();
-		} else {
-			// anthology or doujin
-			let anthologies = helper::find_in_array(&tags, String::from("Anthology"))?;
-			if !anthologies.is_empty() {
-				result_object = anthologies[0].clone();
-			} else {
-				// has to be a doujin
-				let doujins = helper::find_in_array(&tags, String::from("Doujin"))?;
-				if !doujins.is_empty() {
-					result_object = doujins[0].clone();
-				} else {
-					// idek
-					continue;
-				}
-			}
-		}
-		let mut id = String::from(
-			match result_object.get("type").as_string()?.read().as_str() {
-				"Series" => "series",
-				"Anthology" => "anthologies",
-				"Doujin" => "doujins",
-				_ => continue,
-			},
+		// shown as the first collection the chapter belongs to, not as its tags
+		let collection = helper::COLLECTIONS
+			.iter()
+			.filter(|(type_name, _)| *type_name != "General")
+			.find_map(|(type_name, path)| {
+				let found = helper::find_in_array(&tags, String::from(*type_name)).ok()?;
+				found.into_iter().next().map(|object| (path, object))
+			});
+		let Some((path, result_object)) = collection else {
+			continue;
+		};
+		let id = format!(
+			"{}/{}",
+			path,
+			result_object.get("permalink").as_string()?.read()
 		);
-		id.push('/');
-		id.push_str(result_object.get("permalink").as_string()?.read().as_str());
 		if added_ids.contains(&id.clone()) {
 			continue;
 		}
 		added_ids.push(id.clone());
-		if skip_images {
-			let title = result_object.get("name").as_string()?.read();
-			manga_arr.push(Manga {
-				id: id.clone(),
-				title,
-				status: MangaStatus::Completed,
-				nsfw: MangaContentRating::Nsfw,
-				..Default::default()
-			});
-		} else {
-			match helper::get_manga_details(id.clone()) {
-				Ok(manga) => {
-					manga_arr.push(manga);
-				}
-				Err(_) => continue,
-			}
+		let title = result_object.get("name").as_string()?.read();
+		if let Ok(manga) = helper::get_collection_manga(id, title, skip_images) {
+			manga_arr.push(manga);
 		}
 	}
 	Ok(MangaPageResult {
@@ -227,12 +191,26 @@ fn get_manga_details(id: String) -> Result<Manga> {
 
 #[get_chapter_list]
 fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
-	let url = format!("https://dynasty-scans.com/{}.json", &id);
-	let json = Request::new(url.as_str(), HttpMethod::Get)
-		.json()?
-		.as_object()?;
-
-	let json_chapters = json.get("taggings").as_array()?;
+	// doujins and tags split their chapters in pages, all of them are loaded
+	// so none go missing from the list
+	let mut json_chapters = Vec::new();
+	let mut page = 1;
+	loop {
+		let url = format!(
+			"https://dynasty-scans.com/{}.json?page={}",
+			&id,
+			helper::i32_to_string(page)
+		);
+		let json = Request::new(url.as_str(), HttpMethod::Get)
+			.json()?
+			.as_object()?;
+		json_chapters.extend(json.get("taggings").as_array()?);
+		let total_pages = json.get("total_pages").as_int().unwrap_or(1) as i32;
+		if page >= total_pages {
+			break;
+		}
+		page += 1;
+	}
 	let mut chapters = Vec::new();
 
 	let mut volume_on: f32 = -1.0;
@@ -262,8 +240,9 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 			.get("released_on")
 			.as_date("YYYY-MM-dd", None, None)
 			.unwrap_or(0.0);
+		// only chapters of a series are named after it, e.g. `<series>_ch12_5`
 		let chapter_num_pos = id.split('/').last().unwrap().len() + 3;
-		let chapter_num = if chapter_num_pos >= chapter_id.len() {
+		let chapter_num = if !id.starts_with("series/") || chapter_num_pos >= chapter_id.len() {
 			-1.0
 		} else {
 			helper::string_replace(String::from(&chapter_id[chapter_num_pos..]), '_', '.')
@@ -331,19 +310,23 @@ fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 
 #[handle_url]
 pub fn handle_url(url: String) -> Result<DeepLink> {
-	let mut count = 0;
-	let mut index = 0;
-	for (i, c) in url.chars().enumerate() {
-		if count == 3 {
-			index = i;
-			break;
-		}
-		if c == '/' {
-			count += 1;
-		}
+	// https://dynasty-scans.com/doujins/kancolle?page=2
+	let path = url
+		.split(['?', '#'])
+		.next()
+		.unwrap_or_default()
+		.splitn(4, '/')
+		.nth(3)
+		.unwrap_or_default();
+	let mut segments = path.split('/');
+	let kind = segments.next().unwrap_or_default();
+	let permalink = segments.next().unwrap_or_default().trim_end_matches(".json");
+	if permalink.is_empty() || !helper::COLLECTIONS.iter().any(|(_, p)| *p == kind) {
+		return Err(AidokuError {
+			reason: AidokuErrorKind::Unimplemented,
+		});
 	}
-	let manga_id = &url[index..];
-	let manga = get_manga_details(String::from(manga_id))?;
+	let manga = get_manga_details(format!("{}/{}", kind, permalink))?;
 	Ok(DeepLink {
 		manga: Some(manga),
 		chapter: None,
@@ -54,7 +54,17 @@ fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 	let html = Request::new(url.as_str(), HttpMethod::Get)
 		.header("Cookie", "readway=2")
 		.html()?;
-	parser::get_page_list(html)
+	let pages = parser::get_page_list(html)?;
+	if !pages.is_empty() {
+		return Ok(pages);
+	}
+
+	// paged chapters only load their images through the desktop reader
+	let url = format!("{BASE_URL}/manga/{}/1.html", chapter_id);
+	let document = Request::new(url.as_str(), HttpMethod::Get)
+		.header("Cookie", "isAdult=1")
+		.string()?;
+	parser::resolve_pages(&url, &document)
 }
 
 #[modify_image_request]
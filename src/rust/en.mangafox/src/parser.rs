@@ -1,14 +1,15 @@
 use aidoku::{
-	error::Result,
+	error::{AidokuError, AidokuErrorKind, Result},
 	helpers::{substring::Substring, uri::encode_uri},
 	prelude::*,
-	std::{html::Node, String, Vec},
+	std::{html::Node, net::Request, String, Vec},
 	Chapter, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
 	MangaViewer, Page,
 };
 
 extern crate alloc;
 use alloc::string::ToString;
+use common::packer;
 
 use crate::BASE_URL;
 
@@ -177,8 +178,6 @@ pub fn parse_chapters(obj: Node) -> Result<Vec<Chapter>> {
 }
 
 pub fn get_page_list(html: Node) -> Result<Vec<Page>> {
-	// Unpacker script
-	// https://github.com/Skittyblock/aidoku-community-sources/commit/616199e0ccb3704c45438b9f863641e1aa0cfa19
 	let mut pages: Vec<Page> = Vec::new();
 	for (index, item) in html.select("#viewer img").array().enumerate() {
 		let obj = item.as_node().expect("");
@@ -192,17 +191,129 @@ pub fn get_page_list(html: Node) -> Result<Vec<Page>> {
 			..Default::default()
 		});
 	}
-	if pages.is_empty() {
-		pages.push(Page {
-			index: 1,
-			url: "https://i.imgur.com/5mNXCgV.png".to_string(),
-			..Default::default()
-		});
-	}
 
 	Ok(pages)
 }
 
+/// Reads a number assigned to a variable in an inline script, e.g.
+/// `var imagecount=24;`.
+fn read_script_number(document: &str, name: &str) -> Option<i32> {
+	document.match_indices(name).find_map(|(idx, _)| {
+		let rest = document[idx + name.len()..].trim_start();
+		let rest = rest.strip_prefix('=')?.trim_start();
+		let end = rest
+			.find(|c: char| !c.is_ascii_digit())
+			.unwrap_or(rest.len());
+		rest[..end].parse().ok()
+	})
+}
+
+/// The key `chapterfun.ashx` expects, built by a packed script of the reader
+/// page as `var guidkey=''+'a'+'b'+...;`. Most chapters also accept an empty
+/// one.
+fn read_chapter_key(document: &str) -> String {
+	let Some(start) = document.find("eval(function(p,a,c,k,e,d)") else {
+		return String::new();
+	};
+	let Ok(script) = packer::unpack(&document[start..]) else {
+		return String::new();
+	};
+	let Some(value) = script
+		.substring_after("guidkey=")
+		.and_then(|v| v.substring_before(";"))
+	else {
+		return String::new();
+	};
+	value
+		.split('\'')
+		.skip(1)
+		.step_by(2)
+		.collect::<Vec<&str>>()
+		.join("")
+}
+
+/// Reads the image urls out of a `chapterfun.ashx` response, a packed script
+/// that unpacks to `var pix="<base>";var pvalue=["<file>",...];...`.
+fn parse_chapterfun(response: &str) -> Result<Vec<String>> {
+	let script = packer::unpack(response)?;
+	let base = script
+		.substring_after("pix=\"")
+		.and_then(|v| v.substring_before("\""))
+		.unwrap_or_default();
+	let files = script
+		.substring_after("pvalue=[")
+		.and_then(|v| v.substring_before("]"))
+		.unwrap_or_default();
+
+	Ok(files
+		.split(',')
+		.map(|file| file.trim().trim_matches('"'))
+		.filter(|file| !file.is_empty())
+		.map(|file| {
+			let url = format!("{}{}", base, file);
+			if url.starts_with("//") {
+				format!("https:{}", url)
+			} else {
+				url
+			}
+		})
+		.collect())
+}
+
+/// Resolves the pages of a chapter the way the desktop reader does: it asks
+/// `chapterfun.ashx` for the images page by page, a few at a time.
+pub fn resolve_pages(reader_url: &str, document: &str) -> Result<Vec<Page>> {
+	let not_found = || AidokuError {
+		reason: AidokuErrorKind::DefaultNotFound,
+	};
+	let chapter_id = read_script_number(document, "chapterid").ok_or_else(not_found)?;
+	let image_count = read_script_number(document, "imagecount").ok_or_else(not_found)?;
+	let key = read_chapter_key(document);
+	let chapter_dir = reader_url.substring_before_last("/").unwrap_or(reader_url);
+
+	let mut urls: Vec<String> = Vec::new();
+	// each response holds at least one image, so this many requests suffice
+	for _ in 0..image_count {
+		if urls.len() >= image_count as usize {
+			break;
+		}
+		let url = format!(
+			"{}/chapterfun.ashx?cid={}&page={}&key={}",
+			chapter_dir,
+			chapter_id,
+			urls.len() + 1,
+			key
+		);
+		let response = Request::get(url)
+			.header("Referer", reader_url)
+			.header("X-Requested-With", "XMLHttpRequest")
+			.string()?;
+		let count = urls.len();
+		for url in parse_chapterfun(&response)? {
+			if !urls.contains(&url) {
+				urls.push(url);
+			}
+		}
+		if urls.len() == count {
+			break;
+		}
+	}
+
+	if urls.is_empty() {
+		return Err(not_found());
+	}
+	Ok(urls
+		.into_iter()
+		.take(image_count as usize)
+		.enumerate()
+		.map(|(index, url)| Page {
+			index: index as i32,
+			url,
+			..Default::default()
+		})
+		.collect())
+}
+
 pub fn get_filtered_url(filters: Vec<Filter>, page: i32) -> String {
 	let mut is_searching = false;
 	let mut search_query = String::new();
@@ -316,3 +427,39 @@ pub fn is_last_page(html: Node) -> bool {
 	}
 	false
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const READER_PAGE: &str = include_str!("../../common/fixtures/mangafox.reader.html");
+	const CHAPTERFUN: &str = include_str!("../../common/fixtures/mangafox.chapterfun.js");
+
+	#[test]
+	fn reads_the_reader_page() {
+		assert_eq!(read_script_number(READER_PAGE, "chapterid"), Some(245786));
+		assert_eq!(read_script_number(READER_PAGE, "imagecount"), Some(23));
+		assert_eq!(read_script_number(READER_PAGE, "comicid"), Some(11362));
+		assert_eq!(read_script_number(READER_PAGE, "mangaid"), None);
+		assert_eq!(read_chapter_key(READER_PAGE), "7b0f5a31ce9d2648");
+	}
+
+	#[test]
+	fn reads_no_key_without_the_packed_script() {
+		assert_eq!(read_chapter_key("var chapterid =245786;"), "");
+	}
+
+	#[test]
+	fn parses_chapterfun() {
+		let token = "?token=8c2c1e0d5b4ad3e5a2b1f0c8e4d6a9b7&ttl=1760900000";
+		let base = "https://zjcdn.mangafox.me/store/manga/11362/01-001.0/compressed";
+		assert_eq!(
+			parse_chapterfun(CHAPTERFUN).unwrap(),
+			[
+				format!("{base}/i001.jpg{token}"),
+				format!("{base}/i002.jpg{token}"),
+			]
+		);
+		assert!(parse_chapterfun("var pix=\"\";").is_err());
+	}
+}
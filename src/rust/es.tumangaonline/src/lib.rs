@@ -1,6 +1,7 @@
 #![no_std]
+#![allow(static_mut_refs)]
 use aidoku::{
-	error::Result,
+	error::{AidokuError, Result},
 	helpers::substring::Substring,
 	prelude::*,
 	std::{defaults::defaults_get, html::Node, net::HttpMethod, net::Request, String, Vec},
@@ -8,6 +9,7 @@ use aidoku::{
 	MangaViewer, Page,
 };
 
+use common::error::{pages_or_notice, SourceError};
 use regex::Regex;
 
 extern crate alloc;
@@ -36,6 +38,16 @@ pub unsafe extern "C" fn initialize() {
 	set_rate_limit_period(60);
 }
 
+/// The scanlation group to keep when a chapter has uploads from several,
+/// lowercased for matching.
+fn get_preferred_group() -> Option<String> {
+	defaults_get("preferredGroup")
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.map(|v| v.trim().to_lowercase())
+		.ok()
+		.filter(|v| !v.is_empty())
+}
+
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	let mut url = BASE_URL.to_owned() + "library?_pg=1&page=" + &page.to_string();
@@ -263,7 +275,13 @@ fn parse_chapter(element: Node) -> Chapter {
 		.read();
 	let id = url.strip_prefix(BASE_URL).unwrap_or(&url).to_owned();
 
-	let scanlator = element.select("div.col-md-6.text-truncate").text().read();
+	let scanlator = String::from(
+		element
+			.select("div.col-md-6.text-truncate")
+			.text()
+			.read()
+			.trim(),
+	);
 
 	let date_updated = element
 		.select("span.badge.badge-primary.p-2")
@@ -280,76 +298,120 @@ fn parse_chapter(element: Node) -> Chapter {
 	}
 }
 
-fn get_redirect_read_page(html: Node) -> Result<Node> {
-	let url = html.base_uri().to_string();
+/// Upper bound on the redirect pages between a chapter link and its reader.
+const MAX_REDIRECTS: usize = 10;
+
+struct RedirectPatterns {
+	params: Regex,
+	action: Regex,
+	location_replace: Regex,
+	redirect_url: Regex,
+	opener_replace: Regex,
+}
+
+static mut REDIRECT_PATTERNS: Option<RedirectPatterns> = None;
+
+fn redirect_patterns() -> &'static RedirectPatterns {
+	unsafe {
+		if REDIRECT_PATTERNS.is_none() {
+			REDIRECT_PATTERNS = Some(RedirectPatterns {
+				params: Regex::new(r"\{\s*uniqid\s*:\s*'(.+)'\s*,\s*cascade\s*:\s*(.+)\s*\}")
+					.unwrap(),
+				action: Regex::new(r"form\.action\s*=\s*'(.+)'").unwrap(),
+				location_replace: Regex::new(r#"window\.location\.replace\(['"](.+)['"]\)"#)
+					.unwrap(),
+				redirect_url: Regex::new(r"redirectUrl\s*=\s*'(.+)'").unwrap(),
+				opener_replace: Regex::new(r#";[^.]location\.replace\(['"](.+)['"]\)"#).unwrap(),
+			});
+		}
+		REDIRECT_PATTERNS.as_ref().expect("patterns were just set")
+	}
+}
+
+enum Redirect {
+	Get(String),
+	Post { url: String, body: String },
+}
+
+/// The error for a chapter whose redirects loop or don't end within
+/// [`MAX_REDIRECTS`] pages, shown in the reader by [`get_page_list`].
+fn redirect_chain_too_long() -> AidokuError {
+	SourceError::TooManyRedirects.into()
+}
+
+/// Finds where an intermediate page of the reader sends the browser next.
+fn find_redirect(html: &Node) -> Option<Redirect> {
 	let script = html.select("script").to_string();
+	let patterns = redirect_patterns();
 
 	if script.contains("uniqid") {
-		let regex_params =
-			Regex::new(r"\{\s*uniqid\s*:\s*'(.+)'\s*,\s*cascade\s*:\s*(.+)\s*\}").unwrap();
-		let regex_action = Regex::new(r"form\.action\s*=\s*'(.+)'").unwrap();
-
 		if let (Some(params), Some(action)) = (
-			regex_params.captures(&script),
-			regex_action.captures(&script),
+			patterns.params.captures(&script),
+			patterns.action.captures(&script),
 		) {
-			let parameters = format!("uniqid={}&cascade={}", &params[1], &params[2]);
-			let request = Request::new(&action[1], HttpMethod::Post)
-				.header("User-Agent", USER_AGENT)
-				.header("Referer", &url)
-				.body(parameters)
-				.html()?;
-
-			return get_redirect_read_page(request);
+			return Some(Redirect::Post {
+				url: String::from(&action[1]),
+				body: format!("uniqid={}&cascade={}", &params[1], &params[2]),
+			});
 		}
 	}
 
 	if script.contains("window.location.replace") {
-		let regex_redirect = Regex::new(r#"window\.location\.replace\(['"](.+)['"]\)"#).unwrap();
-
-		if let Some(url) = regex_redirect.captures(&script) {
-			let request = Request::new(&url[1], HttpMethod::Get)
-				.header("User-Agent", USER_AGENT)
-				.html()?;
-
-			return get_redirect_read_page(request);
+		if let Some(url) = patterns.location_replace.captures(&script) {
+			return Some(Redirect::Get(String::from(&url[1])));
 		}
 	}
 
 	if script.contains("redirectUrl") {
-		let regex_redirect = Regex::new(r"redirectUrl\s*=\s*'(.+)'").unwrap();
-
-		if let Some(url) = regex_redirect.captures(&script) {
-			let request = Request::new(&url[1], HttpMethod::Get)
-				.header("User-Agent", USER_AGENT)
-				.html()?;
-
-			return get_redirect_read_page(request);
+		if let Some(url) = patterns.redirect_url.captures(&script) {
+			return Some(Redirect::Get(String::from(&url[1])));
 		}
 	}
 
 	let input_redir = html.select("input#redir").text().read();
 	if !input_redir.is_empty() {
-		let request = Request::new(&input_redir, HttpMethod::Get)
-			.header("User-Agent", USER_AGENT)
-			.html()?;
-
-		return get_redirect_read_page(request);
+		return Some(Redirect::Get(input_redir));
 	}
 
 	if script.contains("window.opener") && script.contains("location.replace") {
-		let regex_redirect = Regex::new(r#";[^.]location\.replace\(['"](.+)['"]\)"#).unwrap();
+		if let Some(url) = patterns.opener_replace.captures(&script) {
+			return Some(Redirect::Get(String::from(&url[1])));
+		}
+	}
 
-		if let Some(url) = regex_redirect.captures(&script) {
-			let request = Request::new(&url[1], HttpMethod::Get)
-				.header("User-Agent", USER_AGENT)
-				.html()?;
+	None
+}
+
+/// Follows the redirect pages in front of the reader, giving up on loops and
+/// on chains longer than [`MAX_REDIRECTS`].
+fn get_redirect_read_page(mut html: Node) -> Result<Node> {
+	let mut visited: Vec<String> = Vec::new();
 
-			return get_redirect_read_page(request);
+	loop {
+		let Some(redirect) = find_redirect(&html) else {
+			return Ok(html);
+		};
+		let target = match &redirect {
+			Redirect::Get(url) => url.clone(),
+			Redirect::Post { url, body } => format!("{url}?{body}"),
+		};
+		if visited.len() >= MAX_REDIRECTS || visited.contains(&target) {
+			return Err(redirect_chain_too_long());
 		}
-	}
+		visited.push(target);
 
-	Ok(html)
+		let referer = html.base_uri().read();
+		html = match redirect {
+			Redirect::Post { url, body } => Request::new(&url, HttpMethod::Post)
+				.header("User-Agent", USER_AGENT)
+				.header("Referer", &referer)
+				.body(body)
+				.html()?,
+			Redirect::Get(url) => Request::new(&url, HttpMethod::Get)
+				.header("User-Agent", USER_AGENT)
+				.html()?,
+		};
+	}
 }
 
 #[get_chapter_list]
@@ -365,6 +427,7 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 		.html()?;
 
 	let chapter_elements = html.select("div.chapters > ul.list-group li.p-0.list-group-item");
+	let preferred_group = get_preferred_group();
 
 	let mut chapters: Vec<Chapter> = Vec::new();
 
@@ -403,6 +466,7 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 			};
 
 			let scanlations = element.select("ul.chapter-list > li");
+			let mut uploads: Vec<Chapter> = Vec::new();
 			for scanlation in scanlations.array() {
 				let mut chapter = parse_chapter(
 					scanlation
@@ -411,8 +475,18 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 				);
 				chapter.title = title.clone();
 				chapter.chapter = chapter_num;
-				chapters.push(chapter);
+				uploads.push(chapter);
+			}
+			// keep only the preferred group's upload when it has one
+			if let Some(group) = &preferred_group {
+				if uploads
+					.iter()
+					.any(|c| c.scanlator.to_lowercase().contains(group))
+				{
+					uploads.retain(|c| c.scanlator.to_lowercase().contains(group));
+				}
 			}
+			chapters.extend(uploads);
 		}
 	}
 
@@ -421,6 +495,10 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 
 #[get_page_list]
 fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	pages_or_notice(fetch_page_list(chapter_id))
+}
+
+fn fetch_page_list(chapter_id: String) -> Result<Vec<Page>> {
 	let url = if chapter_id.starts_with("http") {
 		chapter_id
 	} else {
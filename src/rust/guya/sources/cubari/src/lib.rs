@@ -0,0 +1,44 @@
+#![no_std]
+use aidoku::{
+	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
+	MangaPageResult, Page,
+};
+
+use guya_template::{cubari, template};
+
+fn data() -> template::GuyaSiteData {
+	template::GuyaSiteData {
+		base_url: String::from("https://cubari.moe"),
+		..Default::default()
+	}
+}
+
+#[get_manga_list]
+fn get_manga_list(filters: Vec<Filter>, _page: i32) -> Result<MangaPageResult> {
+	cubari::get_manga_list(data(), filters)
+}
+
+#[get_manga_listing]
+fn get_manga_listing(_listing: Listing, _page: i32) -> Result<MangaPageResult> {
+	cubari::get_library(data(), None)
+}
+
+#[get_manga_details]
+fn get_manga_details(id: String) -> Result<Manga> {
+	cubari::get_manga_details(data(), id)
+}
+
+#[get_chapter_list]
+fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	cubari::get_chapter_list(data(), id)
+}
+
+#[get_page_list]
+fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+	cubari::get_page_list(data(), manga_id, chapter_id)
+}
+
+#[handle_url]
+fn handle_url(url: String) -> Result<DeepLink> {
+	cubari::handle_url(data(), url)
+}
@@ -0,0 +1,367 @@
+//! Cubari serves gists, imgur/imgchest albums and a few other sites in the same
+//! series format as the Guya API, with groups mapping to either an array of
+//! pages or a proxy path returning that array.
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, Result},
+	prelude::*,
+	std::{
+		defaults::{defaults_get, defaults_set},
+		html::Node,
+		net::{HttpMethod, Request},
+		ObjectRef, String, StringRef, ValueRef, Vec,
+	},
+	Chapter, DeepLink, Filter, FilterType, Manga, MangaPageResult, MangaStatus, MangaViewer, Page,
+};
+use alloc::vec;
+use base64::{engine::general_purpose, Engine as _};
+use common::net::CheckedRequest;
+
+use crate::template::GuyaSiteData;
+
+/// Sources understood by the Cubari proxy, as they appear in `/read/<source>/`.
+const PROXY_SOURCES: [&str; 6] = [
+	"gist", "imgur", "imgchest", "mangasee", "mangadex", "nhentai",
+];
+
+fn not_found() -> AidokuError {
+	AidokuError {
+		reason: AidokuErrorKind::DefaultNotFound,
+	}
+}
+
+/// Turns a Cubari, imgur, imgchest or gist link, or an already parsed
+/// `<source>/<slug>` id, into a manga id.
+pub fn parse_id(input: &str) -> Option<String> {
+	let input = input.trim().trim_end_matches('/');
+	// an id such as `gist/<slug>`, whose source would otherwise be read as a host
+	if !input.contains("://") {
+		if let Some((source, slug)) = input.split_once('/') {
+			if PROXY_SOURCES.contains(&source) && !slug.is_empty() && !slug.contains('/') {
+				return Some(format!("{source}/{slug}"));
+			}
+		}
+	}
+	let path = input
+		.split_once("://")
+		.map(|(_, rest)| rest)
+		.unwrap_or(input);
+	let (host, path) = path.split_once('/').unwrap_or(("", path));
+	let parts = path.split('/').collect::<Vec<&str>>();
+
+	let (source, slug) = match host.trim_start_matches("www.") {
+		"imgur.com" | "m.imgur.com" => match parts.as_slice() {
+			["a" | "gallery", id, ..] => ("imgur", *id),
+			_ => return None,
+		},
+		"imgchest.com" => match parts.as_slice() {
+			["p", id, ..] => ("imgchest", *id),
+			_ => return None,
+		},
+		"gist.github.com" | "gist.githubusercontent.com" => {
+			// the raw url of the file is what Cubari encodes as the gist slug,
+			// `/raw` without a file name serves the first file of the gist
+			let raw = if path.contains("/raw") {
+				format!("gist/{}", path)
+			} else {
+				format!("gist/{}/raw", path)
+			};
+			let slug = general_purpose::URL_SAFE_NO_PAD.encode(raw.as_bytes());
+			return Some(format!("gist/{slug}"));
+		}
+		"raw.githubusercontent.com" => {
+			let raw = format!("raw/{}", path);
+			let slug = general_purpose::URL_SAFE_NO_PAD.encode(raw.as_bytes());
+			return Some(format!("gist/{slug}"));
+		}
+		_ => match parts.as_slice() {
+			["read", source, slug, ..] | ["proxy", "api", source, "series", slug, ..] => {
+				(*source, *slug)
+			}
+			_ => return None,
+		},
+	};
+
+	if slug.is_empty() || !PROXY_SOURCES.contains(&source) {
+		return None;
+	}
+	Some(format!("{source}/{slug}"))
+}
+
+/// Decodes a gist slug into the raw url of the JSON file, if it is one of the
+/// `raw/...` or `gist/...` forms Cubari generates.
+fn gist_raw_url(slug: &str) -> Option<String> {
+	let normalized = slug.replace('-', "+").replace('_', "/");
+	let decoded = general_purpose::STANDARD_NO_PAD
+		.decode(normalized.trim_end_matches('='))
+		.ok()?;
+	let path = String::from_utf8(decoded).ok()?;
+
+	if let Some(path) = path.strip_prefix("raw/") {
+		Some(format!("https://raw.githubusercontent.com/{path}"))
+	} else {
+		path.strip_prefix("gist/")
+			.map(|path| format!("https://gist.githubusercontent.com/{path}"))
+	}
+}
+
+fn get_series(data: &GuyaSiteData, id: &str) -> Result<ObjectRef> {
+	let (source, slug) = id.split_once('/').ok_or_else(not_found)?;
+
+	if source == "gist" {
+		if let Some(url) = gist_raw_url(slug) {
+			if let Ok(json) = Request::new(url, HttpMethod::Get)
+				.header("User-Agent", "Aidoku")
+				.checked_json()
+				.and_then(|v| v.as_object())
+			{
+				return Ok(json);
+			}
+		}
+	}
+
+	let url = format!("{}/read/api/{source}/series/{slug}/", data.base_url);
+	Request::new(url, HttpMethod::Get)
+		.header("User-Agent", "Aidoku")
+		.checked_json()?
+		.as_object()
+}
+
+fn string_or_empty(value: ValueRef) -> String {
+	value.as_string().map(|v| v.read()).unwrap_or_default()
+}
+
+fn absolute_url(data: &GuyaSiteData, url: String) -> String {
+	if url.starts_with('/') {
+		format!("{}{}", data.base_url, url)
+	} else {
+		url
+	}
+}
+
+fn reader_url(data: &GuyaSiteData, id: &str) -> String {
+	format!("{}/read/{id}/", data.base_url)
+}
+
+fn parse_series(data: &GuyaSiteData, id: String, json: &ObjectRef) -> Manga {
+	let description_raw = string_or_empty(json.get("description"));
+	let description = Node::new_fragment(description_raw.as_bytes())
+		.map(|node| node.text().read())
+		.unwrap_or(description_raw);
+
+	Manga {
+		title: string_or_empty(json.get("title")),
+		cover: absolute_url(data, string_or_empty(json.get("cover"))),
+		description,
+		author: string_or_empty(json.get("author")),
+		artist: string_or_empty(json.get("artist")),
+		url: reader_url(data, &id),
+		id,
+		status: MangaStatus::Unknown,
+		nsfw: data.nsfw,
+		viewer: MangaViewer::Rtl,
+		..Default::default()
+	}
+}
+
+pub fn get_manga_details(data: GuyaSiteData, id: String) -> Result<Manga> {
+	let json = get_series(&data, &id)?;
+	Ok(parse_series(&data, id, &json))
+}
+
+/// Group ids in proxied series map to names through the top level `groups`
+/// object, while gists key their chapters by the group name directly.
+fn group_name(groups: &Option<ObjectRef>, key: &str) -> String {
+	groups
+		.as_ref()
+		.and_then(|groups| groups.get(key).as_string().ok())
+		.map(|name| name.read())
+		.unwrap_or_else(|| String::from(key))
+}
+
+fn parse_date(value: ValueRef) -> f64 {
+	value
+		.as_float()
+		.unwrap_or_else(|_| string_or_empty(value).parse::<f64>().unwrap_or(0.0))
+}
+
+pub fn get_chapter_list(data: GuyaSiteData, id: String) -> Result<Vec<Chapter>> {
+	let json = get_series(&data, &id)?;
+	let groups = json.get("groups").as_object().ok();
+	let chapters_obj = json.get("chapters").as_object()?;
+
+	let mut chapters: Vec<Chapter> = Vec::new();
+	for key in chapters_obj.keys() {
+		let key = key.as_string()?.read();
+		let Ok(obj) = chapters_obj.get(&key).as_object() else {
+			continue;
+		};
+		let chapter = key.parse::<f32>().unwrap_or(-1.0);
+		let volume = obj
+			.get("volume")
+			.as_string()
+			.map(|v| v.read())
+			.unwrap_or_default()
+			.parse::<f32>()
+			.unwrap_or(-1.0);
+		let title = string_or_empty(obj.get("title"));
+		let release_dates = obj.get("release_date").as_object().ok();
+		let last_updated = parse_date(obj.get("last_updated"));
+
+		for group in obj.get("groups").as_object()?.keys() {
+			let group = group.as_string()?.read();
+			let date_updated = release_dates
+				.as_ref()
+				.map(|dates| parse_date(dates.get(&group)))
+				.filter(|date| *date > 0.0)
+				.unwrap_or(last_updated);
+			chapters.push(Chapter {
+				id: format!("{key}|{group}"),
+				title: title.clone(),
+				volume,
+				chapter,
+				date_updated,
+				scanlator: group_name(&groups, &group),
+				url: format!("{}{}/", reader_url(&data, &id), key.replace('.', "-")),
+				lang: data.language.clone(),
+			});
+		}
+	}
+
+	chapters.sort_by(|a, b| {
+		b.chapter
+			.partial_cmp(&a.chapter)
+			.unwrap_or(core::cmp::Ordering::Equal)
+	});
+	Ok(chapters)
+}
+
+fn push_page(data: &GuyaSiteData, pages: &mut Vec<Page>, value: ValueRef) {
+	let url = match value.as_object() {
+		Ok(obj) => string_or_empty(obj.get("src")),
+		Err(_) => string_or_empty(value),
+	};
+	if !url.is_empty() {
+		pages.push(Page {
+			index: pages.len() as i32,
+			url: absolute_url(data, url),
+			..Default::default()
+		});
+	}
+}
+
+/// The pages of a chapter, with a failure the user can act on shown as a
+/// text page, see [`common::error::pages_or_notice`].
+pub fn get_page_list(
+	data: GuyaSiteData,
+	manga_id: String,
+	chapter_id: String,
+) -> Result<Vec<Page>> {
+	common::error::pages_or_notice(fetch_page_list(data, manga_id, chapter_id))
+}
+
+fn fetch_page_list(
+	data: GuyaSiteData,
+	manga_id: String,
+	chapter_id: String,
+) -> Result<Vec<Page>> {
+	let (chapter, group) = chapter_id.split_once('|').ok_or_else(not_found)?;
+	let json = get_series(&data, &manga_id)?;
+	let value = json
+		.get("chapters")
+		.as_object()?
+		.get(chapter)
+		.as_object()?
+		.get("groups")
+		.as_object()?
+		.get(group);
+
+	// proxied albums only give the path of an endpoint listing the pages
+	let images = match value.as_string() {
+		Ok(path) => Request::new(absolute_url(&data, path.read()), HttpMethod::Get)
+			.header("User-Agent", "Aidoku")
+			.checked_json()?
+			.as_array()?,
+		Err(_) => value.as_array()?,
+	};
+
+	let mut pages: Vec<Page> = Vec::new();
+	for image in images {
+		push_page(&data, &mut pages, image);
+	}
+	Ok(pages)
+}
+
+/// Series added to the library, stored as comma separated ids so they can also
+/// be edited from the settings.
+pub fn get_library_ids() -> Vec<String> {
+	defaults_get("library")
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.unwrap_or_default()
+		.split([',', '\n'])
+		.filter_map(parse_id)
+		.collect()
+}
+
+pub fn add_to_library(id: &str) {
+	let mut ids = get_library_ids();
+	if ids.iter().any(|saved| saved == id) {
+		return;
+	}
+	ids.push(String::from(id));
+	defaults_set("library", StringRef::from(ids.join(", ")).0);
+}
+
+pub fn get_library(data: GuyaSiteData, query: Option<String>) -> Result<MangaPageResult> {
+	let query = query.map(|q| q.to_lowercase());
+
+	let mut manga: Vec<Manga> = Vec::new();
+	for id in get_library_ids() {
+		let Ok(json) = get_series(&data, &id) else {
+			continue;
+		};
+		let series = parse_series(&data, id, &json);
+		if let Some(query) = &query {
+			if !series.title.to_lowercase().contains(query.as_str()) {
+				continue;
+			}
+		}
+		manga.push(series);
+	}
+
+	Ok(MangaPageResult {
+		manga,
+		has_more: false,
+	})
+}
+
+/// Searching with a link or id opens that series (and adds it to the
+/// library), anything else searches the titles of the library.
+pub fn get_manga_list(data: GuyaSiteData, filters: Vec<Filter>) -> Result<MangaPageResult> {
+	let mut query: Option<String> = None;
+	for filter in filters {
+		if let FilterType::Title = filter.kind {
+			query = filter.value.as_string().map(|v| v.read()).ok();
+		}
+	}
+
+	if let Some(id) = query.as_deref().and_then(parse_id) {
+		let manga = get_manga_details(data, id.clone())?;
+		add_to_library(&id);
+		return Ok(MangaPageResult {
+			manga: vec![manga],
+			has_more: false,
+		});
+	}
+
+	get_library(data, query)
+}
+
+pub fn handle_url(data: GuyaSiteData, url: String) -> Result<DeepLink> {
+	let id = parse_id(&url).ok_or_else(not_found)?;
+	let manga = get_manga_details(data, id.clone())?;
+	add_to_library(&id);
+	Ok(DeepLink {
+		manga: Some(manga),
+		chapter: None,
+	})
+}
@@ -4,13 +4,20 @@ use aidoku::{
 	std::net::HttpMethod,
 	std::net::Request,
 	std::String,
-	std::{html::Node, ObjectRef, Vec},
+	std::{current_date, defaults::defaults_get, html::Node, json, ObjectRef, Vec},
 	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
 	MangaViewer, Page,
 };
 use alloc::string::ToString;
+use common::net::CheckedRequest;
 extern crate alloc;
 
+const PAGE_SIZE: usize = 20;
+
+static mut CACHED_SERIES_URL: Option<String> = None;
+static mut CACHED_SERIES: Option<Vec<u8>> = None;
+static mut CACHED_SERIES_AT: f64 = 0.0;
+
 pub struct GuyaSiteData {
 	pub base_url: String,
 	pub nsfw: MangaContentRating,
@@ -27,32 +34,75 @@ impl Default for GuyaSiteData {
 	}
 }
 
-pub fn get_manga_list(data: GuyaSiteData, filters: Vec<Filter>, _: i32) -> Result<MangaPageResult> {
+// The series list is a single JSON object keyed by title, so it is cached for
+// a few minutes and searched locally instead of being downloaded for every
+// page of results.
+fn get_all_series(data: &GuyaSiteData) -> Result<ObjectRef> {
 	let url = format!("{}/api/get_all_series/", &data.base_url);
-	let request = Request::new(url, HttpMethod::Get).header("User-Agent", "Aidoku");
-	let mut json = request
-		.json()
-		.expect("Manga list json not found")
-		.as_object()?;
 
+	unsafe {
+		let expired = current_date() - CACHED_SERIES_AT > 600.0;
+		if !expired && CACHED_SERIES_URL.as_deref() == Some(url.as_str()) {
+			if let Some(bytes) = CACHED_SERIES.as_deref() {
+				return json::parse(bytes)?.as_object();
+			}
+		}
+	}
+
+	let bytes = Request::new(url.as_str(), HttpMethod::Get)
+		.header("User-Agent", "Aidoku")
+		.checked_data()?;
+	// only keep a body that parses, so a challenge or error page isn't served
+	// from the cache
+	let series = json::parse(bytes.as_slice())?.as_object()?;
+	unsafe {
+		CACHED_SERIES = Some(bytes);
+		CACHED_SERIES_URL = Some(url);
+		CACHED_SERIES_AT = current_date();
+	}
+	Ok(series)
+}
+
+fn matches_query(value: &str, query: &Option<String>) -> bool {
+	match query {
+		Some(query) => value.to_lowercase().contains(query.as_str()),
+		None => true,
+	}
+}
+
+pub fn get_manga_list(
+	data: GuyaSiteData,
+	filters: Vec<Filter>,
+	page: i32,
+) -> Result<MangaPageResult> {
+	let json = get_all_series(&data)?;
+
+	let mut title_query: Option<String> = None;
+	let mut author_query: Option<String> = None;
 	for filter in filters {
+		let value = match filter.value.as_string() {
+			Ok(value) => value.read().trim().to_lowercase(),
+			Err(_) => continue,
+		};
+		if value.is_empty() {
+			continue;
+		}
 		match filter.kind {
-			FilterType::Title => {
-				let query = filter.value.as_string()?.read();
-				for manga in json.keys() {
-					let title = manga.as_string()?.read();
-					if !title.to_lowercase().contains(&query.to_lowercase()) {
-						json.remove(&title);
-					}
-				}
-			}
+			FilterType::Title => title_query = Some(value),
+			// matches either the author or the artist
+			FilterType::Author => author_query = Some(value),
 			_ => continue,
 		}
 	}
 
-	let mut manga_arr: Vec<Manga> = Vec::new();
-	let mut titles: Vec<String> = json.keys().map(|k| k.as_string().unwrap().read()).collect();
+	let mut titles: Vec<String> = json
+		.keys()
+		.filter_map(|k| k.as_string().ok())
+		.map(|k| k.read())
+		.collect();
 	titles.sort();
+
+	let mut manga_arr: Vec<Manga> = Vec::new();
 	for title in titles {
 		let obj = match json.get(&title).as_object() {
 			Ok(obj) => obj,
@@ -62,11 +112,28 @@ pub fn get_manga_list(data: GuyaSiteData, filters: Vec<Filter>, _: i32) -> Resul
 			Ok(slug) => slug.read(),
 			Err(_) => continue,
 		};
+		let author = obj
+			.get("author")
+			.as_string()
+			.map(|v| v.read())
+			.unwrap_or_default();
+		let artist = obj
+			.get("artist")
+			.as_string()
+			.map(|v| v.read())
+			.unwrap_or_default();
+		if !matches_query(&title, &title_query)
+			|| !(matches_query(&author, &author_query) || matches_query(&artist, &author_query))
+		{
+			continue;
+		}
 		let cover = format!("{}{}", &data.base_url, obj.get("cover").as_string()?.read());
 		manga_arr.push(Manga {
 			id: slug,
 			title,
 			cover,
+			author,
+			artist,
 			status: MangaStatus::Unknown,
 			nsfw: data.nsfw,
 			viewer: MangaViewer::Rtl,
@@ -74,12 +141,62 @@ pub fn get_manga_list(data: GuyaSiteData, filters: Vec<Filter>, _: i32) -> Resul
 		})
 	}
 
+	let start = (page.max(1) as usize - 1) * PAGE_SIZE;
+	let has_more = manga_arr.len() > start + PAGE_SIZE;
+	let manga_arr = manga_arr.into_iter().skip(start).take(PAGE_SIZE).collect();
+
 	Ok(MangaPageResult {
 		manga: manga_arr,
-		has_more: false,
+		has_more,
 	})
 }
 
+/// Group id or name chosen in the settings, if any.
+fn get_preferred_group() -> Option<String> {
+	defaults_get("preferredGroup")
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.ok()
+		.map(|v| v.trim().to_lowercase())
+		.filter(|v| !v.is_empty())
+}
+
+/// Picks the group to read a chapter from: the preferred group from the
+/// settings when it has the chapter, then the site's `preferred_sort` order,
+/// then whichever group is left.
+fn choose_group(json: &ObjectRef, chapter_groups: &ObjectRef, preferred: &str) -> Option<String> {
+	let available: Vec<String> = chapter_groups
+		.keys()
+		.filter_map(|k| k.as_string().ok())
+		.map(|k| k.read())
+		.collect();
+	let groups = json.get("groups").as_object().ok();
+
+	if let Some(id) = available.iter().find(|id| {
+		id.to_lowercase() == preferred
+			|| groups
+				.as_ref()
+				.and_then(|g| g.get(id).as_string().ok())
+				.map(|name| name.read().to_lowercase() == preferred)
+				.unwrap_or(false)
+	}) {
+		return Some(id.clone());
+	}
+
+	if let Ok(sort) = json.get("preferred_sort").as_array() {
+		for id in sort {
+			let id = match id.as_string() {
+				Ok(id) => id.read(),
+				Err(_) => continue,
+			};
+			if available.contains(&id) {
+				return Some(id);
+			}
+		}
+	}
+
+	available.into_iter().next()
+}
+
 pub fn get_manga_details(
 	data: GuyaSiteData,
 	slug: String,
@@ -87,10 +204,7 @@ pub fn get_manga_details(
 ) -> Result<Manga> {
 	let url = format!("{}/api/series/{}/", &data.base_url, slug);
 	let request = Request::new(url, HttpMethod::Get).header("User-Agent", "Aidoku");
-	let json = request
-		.json()
-		.expect("Manga detail json not found")
-		.as_object()?;
+	let json = request.checked_json()?.as_object()?;
 	let title = json
 		.get("title")
 		.as_string()
@@ -134,19 +248,19 @@ pub fn get_manga_details(
 pub fn get_chapter_list(data: GuyaSiteData, slug: String) -> Result<Vec<Chapter>> {
 	let url = format!("{}/api/series/{}/", &data.base_url, slug);
 	let request = Request::new(url, HttpMethod::Get).header("User-Agent", "Aidoku");
-	let json = request
-		.json()
-		.expect("Manga chapter list json not found")
-		.as_object()?;
+	let json = request.checked_json()?.as_object()?;
+	let preferred_group = get_preferred_group();
+	let group_list = json.get("groups").as_object()?;
 	let mut chapter_arr: Vec<Chapter> = Vec::new();
 	let chapter_obj = json.get("chapters").as_object()?;
-	let mut chapters: Vec<f32> = chapter_obj
+	let mut chapters: Vec<(f32, String)> = chapter_obj
 		.keys()
-		.map(|k| k.as_string().unwrap().read().parse::<f32>().unwrap())
+		.filter_map(|k| k.as_string().ok())
+		.map(|k| k.read())
+		.filter_map(|k| k.parse::<f32>().ok().map(|num| (num, k)))
 		.collect();
-	chapters.sort_by(|a, b| b.partial_cmp(a).unwrap());
-	for chapter in chapters {
-		let chapter = chapter.to_string();
+	chapters.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(core::cmp::Ordering::Equal));
+	for (chapter_int, chapter) in chapters {
 		let obj = match chapter_obj.get(&chapter).as_object() {
 			Ok(obj) => obj,
 			Err(_) => continue,
@@ -158,27 +272,27 @@ pub fn get_chapter_list(data: GuyaSiteData, slug: String) -> Result<Vec<Chapter>
 			.read()
 			.parse()
 			.unwrap_or(-1.0);
-		let chapter_int = chapter.parse().unwrap_or(1.0);
 		let user_url = format!("{}/read/manga/{}/{}/", &data.base_url, &slug, chapter);
 		let folder = obj.get("folder").as_string()?.read();
-		for groups in obj.get("groups").as_object()?.keys() {
-			let group_id = groups.as_string()?.read();
-			let mut group_name = String::new();
-			let group_list = json.get("groups").as_object()?;
-			for gl_index in group_list.keys() {
-				let gl_id = gl_index.as_string()?.read();
-				if gl_id == group_id {
-					group_name = group_list.get(&gl_id).as_string()?.read();
-				}
-			}
-			let mut date_updated = 0.0;
-			let date_list = obj.get("release_date").as_object()?;
-			for dl_index in date_list.keys() {
-				let dl_id = dl_index.as_string()?.read();
-				if dl_id == group_id {
-					date_updated = date_list.get(&dl_id).as_float()?;
-				}
-			}
+		let chapter_groups = obj.get("groups").as_object()?;
+		let group_ids: Vec<String> = match &preferred_group {
+			Some(preferred) => choose_group(&json, &chapter_groups, preferred)
+				.into_iter()
+				.collect(),
+			None => chapter_groups
+				.keys()
+				.filter_map(|k| k.as_string().ok())
+				.map(|k| k.read())
+				.collect(),
+		};
+		let date_list = obj.get("release_date").as_object()?;
+		for group_id in group_ids {
+			let group_name = group_list
+				.get(&group_id)
+				.as_string()
+				.map(|v| v.read())
+				.unwrap_or_default();
+			let date_updated = date_list.get(&group_id).as_float().unwrap_or(0.0);
 			let id = format!("{}|{}", &folder, &group_id);
 			chapter_arr.push(Chapter {
 				id,
@@ -196,7 +310,13 @@ pub fn get_chapter_list(data: GuyaSiteData, slug: String) -> Result<Vec<Chapter>
 	Ok(chapter_arr)
 }
 
+/// The pages of a chapter, with a failure the user can act on shown as a
+/// text page, see [`common::error::pages_or_notice`].
 pub fn get_page_list(data: GuyaSiteData, chapter: ObjectRef) -> Result<Vec<Page>> {
+	common::error::pages_or_notice(fetch_page_list(data, chapter))
+}
+
+fn fetch_page_list(data: GuyaSiteData, chapter: ObjectRef) -> Result<Vec<Page>> {
 	let slug = chapter
 		.get("mangaId")
 		.as_string()
@@ -204,21 +324,28 @@ pub fn get_page_list(data: GuyaSiteData, chapter: ObjectRef) -> Result<Vec<Page>
 		.read();
 	let url = format!("{}/api/series/{}/", &data.base_url, &slug);
 	let request = Request::new(url, HttpMethod::Get).header("User-Agent", "Aidoku");
-	let json = request
-		.json()
-		.expect("Manga page list json not found")
-		.as_object()?;
+	let json = request.checked_json()?.as_object()?;
 	let chapter_num = chapter.get("chapterNum").as_float()?;
 	let chapter_num = format!("{:.1}", chapter_num)
 		.trim_end_matches(".0")
 		.to_string();
 
 	let ids = chapter.get("id").as_string()?.read();
-	let group_id = ids.split('|').collect::<Vec<&str>>()[1].to_string();
+	let group_id = ids.split('|').nth(1).unwrap_or_default().to_string();
 	let chapters_obj = json.get("chapters").as_object()?;
 	let chapter_obj = chapters_obj.get(chapter_num.as_str()).as_object()?;
 	let folder = chapter_obj.get("folder").as_string()?.read();
 	let groups_obj = chapter_obj.get("groups").as_object()?;
+	// the group may have pulled the chapter since the list was fetched
+	let group_id = match groups_obj.get(group_id.as_str()).as_array() {
+		Ok(_) => group_id,
+		Err(_) => choose_group(
+			&json,
+			&groups_obj,
+			&get_preferred_group().unwrap_or_default(),
+		)
+		.unwrap_or(group_id),
+	};
 	let chapter_array = groups_obj.get(group_id.as_str()).as_array()?;
 	let mut pages: Vec<Page> = Vec::new();
 	for (idx, page) in chapter_array.enumerate() {
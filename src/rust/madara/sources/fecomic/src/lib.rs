@@ -1,7 +1,7 @@
 #![no_std]
 use aidoku::{
 	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaContentRating, MangaPageResult, MangaStatus, MangaViewer, Page,
+	MangaPageResult, MangaStatus, Page,
 };
 
 use madara_template::template;
@@ -13,15 +13,8 @@ fn get_data() -> template::MadaraSiteData {
 		source_path: String::from("comic"),
 		genre_selector: String::from("div.genres > a"),
 		alt_ajax: true,
-		viewer: |_, categories| {
-			for category in categories {
-				match category.as_str() {
-					"Manhwa" | "Manhua" | "Webtoon" => return MangaViewer::Scroll,
-					_ => continue,
-				}
-			}
-			MangaViewer::Rtl
-		},
+		default_viewer: template::Hint::Rtl,
+		classifier_overrides: &[("Truyện 18+", template::Hint::Nsfw)],
 		status: |html| {
 			let status = html.select("div.post-status").text().read();
 			match status.trim() {
@@ -32,28 +25,6 @@ fn get_data() -> template::MadaraSiteData {
 				_ => MangaStatus::Unknown,
 			}
 		},
-		nsfw: |html, categories| {
-			if !html
-				.select(".manga-title-badges.adult")
-				.text()
-				.read()
-				.is_empty()
-			{
-				MangaContentRating::Nsfw
-			} else {
-				let mut nsfw = MangaContentRating::Safe;
-				for category in categories {
-					match category.to_lowercase().as_str() {
-						"smut" | "mature" | "adult" | "truyện 18+" => {
-							return MangaContentRating::Nsfw
-						}
-						"ecchi" | "16+" => nsfw = MangaContentRating::Suggestive,
-						_ => continue,
-					}
-				}
-				nsfw
-			}
-		},
 		status_filter_ongoing: String::from("Đang tiến hành"),
 		status_filter_completed: String::from("Đã hoàn thành"),
 		status_filter_cancelled: String::from("Đã bị huỷ/Ngừng dịch"),
@@ -4,8 +4,7 @@ use aidoku::{
 	prelude::*,
 	std::String,
 	std::{net::Request, Vec},
-	Chapter, DeepLink, Filter, Listing, Manga, MangaContentRating, MangaPageResult, MangaStatus,
-	MangaViewer, Page,
+	Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, MangaStatus, Page,
 };
 
 use madara_template::template;
@@ -17,15 +16,8 @@ fn get_data() -> template::MadaraSiteData {
 		source_path: String::from("read"),
 		image_selector: String::from(".reading-content .doc-truyen > img"),
 		alt_ajax: true,
-		viewer: |_, categories| {
-			for category in categories {
-				match category.as_str() {
-					"Manhwa" | "Manhua" | "Webtoon" => return MangaViewer::Scroll,
-					_ => continue,
-				}
-			}
-			MangaViewer::Rtl
-		},
+		default_viewer: template::Hint::Rtl,
+		default_rating: template::Hint::Nsfw,
 		status: |html| {
 			let status = html
 				.select("div.post-content_item:contains(Tình trạng) div.summary-content")
@@ -39,7 +31,6 @@ fn get_data() -> template::MadaraSiteData {
 				_ => MangaStatus::Unknown,
 			}
 		},
-		nsfw: |_, _| MangaContentRating::Nsfw,
 		status_filter_ongoing: String::from("Đang tiến hành"),
 		status_filter_completed: String::from("Hoàn thành"),
 		status_filter_cancelled: String::from("Đã huỷ"),
@@ -1,7 +1,7 @@
 #![no_std]
 use aidoku::{
 	error::Result, prelude::*, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaContentRating, MangaPageResult, MangaViewer, Page,
+	MangaPageResult, Page,
 };
 
 use madara_template::template;
@@ -10,36 +10,7 @@ fn get_data() -> template::MadaraSiteData {
 	let data: template::MadaraSiteData = template::MadaraSiteData {
 		base_url: String::from("https://lilymanga.net"),
 		source_path: String::from("ys"),
-		viewer: |html, _| {
-			let temp = html
-				.select("div.post-content_item:contains(Type) div.summary-content")
-				.text()
-				.read();
-			match temp.as_str() {
-				"Manhwa" | "Manhua" => MangaViewer::Scroll,
-				_ => MangaViewer::Rtl,
-			}
-		},
-		nsfw: |html, categories| {
-			if !html
-				.select(".manga-title-badges.adult")
-				.text()
-				.read()
-				.is_empty()
-			{
-				MangaContentRating::Nsfw
-			} else {
-				let mut nsfw = MangaContentRating::Safe;
-				for category in categories {
-					match category.to_lowercase().as_str() {
-						"smut" | "mature" | "adult" | "hentai" => return MangaContentRating::Nsfw,
-						"ecchi" => nsfw = MangaContentRating::Suggestive,
-						_ => continue,
-					}
-				}
-				nsfw
-			}
-		},
+		default_viewer: template::Hint::Rtl,
 		alt_ajax: true,
 		..Default::default()
 	};
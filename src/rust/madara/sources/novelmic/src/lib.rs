@@ -10,6 +10,7 @@ fn get_data() -> template::MadaraSiteData {
 	let data: template::MadaraSiteData = template::MadaraSiteData {
 		base_url: String::from("https://novelmic.com"),
 		source_path: String::from("comic"),
+		novel: true,
 		..Default::default()
 	};
 	data
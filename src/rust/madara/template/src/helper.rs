@@ -1,9 +1,16 @@
 use aidoku::{
-	std::defaults::defaults_get, std::html::Node, std::net::HttpMethod, std::net::Request,
-	std::String, std::Vec, Filter, FilterType,
+	prelude::*,
+	std::current_date,
+	std::defaults::{defaults_get, defaults_set},
+	std::html::Node,
+	std::net::HttpMethod,
+	std::net::Request,
+	std::{String, StringRef, Vec},
+	Filter, FilterType,
 };
 
 use crate::template::MadaraSiteData;
+use common::net::{self, CheckedRequest};
 
 extern crate alloc;
 use alloc::string::ToString;
@@ -199,10 +206,10 @@ pub fn get_int_manga_id(
 ) -> String {
 	let url = base_url + "/" + path.as_str() + "/" + manga_id.as_str();
 
-	let mut req = Request::new(url.as_str(), HttpMethod::Get);
+	let mut req = net::request(url.as_str(), HttpMethod::Get);
 	req = add_user_agent_header(req, &user_agent);
 
-	if let Ok(html) = req.html() {
+	if let Ok(html) = req.checked_html() {
 		let data_id = html
 			.select("div[id^=manga-chapters-holder]")
 			.first()
@@ -235,3 +242,166 @@ pub fn get_lang_code() -> Option<String> {
 pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> &'a str {
 	s.strip_prefix(prefix).unwrap_or(s)
 }
+
+const LOGIN_COOKIE_KEY: &str = "madaraLoginCookie";
+const LOGIN_USER_KEY: &str = "madaraLoginUser";
+const LOGIN_FAILED_KEY: &str = "madaraLoginFailedAt";
+
+/// Seconds before a failed login is tried again with the same credentials.
+const LOGIN_RETRY_DELAY: f64 = 60.0 * 60.0;
+
+/// Returns the username and password from the source settings, if the source
+/// exposes them and both are filled in.
+pub fn get_credentials() -> Option<(String, String)> {
+	let read = |key: &str| {
+		defaults_get(key)
+			.and_then(|v| v.as_string().map(|v| v.read()))
+			.map(|v| String::from(v.trim()))
+			.unwrap_or_default()
+	};
+	let username = read("username");
+	let password = read("password");
+	if username.is_empty() || password.is_empty() {
+		None
+	} else {
+		Some((username, password))
+	}
+}
+
+/// Extracts the `wordpress_logged_in_*` cookie from a `Set-Cookie` header,
+/// which may contain several comma separated cookies.
+fn find_login_cookie(set_cookie: &str) -> Option<String> {
+	set_cookie
+		.split([',', ';'])
+		.map(|part| part.trim())
+		.find(|part| part.starts_with("wordpress_logged_in_") && !part.ends_with('='))
+		.map(String::from)
+}
+
+fn login_request(req: Request, user_agent: &Option<String>) -> Option<String> {
+	let req = add_user_agent_header(req, user_agent);
+	req.send();
+	req.get_header("Set-Cookie")
+		.and_then(|v| find_login_cookie(&v.read()))
+}
+
+/// Logs in with the credentials from the settings, first through the Madara
+/// AJAX endpoint and then through `wp-login.php`.
+fn login(base_url: &str, user_agent: &Option<String>) -> Option<String> {
+	let (username, password) = get_credentials()?;
+
+	let body = format!(
+		"action=wp_manga_signin&login={}&pass={}&rememberme=forever",
+		urlencode(username.clone()),
+		urlencode(password.clone())
+	);
+	let req = net::request(
+		format!("{base_url}/wp-admin/admin-ajax.php"),
+		HttpMethod::Post,
+	)
+	.body(body.as_bytes())
+	.header("Referer", base_url)
+	.header("Content-Type", "application/x-www-form-urlencoded");
+	if let Some(cookie) = login_request(req, user_agent) {
+		return Some(cookie);
+	}
+
+	let body = format!(
+		"log={}&pwd={}&rememberme=forever&wp-submit=Log+In&redirect_to={}&testcookie=1",
+		urlencode(username),
+		urlencode(password),
+		urlencode(String::from(base_url))
+	);
+	let req = net::request(format!("{base_url}/wp-login.php"), HttpMethod::Post)
+		.body(body.as_bytes())
+		.header("Referer", &format!("{base_url}/wp-login.php"))
+		.header("Cookie", "wordpress_test_cookie=WP%20Cookie%20check")
+		.header("Content-Type", "application/x-www-form-urlencoded");
+	login_request(req, user_agent)
+}
+
+/// Identifies the credentials a stored session or failure belongs to, without
+/// storing the password a second time.
+fn credentials_hash(username: &str, password: &str) -> String {
+	// FNV-1a
+	let mut hash: u64 = 0xcbf29ce484222325;
+	for byte in username.bytes().chain([0]).chain(password.bytes()) {
+		hash ^= byte as u64;
+		hash = hash.wrapping_mul(0x100000001b3);
+	}
+	format!("{hash:016x}")
+}
+
+/// Returns the persisted login cookie, logging in first if the credentials in
+/// the settings changed or no session was stored yet. A failed login is
+/// remembered for [`LOGIN_RETRY_DELAY`] so requests don't all try again.
+pub fn get_login_cookie(base_url: &str, user_agent: &Option<String>) -> Option<String> {
+	let (username, password) = get_credentials()?;
+	let hash = credentials_hash(&username, &password);
+
+	let stored_hash = defaults_get(LOGIN_USER_KEY)
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.unwrap_or_default();
+	if stored_hash == hash {
+		let cookie = defaults_get(LOGIN_COOKIE_KEY)
+			.and_then(|v| v.as_string().map(|v| v.read()))
+			.unwrap_or_default();
+		if !cookie.is_empty() {
+			return Some(cookie);
+		}
+		let failed_at = defaults_get(LOGIN_FAILED_KEY)
+			.and_then(|v| v.as_string().map(|v| v.read()))
+			.ok()
+			.and_then(|v| v.parse::<f64>().ok())
+			.unwrap_or(0.0);
+		if current_date() - failed_at < LOGIN_RETRY_DELAY {
+			return None;
+		}
+	}
+
+	let cookie = login(base_url, user_agent);
+	defaults_set(
+		LOGIN_COOKIE_KEY,
+		StringRef::from(cookie.as_deref().unwrap_or_default()).0,
+	);
+	if cookie.is_none() {
+		let now = format!("{}", current_date());
+		defaults_set(LOGIN_FAILED_KEY, StringRef::from(&now).0);
+	}
+	defaults_set(LOGIN_USER_KEY, StringRef::from(&hash).0);
+	cookie
+}
+
+/// Forgets the stored session so the next request logs in again.
+pub fn clear_login_cookie() {
+	defaults_set(LOGIN_USER_KEY, StringRef::from("").0);
+}
+
+/// Adds the login cookie (if any) in front of the `extra` cookies.
+pub fn add_login_cookie_header(
+	req: Request,
+	base_url: &str,
+	user_agent: &Option<String>,
+	extra: &str,
+) -> Request {
+	let cookie = match get_login_cookie(base_url, user_agent) {
+		Some(cookie) if !extra.is_empty() => format!("{cookie}; {extra}"),
+		Some(cookie) => cookie,
+		None => String::from(extra),
+	};
+	if cookie.is_empty() {
+		req
+	} else {
+		req.header("Cookie", &cookie)
+	}
+}
+
+/// Whether a node (a chapter list item or its children) carries one of the
+/// markers used by premium/coin chapter plugins.
+pub fn is_locked(node: &Node, selector: &str) -> bool {
+	let class = node.attr("class").read();
+	class
+		.split_whitespace()
+		.any(|c| c == "premium" || c == "premium-block")
+		|| !node.select(selector).array().is_empty()
+}
@@ -5,16 +5,25 @@ use aidoku::{
 		current_date,
 		html::Node,
 		net::{HttpMethod, Request},
-		String, StringRef, Vec,
+		String, Vec,
 	},
 	Chapter, DeepLink, Filter, Listing, Manga, MangaContentRating, MangaPageResult, MangaStatus,
 	MangaViewer, Page,
 };
 
 use crate::helper::*;
+use common::{
+	chapter::parse_chapter,
+	classify::Classifier,
+	date::DateParser,
+	error::SourceError,
+	net::{self, CheckedRequest},
+};
+
+pub use common::classify::Hint;
 
 extern crate alloc;
-use alloc::string::ToString;
+use alloc::{string::ToString, vec};
 
 pub struct MadaraSiteData {
 	pub base_url: String,
@@ -49,10 +58,20 @@ pub struct MadaraSiteData {
 	pub user_agent: Option<String>,
 	pub use_ajax_listing: bool,
 
+	pub locked_chapter_selector: String,
+	pub locked_page_selector: String,
+	pub locked: String,
+
+	pub novel: bool,
+	pub text_selector: String,
+
 	pub get_manga_id: fn(String, String, String, Option<String>) -> String,
-	pub viewer: fn(&Node, &Vec<String>) -> MangaViewer,
+	pub default_viewer: Hint,
+	pub default_rating: Hint,
+	pub classifier_overrides: &'static [(&'static str, Hint)],
+	pub viewer: fn(&Node, &Vec<String>, &Classifier) -> MangaViewer,
 	pub status: fn(&Node) -> MangaStatus,
-	pub nsfw: fn(&Node, &Vec<String>) -> MangaContentRating,
+	pub nsfw: fn(&Node, &Vec<String>, &Classifier) -> MangaContentRating,
 
 	pub ignore_class: String,
 }
@@ -93,50 +112,33 @@ impl Default for MadaraSiteData {
 			user_agent: None,
 			// use admin-ajax to get listings
 			use_ajax_listing: true,
+			// markers inside a chapter list item for chapters that need coins or an account
+			locked_chapter_selector: String::from(
+				".premium-block, .coin, i.fa-lock, i.fas.fa-lock, i.fa-coins",
+			),
+			// markers on a chapter page that replace the images for locked chapters
+			locked_page_selector: String::from(
+				"div.content-blocked, div.premium-block, div.login-required, .wp-manga-chapter-coin-wrapper",
+			),
+			// detect text chapters and return them as novel pages
+			novel: false,
+			// div to select the text of a novel chapter from
+			text_selector: String::from("div.text-left"),
 			// get the manga id from script tag
 			get_manga_id: get_int_manga_id,
+			// viewer when neither the type, the tags nor the language decide
+			default_viewer: Hint::Scroll,
+			// lowest content rating of the site
+			default_rating: Hint::Safe,
+			// keywords of the site, checked before the shared ones
+			classifier_overrides: &[],
 			// default viewer
-			viewer: |html, categories| {
+			viewer: |html, categories, classifier| {
 				let series_type = html
 					.select("div.post-content_item:contains(Type) div.summary-content")
 					.text()
-					.read()
-					.to_lowercase();
-
-				let webtoon_tags = [
-					"manhwa", "manhua", "webtoon", "vertical", "korean", "chinese",
-				];
-				let rtl_tags = ["manga", "japan"];
-
-				if !series_type.is_empty() {
-					for tag in webtoon_tags {
-						if series_type.contains(tag) {
-							return MangaViewer::Scroll;
-						}
-					}
-
-					for tag in rtl_tags {
-						if series_type.contains(tag) {
-							return MangaViewer::Rtl;
-						}
-					}
-
-					MangaViewer::Scroll
-				} else {
-					for tag in webtoon_tags {
-						if categories.iter().any(|v| v.to_lowercase() == tag) {
-							return MangaViewer::Scroll;
-						}
-					}
-
-					for tag in rtl_tags {
-						if categories.iter().any(|v| v.to_lowercase() == tag) {
-							return MangaViewer::Rtl;
-						}
-					}
-
-					MangaViewer::Scroll
-				}
+					.read();
+				classifier.classify(categories, &series_type, "").1
 			},
 			status: |html| {
 				let status_str = html
@@ -155,7 +157,7 @@ impl Default for MadaraSiteData {
 					_ => MangaStatus::Unknown,
 				}
 			},
-			nsfw: |html, categories| {
+			nsfw: |html, categories, classifier| {
 				if !html
 					.select(".manga-title-badges.adult")
 					.text()
@@ -164,22 +166,11 @@ impl Default for MadaraSiteData {
 				{
 					MangaContentRating::Nsfw
 				} else {
-					let nsfw_tags = ["adult", "mature"];
-					let suggestive_tags = ["ecchi"];
-
-					for tag in nsfw_tags {
-						if categories.iter().any(|v| v.to_lowercase() == tag) {
-							return MangaContentRating::Nsfw;
-						}
-					}
-
-					for tag in suggestive_tags {
-						if categories.iter().any(|v| v.to_lowercase() == tag) {
-							return MangaContentRating::Suggestive;
-						}
-					}
-
-					MangaContentRating::Safe
+					let series_type = html
+						.select("div.post-content_item:contains(Type) div.summary-content")
+						.text()
+						.read();
+					classifier.classify(categories, &series_type, "").0
 				}
 			},
 			// Ignore MangaPageResult manga with this class from a listing. Usually used for novels.
@@ -193,6 +184,7 @@ impl Default for MadaraSiteData {
 			genre_condition: String::from("Genre Condition"),
 			popular: String::from("Popular"),
 			trending: String::from("Trending"),
+			locked: String::from("Locked"),
 		}
 	}
 }
@@ -212,11 +204,11 @@ pub fn get_manga_list(
 }
 
 pub fn get_search_result(data: MadaraSiteData, url: String) -> Result<MangaPageResult> {
-	let mut req = Request::get(&url).header("Cookie", &data.search_cookies);
+	let mut req = net::request(&url, HttpMethod::Get).header("Cookie", &data.search_cookies);
 
 	req = add_user_agent_header(req, &data.user_agent);
 
-	let html = req.html()?;
+	let html = req.checked_html()?;
 	let mut manga: Vec<Manga> = Vec::new();
 	let mut has_more = false;
 
@@ -235,7 +227,7 @@ pub fn get_search_result(data: MadaraSiteData, url: String) -> Result<MangaPageR
 		let cover = get_image_url(obj.select("img"));
 
 		let genres = obj.select("div.post-content_item div.summary-content a");
-		if genres.text().read().to_lowercase().contains("novel") {
+		if !data.novel && genres.text().read().to_lowercase().contains("novel") {
 			continue;
 		}
 
@@ -273,21 +265,21 @@ pub fn get_series_page(data: MadaraSiteData, listing: &str, page: i32) -> Result
 
 	let body_content =  format!("action=madara_load_more&page={}&template=madara-core%2Fcontent%2Fcontent-archive&vars%5Bpaged%5D=1&vars%5Borderby%5D=meta_value_num&vars%5Btemplate%5D=archive&vars%5Bsidebar%5D=full&vars%5Bpost_type%5D=wp-manga&vars%5Bpost_status%5D=publish&vars%5Bmeta_key%5D={}&vars%5Border%5D=desc&vars%5Bmeta_query%5D%5Brelation%5D=OR&vars%5Bmanga_archives_item_layout%5D=big_thumbnail", &page-1, listing);
 
-	let mut req = Request::new(url.as_str(), HttpMethod::Post)
+	let mut req = net::request(url.as_str(), HttpMethod::Post)
 		.body(body_content.as_bytes())
 		.header("Referer", &data.base_url)
 		.header("Content-Type", "application/x-www-form-urlencoded");
 
 	req = add_user_agent_header(req, &data.user_agent);
 
-	let html = req.html()?;
+	let html = req.checked_html()?;
 
 	let mut manga: Vec<Manga> = Vec::new();
 	let mut has_more = false;
 	for item in html.select("div.page-item-detail").array() {
 		let obj = item.as_node().expect("node array");
 
-		if !obj.select(&data.ignore_class).text().read().is_empty() {
+		if !data.novel && !obj.select(&data.ignore_class).text().read().is_empty() {
 			continue;
 		}
 
@@ -347,11 +339,11 @@ pub fn get_manga_details(manga_id: String, data: MadaraSiteData) -> Result<Manga
 		format!("{}/{}/{manga_id}", data.base_url, data.source_path)
 	};
 
-	let mut req = Request::get(&url);
+	let mut req = net::request(&url, HttpMethod::Get);
 
 	req = add_user_agent_header(req, &data.user_agent);
 
-	let html = req.html()?;
+	let html = req.checked_html()?;
 
 	// These are useless badges that are added to the title like "HOT", "NEW", etc.
 	let title_badges = html.select("span.manga-title-badges").text().read();
@@ -371,8 +363,12 @@ pub fn get_manga_details(manga_id: String, data: MadaraSiteData) -> Result<Manga
 	}
 
 	let status = (data.status)(&html);
-	let viewer = (data.viewer)(&html, &categories);
-	let nsfw = (data.nsfw)(&html, &categories);
+	let classifier = Classifier::new(&data.lang)
+		.with_overrides(data.classifier_overrides)
+		.with_viewer(data.default_viewer)
+		.with_rating(data.default_rating);
+	let viewer = (data.viewer)(&html, &categories, &classifier);
+	let nsfw = (data.nsfw)(&html, &categories, &classifier);
 
 	Ok(Manga {
 		id: manga_id,
@@ -406,14 +402,15 @@ pub fn get_chapter_list(manga_id: String, data: MadaraSiteData) -> Result<Vec<Ch
 	);
 	let body_content = format!("action=manga_get_chapters&manga={}", int_id);
 
-	let mut req = Request::new(url.as_str(), HttpMethod::Post)
+	let mut req = net::request(url.as_str(), HttpMethod::Post)
 		.body(body_content.as_bytes())
 		.header("Referer", &data.base_url)
 		.header("Content-Type", "application/x-www-form-urlencoded");
 
 	req = add_user_agent_header(req, &data.user_agent);
+	req = add_login_cookie_header(req, &data.base_url, &data.user_agent, "");
 
-	let html = req.html()?;
+	let html = req.checked_html()?;
 
 	let mut chapters: Vec<Chapter> = Vec::new();
 	for item in html.select(&data.chapter_selector).array() {
@@ -426,66 +423,34 @@ pub fn get_chapter_list(manga_id: String, data: MadaraSiteData) -> Result<Vec<Ch
 			.replace(&(data.base_url.clone() + "/"), "")
 			.replace(&(data.source_path.clone() + "/"), "");
 
-		let mut title = String::new();
-		let t_tag = obj.select("a").text().read();
-		if t_tag.contains('-') {
-			title.push_str(t_tag[t_tag.find('-').unwrap() + 1..].trim());
-		}
-
-		/*  Chapter number is first occourance of a number in the last element of url
-			when split with "/"
-			e.g.
-			one-piece-color-jk-english/volume-20-showdown-at-alubarna/chapter-177-30-million-vs-81-million/
-			will return 177
-			parasite-chromatique-french/volume-10/chapitre-062-5/
-			will return 62.5
-		*/
-
-		let slash_vec = id.as_str().split('/').collect::<Vec<&str>>();
-
-		let dash_split = slash_vec[slash_vec.len() - 2].split('-');
-		let dash_vec = dash_split.collect::<Vec<&str>>();
-
-		let mut is_decimal = false;
-		let mut chapter = 0.0;
-		for obj in dash_vec {
-			let mut item = {
-				let mut obj = obj;
-				if obj.contains('_') {
-					obj = obj.split('_').next().unwrap_or(obj);
-				}
-				obj.replace('/', "").parse::<f32>().unwrap_or(-1.0)
+		let parsed = parse_chapter(&obj.select("a").text().read(), "");
+		let mut title = parsed.title;
+		if is_locked(&obj, &data.locked_chapter_selector) {
+			title = if title.is_empty() {
+				format!("({})", data.locked)
+			} else {
+				format!("({}) {}", data.locked, title)
 			};
-			if item == -1.0 {
-				item = String::from(obj.chars().next().unwrap())
-					.parse::<f32>()
-					.unwrap_or(-1.0);
-			}
-			if item != -1.0 {
-				if is_decimal {
-					chapter += item / 10.0;
-					break;
-				} else {
-					chapter = item;
-					is_decimal = true;
-				}
-			}
 		}
 
-		let date_str = obj.select("span.chapter-release-date > i").text().read();
-		let mut date_updated = StringRef::from(&date_str)
-			.0
-			.as_date(data.date_format.as_str(), Some("en"), None)
-			.unwrap_or(-1.0);
-		if date_updated < -1.0 {
-			date_updated = StringRef::from(&date_str)
-				.0
-				.as_date("MMM d, yy", Some("en"), None)
-				.unwrap_or(-1.0);
-		}
-		if date_updated == -1.0 {
-			date_updated = current_date();
+		let chapter = if parsed.chapter >= 0.0 {
+			parsed.chapter
+		} else {
+			chapter_number_from_id(&id)
+		};
+
+		// recent chapters show "2 hours ago" in the title of a badge instead
+		let mut date_str = obj.select("span.chapter-release-date > i").text().read();
+		if date_str.is_empty() {
+			date_str = obj
+				.select("span.chapter-release-date a")
+				.attr("title")
+				.read();
 		}
+		let formats = [data.date_format.as_str(), "MMM d, yy"];
+		let date_updated = DateParser::new(&formats, Some("en"))
+			.parse(&date_str)
+			.unwrap_or_else(current_date);
 
 		let url = obj.select("a").attr("href").read();
 		let lang = data.lang.clone();
@@ -493,7 +458,7 @@ pub fn get_chapter_list(manga_id: String, data: MadaraSiteData) -> Result<Vec<Ch
 		chapters.push(Chapter {
 			id,
 			title,
-			volume: -1.0,
+			volume: parsed.volume,
 			chapter,
 			date_updated,
 			scanlator: String::new(),
@@ -504,13 +469,83 @@ pub fn get_chapter_list(manga_id: String, data: MadaraSiteData) -> Result<Vec<Ch
 	Ok(chapters)
 }
 
+/// Chapter number from the last segment of a chapter id, for chapters whose
+/// name has none, e.g.
+/// `one-piece-color-jk-english/volume-20-showdown-at-alubarna/chapter-177-30-million-vs-81-million/`
+/// gives 177 and `parasite-chromatique-french/volume-10/chapitre-062-5/` 62.5.
+fn chapter_number_from_id(id: &str) -> f32 {
+	let slash_vec = id.split('/').collect::<Vec<&str>>();
+
+	let dash_split = slash_vec[slash_vec.len() - 2].split('-');
+	let dash_vec = dash_split.collect::<Vec<&str>>();
+
+	let mut is_decimal = false;
+	let mut chapter = 0.0;
+	for obj in dash_vec {
+		let mut item = {
+			let mut obj = obj;
+			if obj.contains('_') {
+				obj = obj.split('_').next().unwrap_or(obj);
+			}
+			obj.replace('/', "").parse::<f32>().unwrap_or(-1.0)
+		};
+		if item == -1.0 {
+			item = String::from(obj.chars().next().unwrap())
+				.parse::<f32>()
+				.unwrap_or(-1.0);
+		}
+		if item != -1.0 {
+			if is_decimal {
+				chapter += item / 10.0;
+				break;
+			} else {
+				chapter = item;
+				is_decimal = true;
+			}
+		}
+	}
+	chapter
+}
+
+/// The pages of a chapter, with a failure the user can act on shown as a
+/// text page, see [`common::error::pages_or_notice`].
 pub fn get_page_list(chapter_id: String, data: MadaraSiteData) -> Result<Vec<Page>> {
+	common::error::pages_or_notice(fetch_page_list(chapter_id, data))
+}
+
+fn fetch_page_list(chapter_id: String, data: MadaraSiteData) -> Result<Vec<Page>> {
 	let url = data.base_url.clone() + "/" + data.source_path.as_str() + "/" + chapter_id.as_str();
-	let mut req = Request::new(url.as_str(), HttpMethod::Get);
 
-	req = add_user_agent_header(req, &data.user_agent);
+	let fetch = || {
+		let mut req = net::request(url.as_str(), HttpMethod::Get);
+		req = add_user_agent_header(req, &data.user_agent);
+		req = add_login_cookie_header(req, &data.base_url, &data.user_agent, "");
+		req.checked_html()
+	};
+
+	let is_locked_page = |html: &Node| {
+		html.select(data.image_selector.as_str()).array().is_empty()
+			&& !html.select(&data.locked_page_selector).array().is_empty()
+	};
 
-	let html = req.html()?;
+	let mut html = fetch()?;
+
+	// a stored session may have expired, log in again once before giving up
+	if is_locked_page(&html) && get_login_cookie(&data.base_url, &data.user_agent).is_some() {
+		clear_login_cookie();
+		html = fetch()?;
+	}
+
+	if data.novel && html.select(data.image_selector.as_str()).array().is_empty() {
+		if let Some(pages) = get_text_pages(&html, &url, &data) {
+			return Ok(pages);
+		}
+	}
+	// a login or purchase notice in place of the images, shown to the user as
+	// a text page by `get_page_list`
+	if is_locked_page(&html) {
+		return Err(SourceError::Locked.into());
+	}
 
 	let mut pages: Vec<Page> = Vec::new();
 	for (index, item) in html
@@ -520,13 +555,49 @@ pub fn get_page_list(chapter_id: String, data: MadaraSiteData) -> Result<Vec<Pag
 	{
 		pages.push(Page {
 			index: index as i32,
-			url: get_image_url(item.as_node().expect("node array")),
+			url: net::relay_image_url(get_image_url(item.as_node().expect("node array"))),
 			..Default::default()
 		});
 	}
 	Ok(pages)
 }
 
+/// Builds the pages of a text chapter, following the novel convention of a
+/// leading `"novel"` page carrying the chapter name.
+fn get_text_pages(html: &Node, url: &str, data: &MadaraSiteData) -> Option<Vec<Page>> {
+	let text_node = html.select(&data.text_selector);
+	let text = if !text_node.array().is_empty() {
+		text_node.first().html().read()
+	} else {
+		html.select("div.reading-content p")
+			.array()
+			.filter_map(|p| p.as_node().ok())
+			.map(|p| p.outer_html().read())
+			.collect::<Vec<_>>()
+			.join("")
+	};
+	if text.trim().is_empty() {
+		return None;
+	}
+
+	let name = html.select("#chapter-heading").text().read();
+
+	Some(vec![
+		Page {
+			text: String::from("novel"),
+			index: -1,
+			url: String::new(),
+			base64: name.clone(),
+		},
+		Page {
+			index: 0,
+			text,
+			base64: name,
+			url: String::from(url),
+		},
+	])
+}
+
 pub fn modify_image_request(base_url: String, request: Request) {
 	request.header("Referer", &base_url);
 }
@@ -4,6 +4,7 @@ use aidoku::{
 	DeepLink, Filter, FilterType, Listing, Manga, MangaContentRating, MangaPageResult, MangaViewer,
 	Page,
 };
+use common::net::CheckedRequest;
 
 pub fn get_manga_list(
 	base_url: &str,
@@ -34,7 +35,7 @@ pub fn parse_manga_list(
 ) -> Result<MangaPageResult> {
 	let html = Request::get(url)
 		.header("Referer", &format!("{base_url}/"))
-		.html()?;
+		.checked_html()?;
 
 	let mut manga: Vec<Manga> = Vec::new();
 
@@ -126,7 +127,7 @@ pub fn get_manga_details(
 	};
 	let html = Request::get(&url)
 		.header("Referer", &format!("{base_url}/"))
-		.html()?;
+		.checked_html()?;
 
 	let details = html.select("div.manga-info-top, div.panel-story-info");
 	let title = details.select("h1").text().read();
@@ -198,7 +199,7 @@ pub fn get_chapter_list(
 	};
 	let html = Request::get(&url)
 		.header("Referer", &format!("{base_url}/"))
-		.html()?;
+		.checked_html()?;
 
 	let mut chapters: Vec<Chapter> = Vec::new();
 
@@ -234,7 +235,13 @@ pub fn get_chapter_list(
 	Ok(chapters)
 }
 
+/// The pages of a chapter, with a failure the user can act on shown as a
+/// text page, see [`common::error::pages_or_notice`].
 pub fn get_page_list(chapter_id: String, base_url: &str) -> Result<Vec<Page>> {
+	common::error::pages_or_notice(fetch_page_list(chapter_id, base_url))
+}
+
+fn fetch_page_list(chapter_id: String, base_url: &str) -> Result<Vec<Page>> {
 	let url = if chapter_id.starts_with("http") {
 		chapter_id
 	} else {
@@ -243,7 +250,7 @@ pub fn get_page_list(chapter_id: String, base_url: &str) -> Result<Vec<Page>> {
 
 	let html = Request::get(url)
 		.header("Referer", &format!("{base_url}/"))
-		.html()?;
+		.checked_html()?;
 
 	Ok(html
 		.select("div.container-chapter-reader > img")
@@ -3,10 +3,14 @@ use aidoku::{
 	helpers::substring::Substring,
 	prelude::format,
 	std::{current_date, html::Node},
-	std::{defaults::defaults_get, net::Request},
+	std::{defaults::defaults_get, net::HttpMethod},
 	std::{String, StringRef, Vec},
 	MangaStatus,
 };
+use common::{
+	date::DateParser,
+	net::{self, CheckedRequest},
+};
 use core::ptr;
 
 use crate::template::{MangaStreamSource, USER_AGENT};
@@ -117,20 +121,8 @@ pub fn text_with_newlines(node: Node) -> String {
 	}
 }
 
-// return chapter number from string
-pub fn get_chapter_number(id: String) -> f32 {
-	id.chars()
-		.filter(|a| (*a >= '0' && *a <= '9') || *a == ' ' || *a == '.')
-		.collect::<String>()
-		.split(' ')
-		.collect::<Vec<&str>>()
-		.into_iter()
-		.map(|a| a.parse::<f32>().unwrap_or(0.0))
-		.find(|a| *a > 0.0)
-		.unwrap_or(0.0)
-}
-
 // generates the search, filter and homepage url
+#[allow(clippy::too_many_arguments)]
 pub fn get_search_url(
 	source: &MangaStreamSource,
 	query: String,
@@ -139,9 +131,16 @@ pub fn get_search_url(
 	excluded_tags: Vec<String>,
 	status: String,
 	manga_type: String,
+	order: String,
 ) -> String {
 	let mut url = format!("{}/{}", source.base_url, source.traverse_pathname);
-	if query.is_empty() && included_tags.is_empty() && status.is_empty() && manga_type.is_empty() {
+	if query.is_empty()
+		&& included_tags.is_empty()
+		&& excluded_tags.is_empty()
+		&& status.is_empty()
+		&& manga_type.is_empty()
+		&& order.is_empty()
+	{
 		return get_listing_url(
 			source.listing,
 			source.base_url.clone(),
@@ -179,21 +178,21 @@ pub fn get_search_url(
 	if !manga_type.is_empty() {
 		url.push_str(&format!("&type={}", manga_type));
 	}
+	if !order.is_empty() {
+		url.push_str(&format!("&order={}", order));
+	}
 	url
 }
 
 // return the date depending on the language
 pub fn get_date(source: &MangaStreamSource, raw_date: StringRef) -> f64 {
-	match source.base_url.contains(source.date_string) {
-		true => raw_date
-			.0
-			.as_date(source.chapter_date_format_2, Some(source.locale_2), None)
-			.unwrap_or(0.0),
-		_ => raw_date
-			.0
-			.as_date(source.chapter_date_format, Some(source.locale), None)
-			.unwrap_or(0.0),
-	}
+	let (format, locale) = match source.base_url.contains(source.date_string) {
+		true => (source.chapter_date_format_2, source.locale_2),
+		_ => (source.chapter_date_format, source.locale),
+	};
+	DateParser::new(&[format], Some(locale))
+		.parse(&raw_date.read())
+		.unwrap_or(0.0)
 }
 
 // encoding non alpha-numeric characters to utf8
@@ -406,9 +405,9 @@ fn generate_manga_url_to_postid_mapping(
 
 	let all_manga_listing_url = format!("{}/{}/list-mode", url, pathname);
 
-	let html = Request::get(all_manga_listing_url)
+	let html = net::request(all_manga_listing_url, HttpMethod::Get)
 		.header("User-Agent", USER_AGENT)
-		.html()?;
+		.checked_html()?;
 	let mut mapping = HashMap::new();
 
 	for node in html.select(".soralist .series").array() {
@@ -451,11 +450,11 @@ pub fn generate_chapter_url_to_postid_mapping(
 	let start = current_date();
 
 	let body = format!("action=get_chapters&id={}", post_id);
-	let html = Request::post(ajax_url)
+	let html = net::request(ajax_url, HttpMethod::Post)
 		.body(body.as_bytes())
 		.header("Referer", base_url)
 		.header("User-Agent", USER_AGENT)
-		.html()?;
+		.checked_html()?;
 
 	// Janky retry logic to bypass rate limiting
 	// Retry after 10 seconds if we get rate limited. 10 seconds is the shortest
@@ -481,3 +480,163 @@ pub fn generate_chapter_url_to_postid_mapping(
 
 	Ok(mapping)
 }
+
+/// Filter values discovered from the search form of the site.
+#[derive(Clone, Default)]
+pub struct SiteFilters {
+	/// Lowercased genre label to the value submitted as `genre[]`
+	pub genres: HashMap<String, String>,
+	/// Values of the `status`, `type` and `order` options, in the order of
+	/// the form, which the select filters of the sources follow
+	pub statuses: Vec<String>,
+	pub types: Vec<String>,
+	pub orders: Vec<String>,
+}
+
+impl SiteFilters {
+	pub fn genre_id(&self, name: &str) -> Option<String> {
+		self.genres.get(&name.trim().to_lowercase()).cloned()
+	}
+
+	/// The value of option `index` of the select filter called `name`.
+	pub fn select_value(&self, name: &str, index: usize) -> Option<String> {
+		let values = match name {
+			"Status" => &self.statuses,
+			"Type" => &self.types,
+			"Order" => &self.orders,
+			_ => return None,
+		};
+		values.get(index).cloned()
+	}
+
+	fn is_empty(&self) -> bool {
+		self.genres.is_empty()
+			&& self.statuses.is_empty()
+			&& self.types.is_empty()
+			&& self.orders.is_empty()
+	}
+}
+
+static mut CACHED_SITE_FILTERS: Option<SiteFilters> = None;
+
+/// Get the genres, statuses, types and orders offered by the search form of
+/// the directory page.
+///
+/// Sites keep adding genres and renaming option values without the filters of
+/// the source being updated, so the form is scraped once and kept for the
+/// lifetime of the source, and the static values are only used when it misses.
+pub fn get_site_filters(source: &MangaStreamSource) -> SiteFilters {
+	unsafe {
+		if let Some(filters) = &*ptr::addr_of!(CACHED_SITE_FILTERS) {
+			return filters.clone();
+		}
+	}
+
+	let url = format!(
+		"{}/{}/",
+		source.base_url,
+		source
+			.directory_pathname
+			.unwrap_or(source.traverse_pathname)
+	);
+	let Ok(html) = net::request(url, HttpMethod::Get)
+		.header("User-Agent", USER_AGENT)
+		.checked_html()
+	else {
+		return SiteFilters::default();
+	};
+
+	let mut filters = SiteFilters::default();
+	for node in html
+		.select(".quickfilter li:has(input[name='genre[]'])")
+		.array()
+	{
+		let Ok(node) = node.as_node() else {
+			continue;
+		};
+		let value = node.select("input").attr("value").read();
+		let label = node.select("label").text().read();
+		if !value.is_empty() && !label.trim().is_empty() {
+			filters.genres.insert(label.trim().to_lowercase(), value);
+		}
+	}
+	for (name, values) in [
+		("status", &mut filters.statuses),
+		("type", &mut filters.types),
+		("order", &mut filters.orders),
+	] {
+		*values = html
+			.select(format!(".quickfilter input[name='{name}']"))
+			.array()
+			.filter_map(|node| node.as_node().ok())
+			.map(|node| node.attr("value").read())
+			.collect();
+	}
+
+	// don't cache a challenge or error page
+	if !filters.is_empty() {
+		unsafe {
+			CACHED_SITE_FILTERS = Some(filters.clone());
+		}
+	}
+
+	filters
+}
+
+/// Find the object passed to `ts_reader.run(...)` in the reader scripts.
+///
+/// The object is located by matching braces instead of searching for known
+/// keys, as sites append different keys (`post_id`, `prevUrl`, ...) after the
+/// `sources` array.
+pub fn find_ts_reader_payload(script: &str) -> Option<&str> {
+	let call = script.find("ts_reader.run(")? + "ts_reader.run(".len();
+	let start = call + script[call..].find('{')?;
+
+	let mut depth = 0;
+	let mut in_string = false;
+	let mut escaped = false;
+	for (i, c) in script[start..].char_indices() {
+		if in_string {
+			if escaped {
+				escaped = false;
+			} else if c == '\\' {
+				escaped = true;
+			} else if c == '"' {
+				in_string = false;
+			}
+			continue;
+		}
+		match c {
+			'"' => in_string = true,
+			'{' => depth += 1,
+			'}' => {
+				depth -= 1;
+				if depth == 0 {
+					return Some(&script[start..=start + i]);
+				}
+			}
+			_ => {}
+		}
+	}
+	None
+}
+
+/// Index of the image server chosen in the settings, `0` if unset.
+pub fn get_image_server() -> usize {
+	defaults_get("imageServer")
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.ok()
+		.and_then(|v| v.parse::<usize>().ok())
+		.unwrap_or(0)
+}
+
+/// Check whether an image server answers for the given image. Only the first
+/// byte is asked for, servers ignoring the range send the whole image.
+pub fn is_image_available(url: &str, referer: &str) -> bool {
+	let request = net::request(url, HttpMethod::Get)
+		.header("Referer", referer)
+		.header("User-Agent", USER_AGENT)
+		.header("Range", "bytes=0-0");
+	request.send();
+	matches!(request.status_code(), 200 | 206)
+}
@@ -4,11 +4,15 @@ use aidoku::{
 	std::json::parse,
 	std::net::{HttpMethod, Request},
 	std::{String, Vec},
-	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaViewer,
-	Page,
+	Chapter, DeepLink, Filter, FilterType, Manga, MangaPageResult, Page,
 };
 
 use crate::helper::*;
+use common::{
+	chapter::parse_chapter,
+	classify::{Classifier, Hint},
+	net::{self, CheckedRequest},
+};
 
 pub const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) GSA/300.0.598994205 Mobile/15E148 Safari/604";
 
@@ -131,8 +135,14 @@ impl MangaStreamSource {
 		let mut status: String = String::new();
 		let mut title: String = String::new();
 		let mut manga_type: String = String::new();
+		let mut order: String = String::new();
+		// used when the search form of the site can't be read
 		let status_options = ["", "ongoing", "completed", "hiatus"];
-		let type_options = ["", "manga", "manhwa", "manhua", "comic"];
+		let type_options = ["", "manga", "manhwa", "manhua", "comic", "novel"];
+		let order_options = ["", "title", "titlereverse", "update", "latest", "popular"];
+		// scraped from the site when the static genre ids miss or a select
+		// filter is set
+		let mut site_filters: Option<SiteFilters> = None;
 		for filter in filters {
 			match filter.kind {
 				FilterType::Title => {
@@ -141,24 +151,59 @@ impl MangaStreamSource {
 					// without using spaces search will possibly not find accurate matches
 					title = input.replace(['’', '‘', '“', '”', '–'], " ");
 				}
-				FilterType::Genre => match filter.value.as_int().unwrap_or(-1) {
-					0 => match !self.language_2.is_empty() {
-						true => excluded_tags.push((self.tagid_mapping)(filter.name)),
-						_ => excluded_tags.push(filter.object.get("id").as_string()?.read()),
-					},
-					1 => match !self.language_2.is_empty() {
-						true => included_tags.push((self.tagid_mapping)(filter.name)),
-						_ => included_tags.push(filter.object.get("id").as_string()?.read()),
-					},
-					_ => continue,
-				},
+				FilterType::Genre => {
+					let value = filter.value.as_int().unwrap_or(-1);
+					if value != 0 && value != 1 {
+						continue;
+					}
+					let mut id = match !self.language_2.is_empty() {
+						true => (self.tagid_mapping)(filter.name.clone()),
+						_ => filter
+							.object
+							.get("id")
+							.as_string()
+							.map(|v| v.read())
+							.unwrap_or_default(),
+					};
+					if id.is_empty() || id == filter.name {
+						if let Some(site_id) = site_filters
+							.get_or_insert_with(|| get_site_filters(self))
+							.genre_id(&filter.name)
+						{
+							id = site_id;
+						}
+					}
+					if id.is_empty() {
+						continue;
+					}
+					match value {
+						0 => excluded_tags.push(id),
+						_ => included_tags.push(id),
+					}
+				}
 
 				FilterType::Select => {
-					let index = filter.value.as_int().unwrap_or(-1) as usize;
-					match filter.name.as_str() {
-						"Status" => status = String::from(status_options[index]),
-						"Type" => manga_type = String::from(type_options[index]),
+					let index = filter.value.as_int().unwrap_or(-1);
+					if index < 0 {
+						continue;
+					}
+					let index = index as usize;
+					let options = match filter.name.as_str() {
+						"Status" => &status_options[..],
+						"Type" => &type_options[..],
+						"Order" => &order_options[..],
 						_ => continue,
+					};
+					let value = site_filters
+						.get_or_insert_with(|| get_site_filters(self))
+						.select_value(&filter.name, index)
+						.unwrap_or_else(|| {
+							String::from(options.get(index).copied().unwrap_or_default())
+						});
+					match filter.name.as_str() {
+						"Status" => status = value,
+						"Type" => manga_type = value,
+						_ => order = value,
 					}
 				}
 				_ => continue,
@@ -172,6 +217,7 @@ impl MangaStreamSource {
 			excluded_tags,
 			status,
 			manga_type,
+			order,
 		);
 		self.parse_manga_listing(url, String::from("Latest"), page)
 	}
@@ -195,9 +241,9 @@ impl MangaStreamSource {
 			base_url
 		};
 		let mut mangas: Vec<Manga> = Vec::new();
-		let html = Request::new(url, HttpMethod::Get)
+		let html = net::request(url, HttpMethod::Get)
 			.header("User-Agent", USER_AGENT)
-			.html()?;
+			.checked_html()?;
 		for manga in html.select(self.manga_selector).array() {
 			let manga_node = manga.as_node().expect("Failed to get manga as node");
 			let title = manga_node.select(self.manga_title).attr("title").read();
@@ -264,9 +310,9 @@ impl MangaStreamSource {
 		} else {
 			format!("{}/{}/{}", self.base_url, self.traverse_pathname, id)
 		};
-		let html = Request::new(&url, HttpMethod::Get)
+		let html = net::request(&url, HttpMethod::Get)
 			.header("User-Agent", USER_AGENT)
-			.html()?;
+			.checked_html()?;
 		let mut title = html.select(self.manga_details_title).text().read();
 		for i in self.manga_title_trim.iter() {
 			if title.contains(i) {
@@ -300,30 +346,26 @@ impl MangaStreamSource {
 			self.status_options_2,
 		);
 		let mut categories = Vec::new();
-		let mut nsfw = if self.is_nsfw {
-			MangaContentRating::Nsfw
-		} else {
-			MangaContentRating::Safe
-		};
 		for node in html.select(self.manga_details_categories).array() {
 			let category = node
 				.as_node()
 				.expect("Failed to get category as node")
 				.text()
 				.read();
-			for genre in self.nsfw_genres.iter() {
-				if *genre == category {
-					nsfw = MangaContentRating::Nsfw
-				}
-			}
-			categories.push(category.clone());
+			categories.push(category);
 		}
+		let mut overrides: Vec<(&str, Hint)> = self
+			.nsfw_genres
+			.iter()
+			.map(|genre| (genre.as_str(), Hint::Nsfw))
+			.collect();
+		overrides.push((self.manga_details_type_options, Hint::Rtl));
 		let manga_type = html.select(self.manga_details_type).text().read();
-		let viewer = if manga_type.as_str() == self.manga_details_type_options {
-			MangaViewer::Rtl
-		} else {
-			MangaViewer::Scroll
-		};
+		let (nsfw, viewer) = Classifier::new(self.language)
+			.with_overrides(&overrides)
+			.with_viewer(Hint::Scroll)
+			.with_rating(if self.is_nsfw { Hint::Nsfw } else { Hint::Safe })
+			.classify(&categories, &manga_type, "");
 
 		Ok(Manga {
 			id,
@@ -358,9 +400,9 @@ impl MangaStreamSource {
 		};
 
 		let mut chapters: Vec<Chapter> = Vec::new();
-		let html = Request::new(url, HttpMethod::Get)
+		let html = net::request(url, HttpMethod::Get)
 			.header("User-Agent", USER_AGENT)
-			.html()?;
+			.checked_html()?;
 		for chapter in html.select(self.chapter_selector).array() {
 			let chapter_node = chapter.as_node().expect("Failed to get chapter as node");
 			let raw_title = chapter_node.select(self.chapter_title).text().read();
@@ -410,13 +452,14 @@ impl MangaStreamSource {
 				chapter_id = get_id_from_url(chapter_url.clone());
 			}
 
-			let chapter_number = get_chapter_number(raw_title.clone());
+			let parsed = parse_chapter(&raw_title, "");
 			let date_updated = get_date(self, chapter_node.select(self.chapter_date).text());
 
 			chapters.push(Chapter {
 				id: chapter_id,
 				title,
-				chapter: chapter_number,
+				volume: parsed.volume,
+				chapter: parsed.chapter,
 				date_updated,
 				url: chapter_url,
 				lang: String::from(self.language),
@@ -426,8 +469,14 @@ impl MangaStreamSource {
 		Ok(chapters)
 	}
 
-	//parse the manga chapter images list
+	/// The pages of a chapter, with a failure the user can act on shown as a
+	/// text page, see [`common::error::pages_or_notice`].
 	pub fn parse_page_list(&self, id: String) -> Result<Vec<Page>> {
+		common::error::pages_or_notice(self.fetch_page_list(id))
+	}
+
+	//parse the manga chapter images list
+	fn fetch_page_list(&self, id: String) -> Result<Vec<Page>> {
 		let url = if self.use_chapter_postids {
 			format!("{}/?p={}", self.base_url, id)
 		} else if self.has_random_chapter_prefix {
@@ -437,39 +486,47 @@ impl MangaStreamSource {
 		};
 
 		let mut pages: Vec<Page> = Vec::new();
-		let html = Request::new(url, HttpMethod::Get)
+		let html = net::request(url, HttpMethod::Get)
 			.header("Referer", &self.base_url)
 			.header("User-Agent", USER_AGENT)
-			.html()?;
+			.checked_html()?;
 		if self.alt_pages {
 			let raw_text = html.select("script").html().read();
-
-			let trimmed_json = {
-				let mut trimmed_json = &raw_text[raw_text.find(r#":[{"s"#).unwrap_or(0) + 2
-					..raw_text
-						.rfind("}]});")
-						.unwrap_or(raw_text.rfind("}],").unwrap_or(0))
-						+ 1];
-
-				if trimmed_json.contains("Default 2") {
-					trimmed_json = &trimmed_json[..trimmed_json.rfind(r#",{"s"#).unwrap_or(0)];
+			let payload = find_ts_reader_payload(&raw_text).ok_or(AidokuError {
+				reason: AidokuErrorKind::JsonParseError,
+			})?;
+			let json = parse(payload.as_bytes())?.as_object()?;
+
+			let mut servers: Vec<Vec<String>> = Vec::new();
+			for server in json.get("sources").as_array()? {
+				let mut images = Vec::new();
+				for image in server.as_object()?.get("images").as_array()? {
+					images.push(urlencode(image.as_string()?.read()));
 				}
-
-				// if "post_id" is present, we've gone too far
-				if trimmed_json.contains("post_id") {
-					trimmed_json = &trimmed_json[..trimmed_json.find("}],").unwrap_or(0) + 1];
+				if !images.is_empty() {
+					servers.push(images);
 				}
+			}
+			if servers.is_empty() {
+				return Ok(pages);
+			}
 
-				trimmed_json
+			// start with the server chosen in the settings and move on to the
+			// next one if it can't serve the first image
+			let preferred = get_image_server().min(servers.len() - 1);
+			let chosen = if servers.len() == 1 {
+				preferred
+			} else {
+				(0..servers.len())
+					.map(|offset| (preferred + offset) % servers.len())
+					.find(|index| is_image_available(&servers[*index][0], &self.base_url))
+					.unwrap_or(preferred)
 			};
 
-			let json = parse(trimmed_json.as_bytes())?.as_object()?;
-			let images = json.get("images").as_array()?;
-			for (index, page) in images.enumerate() {
-				let page_url = urlencode(page.as_string()?.read());
+			for (index, page_url) in servers.swap_remove(chosen).into_iter().enumerate() {
 				pages.push(Page {
 					index: index as i32,
-					url: page_url,
+					url: net::relay_image_url(page_url),
 					..Default::default()
 				});
 			}
@@ -488,7 +545,7 @@ impl MangaStreamSource {
 				}
 				pages.push(Page {
 					index: at as i32,
-					url: page_url,
+					url: net::relay_image_url(page_url),
 					..Default::default()
 				});
 			}
@@ -15,18 +15,6 @@ pub fn manga_status(status: String) -> MangaStatus {
 	}
 }
 
-pub fn get_chapter_number(id: String) -> f32 {
-	id.chars()
-		.filter(|a| (*a >= '0' && *a <= '9') || *a == ' ' || *a == '.')
-		.collect::<String>()
-		.split(' ')
-		.collect::<Vec<&str>>()
-		.into_iter()
-		.map(|a| a.parse::<f32>().unwrap_or(0.0))
-		.find(|a| *a > 0.0)
-		.unwrap_or(0.0)
-}
-
 // generates the search, filter and homepage url
 pub fn get_search_url(
 	base_url: String,
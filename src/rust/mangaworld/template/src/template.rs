@@ -11,7 +11,8 @@ use aidoku::{
 	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaViewer,
 	Page,
 };
-use crate::helper::{get_chapter_number, get_search_url, manga_status};
+use crate::helper::{get_search_url, manga_status};
+use common::{chapter::parse_chapter, net::CheckedRequest};
 
 static COOKIE_NAME: &str = "MWCookie=";
 static mut STORED_COOKIE: Option<String> = None;
@@ -32,7 +33,7 @@ fn get_html_with_cookie(url: String, referer: Option<&str>) -> Result<Node> {
 		request
 	};
 
-	let html = request.html()?;
+	let html = request.checked_html()?;
 
 	if let (Some(start), Some(end)) = (
 		html.outer_html().to_string().find(COOKIE_NAME).map(|i| i + COOKIE_NAME.len()),
@@ -45,9 +46,9 @@ fn get_html_with_cookie(url: String, referer: Option<&str>) -> Result<Node> {
 			.header("Cookie", &new_cookie);
 
 		return if let Some(ref_url) = referer {
-			final_request.header("referer", ref_url).html()
+			final_request.header("referer", ref_url).checked_html()
 		} else {
-			final_request.html()
+			final_request.checked_html()
 		};
 	}
 
@@ -211,7 +212,7 @@ pub fn parse_chapter_list(base_url: String, id: String) -> Result<Vec<Chapter>>
 	for chapter in html.select(".chapters-wrapper .chap").array() {
 		let chapter_node = chapter.as_node().expect("Failed to get chapter as node");
 		let title = chapter_node.select("span").text().read();
-		let chapter_number = get_chapter_number(title.clone());
+		let parsed = parse_chapter(&title, "");
 		let chapter_url = chapter_node.attr("href").read();
 		let chapter_id = String::from(
 			chapter_url
@@ -228,8 +229,8 @@ pub fn parse_chapter_list(base_url: String, id: String) -> Result<Vec<Chapter>>
 		chapters.push(Chapter {
 			id: chapter_id,
 			title,
-			volume: -1.0,
-			chapter: chapter_number,
+			volume: parsed.volume,
+			chapter: parsed.chapter,
 			date_updated,
 			scanlator: String::new(),
 			url: chapter_url,
@@ -239,10 +240,20 @@ pub fn parse_chapter_list(base_url: String, id: String) -> Result<Vec<Chapter>>
 	Ok(chapters)
 }
 
+/// The pages of a chapter, with a failure the user can act on shown as a
+/// text page, see [`common::error::pages_or_notice`].
 pub fn parse_page_list(
 	base_url: String,
 	manga_id: String,
 	chapter_id: String,
+) -> Result<Vec<Page>> {
+	common::error::pages_or_notice(fetch_page_list(base_url, manga_id, chapter_id))
+}
+
+fn fetch_page_list(
+	base_url: String,
+	manga_id: String,
+	chapter_id: String,
 ) -> Result<Vec<Page>> {
 	let url = format!("{base_url}/manga/{manga_id}/read/{chapter_id}/?style=list");
 	let html = get_html_with_cookie(url.clone(), Some(&url))?;
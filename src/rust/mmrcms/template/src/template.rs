@@ -1,7 +1,7 @@
 #![allow(static_mut_refs)]
 use aidoku::{
-	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
-	MangaViewer, Page,
+	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaContentRating, MangaPageResult,
+	MangaStatus, MangaViewer, Page,
 	error::{AidokuError, Result},
 	helpers::{cfemail::decode_cfemail, substring::Substring, uri::encode_uri_component},
 	prelude::format,
@@ -13,7 +13,12 @@ use aidoku::{
 	},
 };
 
-use crate::helper::{append_protocol, extract_f32_from_string};
+use crate::helper::append_protocol;
+use common::{
+	chapter::parse_chapter,
+	classify::Classifier,
+	net::{self, CheckedRequest},
+};
 
 pub static mut CACHED_MANGA: Option<Node> = None;
 static mut CACHED_MANGA_ID: Option<String> = None;
@@ -26,13 +31,81 @@ static mut CACHED_MANGA_ID: Option<String> = None;
 /// not spamming sources with useless requests)
 static mut INTERNAL_USE_SEARCH_ENGINE: bool = true;
 
+/// Name of the listing backed by `/latest-release`.
+pub const LATEST_RELEASES: &str = "Latest releases";
+
+/// Options of the "Type" and "Status" filters in `res/filters.json`.
+const TYPE_OPTIONS: [&str; 7] = [
+	"Any",
+	"Manga",
+	"Manhwa",
+	"Manhua",
+	"Comic",
+	"One shot",
+	"Doujinshi",
+];
+const STATUS_OPTIONS: [&str; 3] = ["Any", "Ongoing", "Complete"];
+
+/// Options of the `/advanced-search` form as `(label, value)` pairs, in the
+/// order of the form.
+#[derive(Default)]
+struct AdvancedSearchOptions {
+	types: Vec<(String, String)>,
+	statuses: Vec<(String, String)>,
+}
+
+static mut CACHED_ADVANCED_OPTIONS: Option<AdvancedSearchOptions> = None;
+
+fn parse_form_options(html: &Node, field: &str) -> Vec<(String, String)> {
+	let mut options = Vec::new();
+	for elem in html
+		.select(format!("select[name='{field}[]'] option"))
+		.array()
+	{
+		if let Ok(node) = elem.as_node() {
+			options.push((node.text().read().to_lowercase(), node.attr("value").read()));
+		}
+	}
+	for elem in html.select(format!("input[name='{field}[]']")).array() {
+		if let Ok(node) = elem.as_node() {
+			let label = html
+				.select(format!("label[for='{}']", node.attr("id").read()))
+				.text()
+				.read();
+			options.push((label.trim().to_lowercase(), node.attr("value").read()));
+		}
+	}
+	options.retain(|(label, value)| !label.is_empty() && !value.is_empty());
+	options
+}
+
+/// Finds the form value for the option at `index` of a filter, whose English
+/// labels are `labels`. Sites in other languages translate the labels, so
+/// the option is otherwise found by the id a stock install gives it, then by
+/// its position in the form.
+fn find_option<'o>(
+	options: &'o [(String, String)],
+	labels: &[&str],
+	index: usize,
+) -> Option<&'o str> {
+	let label = labels.get(index)?.to_lowercase();
+	let mut buffer = itoa::Buffer::new();
+	let id = buffer.format(index);
+	options
+		.iter()
+		.find(|(option, _)| *option == label)
+		.or_else(|| options.iter().find(|(_, value)| value == id))
+		.or_else(|| options.get(index.checked_sub(1)?))
+		.map(|(_, value)| value.as_str())
+}
+
 pub fn cache_manga_page(url: &str) {
 	unsafe {
 		if CACHED_MANGA.is_some() && CACHED_MANGA_ID.clone().unwrap_or_default() == url {
 			return;
 		}
 
-		if let Ok(html) = Request::new(url, HttpMethod::Get).html() {
+		if let Ok(html) = net::request(url, HttpMethod::Get).checked_html() {
 			decode_cfemail(&html);
 			CACHED_MANGA = Some(html);
 			CACHED_MANGA_ID = Some(String::from(url));
@@ -84,26 +157,7 @@ impl<'a> Default for MMRCMSSource<'a> {
 			category: "Category",
 			tags: "Tag",
 
-			category_parser: |_, categories| {
-				let mut nsfw = MangaContentRating::Safe;
-				let mut viewer = MangaViewer::Rtl;
-				for category in categories {
-					match category.as_str() {
-						"Adult" | "Smut" | "Mature" | "18+" | "Hentai" => {
-							nsfw = MangaContentRating::Nsfw
-						}
-						"Ecchi" | "16+" => {
-							nsfw = match nsfw {
-								MangaContentRating::Nsfw => MangaContentRating::Nsfw,
-								_ => MangaContentRating::Suggestive,
-							}
-						}
-						"Webtoon" | "Manhwa" | "Manhua" => viewer = MangaViewer::Scroll,
-						_ => continue,
-					}
-				}
-				(nsfw, viewer)
-			},
+			category_parser: |_, categories| Classifier::new("").classify(&categories, "", ""),
 			category_mapper: |idx| {
 				if idx != 0 {
 					String::from(itoa::Buffer::new().format(idx))
@@ -131,11 +185,11 @@ impl<'a> MMRCMSSource<'a> {
 
 	fn self_search<T: AsRef<str>>(&self, query: T) -> Result<MangaPageResult> {
 		let query = query.as_ref();
-		let html = Request::new(
+		let html = net::request(
 			format!("{}/changeMangaList?type=text", self.base_url),
 			HttpMethod::Get,
 		)
-		.html()?;
+		.checked_html()?;
 		decode_cfemail(&html);
 		let manga = html
 			.select("ul.manga-list a")
@@ -169,8 +223,115 @@ impl<'a> MMRCMSSource<'a> {
 		})
 	}
 
+	/// The options of the advanced search form, loaded once. A failed request
+	/// isn't cached, so it is tried again on the next search.
+	fn advanced_options(&self) -> Option<&AdvancedSearchOptions> {
+		unsafe {
+			if CACHED_ADVANCED_OPTIONS.is_none() {
+				let html = net::request(
+					format!("{}/advanced-search", self.base_url),
+					HttpMethod::Get,
+				)
+				.checked_html()
+				.ok()?;
+				CACHED_ADVANCED_OPTIONS = Some(AdvancedSearchOptions {
+					types: parse_form_options(&html, "types"),
+					statuses: parse_form_options(&html, "status"),
+				});
+			}
+			CACHED_ADVANCED_OPTIONS.as_ref()
+		}
+	}
+
+	/// Parses a list of results linking to manga pages, as returned by the
+	/// advanced search and the latest releases feed.
+	fn parse_manga_items(&self, html: &Node, item_selector: &str) -> Vec<Manga> {
+		let link_selector = format!("a[href*='{}/{}/']", self.base_url, self.manga_path);
+		let mut manga: Vec<Manga> = Vec::new();
+		for elem in html.select(item_selector).array() {
+			let Ok(node) = elem.as_node() else {
+				continue;
+			};
+			let link = node.select(&link_selector).first();
+			let url = link.attr("abs:href").read();
+			let Some(id) = url
+				.split(&format!("/{}/", self.manga_path))
+				.nth(1)
+				.and_then(|rest| rest.split('/').next())
+				.map(String::from)
+			else {
+				continue;
+			};
+			if id.is_empty() || manga.iter().any(|m| m.id == id) {
+				continue;
+			}
+			let mut title = node
+				.select(".media-heading, .manga-heading, a.chart-title strong")
+				.first()
+				.text()
+				.read();
+			if title.is_empty() {
+				title = link.text().read();
+			}
+			let cover = self.guess_cover(&node.select("img").attr("abs:src").read(), &id);
+			manga.push(Manga {
+				url: format!("{}/{}/{}", self.base_url, self.manga_path, id),
+				id,
+				cover,
+				title: String::from(title.trim()),
+				..Default::default()
+			});
+		}
+		manga
+	}
+
+	/// Searches through the form of `/advanced-search`, which is the only way
+	/// to filter by type, status and release year.
+	fn advanced_search(&self, params: Vec<String>, page: i32) -> Result<MangaPageResult> {
+		let body = format!(
+			"params={}&page={}",
+			encode_uri_component(params.join("&")),
+			itoa::Buffer::new().format(page)
+		);
+		let html = net::request(
+			format!("{}/advSearchFilter", self.base_url),
+			HttpMethod::Post,
+		)
+		.header("Content-Type", "application/x-www-form-urlencoded")
+		.header("X-Requested-With", "XMLHttpRequest")
+		.body(body.as_bytes())
+		.checked_html()?;
+		decode_cfemail(&html);
+		let manga = self.parse_manga_items(&html, "div.media, div[class^=col-sm-]");
+		let has_more = !html.select("ul.pagination a[rel=next]").array().is_empty();
+		Ok(MangaPageResult { manga, has_more })
+	}
+
+	pub fn get_manga_listing(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		if listing.name != LATEST_RELEASES {
+			return self.get_manga_list(Vec::new(), page);
+		}
+		let html = net::request(
+			format!(
+				"{}/latest-release?page={}",
+				self.base_url,
+				itoa::Buffer::new().format(page)
+			),
+			HttpMethod::Get,
+		)
+		.checked_html()?;
+		decode_cfemail(&html);
+		let manga = self.parse_manga_items(&html, "div.mangalist div.manga-item");
+		let has_more = !html.select("ul.pagination a[rel=next]").array().is_empty();
+		Ok(MangaPageResult { manga, has_more })
+	}
+
 	pub fn get_manga_list(&self, filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 		let mut query: Vec<String> = Vec::new();
+		// parameters of the advanced search, only used when one of its own
+		// filters is set
+		let mut params: Vec<String> = Vec::new();
+		let mut use_advanced = false;
 		let mut title = String::new();
 		for filter in filters {
 			match filter.kind {
@@ -186,16 +347,28 @@ impl<'a> MMRCMSSource<'a> {
 					title = encode_uri_component(t);
 					break;
 				}
-				FilterType::Author => query.push(format!(
-					"artist={}",
-					encode_uri_component(
-						filter
-							.value
-							.as_string()
-							.map(|v| v.read())
-							.unwrap_or_default()
-					)
-				)),
+				FilterType::Author => {
+					let author = filter
+						.value
+						.as_string()
+						.map(|v| v.read())
+						.unwrap_or_default();
+					if !author.is_empty() {
+						params.push(format!("author={}", encode_uri_component(&author)));
+					}
+					query.push(format!("artist={}", encode_uri_component(author)))
+				}
+				FilterType::Text if filter.name == "Release year" => {
+					let year = filter
+						.value
+						.as_string()
+						.map(|v| v.read())
+						.unwrap_or_default();
+					if let Ok(year) = year.trim().parse::<u16>() {
+						params.push(format!("release={year}"));
+						use_advanced = true;
+					}
+				}
 				FilterType::Sort => {
 					if let Ok(value) = filter.value.as_object() {
 						let index = value.get("index").as_int().unwrap_or(0);
@@ -213,7 +386,25 @@ impl<'a> MMRCMSSource<'a> {
 					let value = filter.value.as_int().unwrap_or(-1);
 					match filter.name.as_str() {
 						x if x == self.category => {
-							query.push(format!("cat={}", (self.category_mapper)(value)))
+							let category = (self.category_mapper)(value);
+							if !category.is_empty() {
+								params.push(format!("categories[]={category}"));
+							}
+							query.push(format!("cat={category}"))
+						}
+						"Type" | "Status" if value > 0 => {
+							let Some(form) = self.advanced_options() else {
+								continue;
+							};
+							let (field, labels, options) = if filter.name == "Type" {
+								("types", &TYPE_OPTIONS[..], &form.types)
+							} else {
+								("status", &STATUS_OPTIONS[..], &form.statuses)
+							};
+							if let Some(id) = find_option(options, labels, value as usize) {
+								params.push(format!("{field}[]={id}"));
+								use_advanced = true;
+							}
 						}
 						x if x == self.tags => {
 							query.push(format!("tag={}", (self.tags_mapper)(value)))
@@ -227,7 +418,7 @@ impl<'a> MMRCMSSource<'a> {
 		if !title.is_empty() {
 			if self.use_search_engine && unsafe { INTERNAL_USE_SEARCH_ENGINE } {
 				let url = format!("{}/search?query={}", self.base_url, title);
-				if let Ok(obj) = Request::new(&url, HttpMethod::Get).json()
+				if let Ok(obj) = net::request(&url, HttpMethod::Get).checked_json()
 					&& let Ok(json) = obj.as_object()
 					&& let Ok(suggestions) = json.get("suggestions").as_array()
 				{
@@ -256,6 +447,8 @@ impl<'a> MMRCMSSource<'a> {
 			} else {
 				self.self_search(title)
 			}
+		} else if use_advanced {
+			self.advanced_search(params, page)
 		} else {
 			let url = format!(
 				"{}/filterList?page={}&{}",
@@ -263,7 +456,7 @@ impl<'a> MMRCMSSource<'a> {
 				itoa::Buffer::new().format(page),
 				query.join("&")
 			);
-			let html = Request::new(&url, HttpMethod::Get).html()?;
+			let html = net::request(&url, HttpMethod::Get).checked_html()?;
 			decode_cfemail(&html);
 			let node = html.select("div[class^=col-sm-]");
 			let elems = node.array();
@@ -384,13 +577,10 @@ impl<'a> MMRCMSSource<'a> {
 					let url = chapter_node.select("a").attr("abs:href").read();
 
 					if let Some(chapter_id) = url.split('/').nth(5).map(String::from) {
-						let volume = extract_f32_from_string(
-							String::from("volume-"),
-							chapter_node.attr("class").read(),
-						);
+						let volume = parse_chapter(&chapter_node.attr("class").read(), "").volume;
 						let chapter_title = chapter_node.select("a").first().text().read();
 
-						let chapter = extract_f32_from_string(title.clone(), chapter_title.clone());
+						let chapter = parse_chapter(&chapter_title, &title).chapter;
 						let mut title = chapter_node.select("em").text().read();
 						if title.is_empty() && should_extract_chapter_title {
 							title = chapter_title;
@@ -422,9 +612,15 @@ impl<'a> MMRCMSSource<'a> {
 			.collect::<Vec<Chapter>>())
 	}
 
+	/// The pages of a chapter, with a failure the user can act on shown as a
+	/// text page, see [`common::error::pages_or_notice`].
 	pub fn get_page_list(&self, manga_id: String, id: String) -> Result<Vec<Page>> {
+		common::error::pages_or_notice(self.fetch_page_list(manga_id, id))
+	}
+
+	fn fetch_page_list(&self, manga_id: String, id: String) -> Result<Vec<Page>> {
 		let url = format!("{}/{}/{}/{}", self.base_url, self.manga_path, manga_id, id);
-		let html = Request::new(&url, HttpMethod::Get).string()?;
+		let html = net::request(&url, HttpMethod::Get).checked_string()?;
 		let array = json::parse(
 			html.substring_after("var pages = ")
 				.unwrap_or_default()
@@ -449,7 +645,7 @@ impl<'a> MMRCMSSource<'a> {
 				};
 				pages.push(Page {
 					index: idx as i32,
-					url,
+					url: net::relay_image_url(url),
 					..Default::default()
 				});
 			}
@@ -0,0 +1,303 @@
+//! The GraphQL API of the v3 ("v3x") site, served at `/apo/` on every mirror.
+//!
+//! Manga and chapter ids are the same as on the classic site, so titles
+//! added through either path keep working with the other.
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind, Result},
+	prelude::*,
+	std::{defaults::defaults_get, net::HttpMethod, net::Request, ObjectRef, String, Vec},
+	Chapter, Manga, MangaContentRating, MangaPageResult, MangaStatus, MangaViewer, Page,
+};
+
+use crate::helper::{get_base_url, json_string, manga_id_from_path, series_number};
+use chapter_recognition::{parse_chapter_number, parse_volume_number};
+
+const SEARCH_QUERY: &str = "query get_content_searchComic($select: SearchComic_Select) { get_content_searchComic(select: $select) { paging { page pages } items { data { id name urlPath urlCoverOri } } } }";
+const COMIC_QUERY: &str = "query get_content_comicNode($id: ID!) { get_content_comicNode(id: $id) { data { id name authors artists genres originalStatus uploadStatus summary { code } urlPath urlCoverOri readDirection } } }";
+const CHAPTERS_QUERY: &str = "query get_content_chapterList($id: ID!) { get_content_comicNode(id: $id) { data { tranLang } } get_content_chapterList(comicId: $id) { data { id dname title dateModify dateCreate urlPath userNode { data { name } } groupNodes { data { name } } } } }";
+const CHAPTER_QUERY: &str = "query get_content_chapterNode($id: ID!) { get_content_chapterNode(id: $id) { data { comicId imageFile { urlList } } } }";
+
+fn missing_data() -> AidokuError {
+	AidokuError {
+		reason: AidokuErrorKind::JsonParseError,
+	}
+}
+
+fn read_string(object: &ObjectRef, key: &str) -> String {
+	object
+		.get(key)
+		.as_string()
+		.map(|v| v.read())
+		.unwrap_or_default()
+}
+
+/// Ids are strings in most responses but plain numbers in some.
+fn read_id(object: &ObjectRef, key: &str) -> String {
+	match object.get(key).as_int() {
+		Ok(id) => format!("{id}"),
+		Err(_) => read_string(object, key),
+	}
+}
+
+fn read_strings(object: &ObjectRef, key: &str) -> Vec<String> {
+	match object.get(key).as_array() {
+		Ok(array) => array
+			.filter_map(|v| v.as_string().ok())
+			.map(|v| v.read())
+			.collect(),
+		Err(_) => Vec::new(),
+	}
+}
+
+/// Sends a query and returns its `data` object.
+fn query(query: &str, variables: String) -> Result<ObjectRef> {
+	let body = format!(
+		r#"{{"query":{},"variables":{}}}"#,
+		json_string(query),
+		variables
+	);
+	let url = format!("{}/apo/", get_base_url());
+	let json = Request::new(url.as_str(), HttpMethod::Post)
+		.header("Content-Type", "application/json")
+		.header("Referer", &get_base_url())
+		.body(body.as_bytes())
+		.json()?
+		.as_object()?;
+	json.get("data").as_object()
+}
+
+fn absolute_url(path: &str) -> String {
+	if path.starts_with("http") {
+		String::from(path)
+	} else {
+		format!("{}{}", get_base_url(), path)
+	}
+}
+
+/// Searches titles. `sort` is one of the sort fields of the v3 browse page.
+pub fn search(word: &str, sort: &str, page: i32) -> Result<MangaPageResult> {
+	let mut langs: Vec<String> = Vec::new();
+	if let Ok(languages) = defaults_get("languages").and_then(|v| v.as_array()) {
+		for lang in languages {
+			if let Ok(lang) = lang.as_string() {
+				langs.push(json_string(&lang.read()));
+			}
+		}
+	}
+	let variables = format!(
+		r#"{{"select":{{"word":{},"sortby":{},"page":{page},"size":36,"incTLangs":[{}]}}}}"#,
+		json_string(word),
+		json_string(sort),
+		langs.join(",")
+	);
+	let result = query(SEARCH_QUERY, variables)?
+		.get("get_content_searchComic")
+		.as_object()?;
+
+	let mut manga: Vec<Manga> = Vec::new();
+	for item in result.get("items").as_array()? {
+		let data = item.as_object()?.get("data").as_object()?;
+		let url_path = read_string(&data, "urlPath");
+		let id = manga_id_from_path(&url_path).unwrap_or_else(|| read_id(&data, "id"));
+		if id.is_empty() {
+			continue;
+		}
+		manga.push(Manga {
+			id,
+			cover: absolute_url(&read_string(&data, "urlCoverOri")),
+			title: read_string(&data, "name"),
+			url: absolute_url(&url_path),
+			..Default::default()
+		});
+	}
+
+	let paging = result.get("paging").as_object()?;
+	let has_more =
+		paging.get("page").as_int().unwrap_or(0) < paging.get("pages").as_int().unwrap_or(0);
+	Ok(MangaPageResult { manga, has_more })
+}
+
+pub fn get_manga_details(id: String) -> Result<Manga> {
+	let variables = format!(r#"{{"id":{}}}"#, json_string(series_number(&id)));
+	let data = query(COMIC_QUERY, variables)?
+		.get("get_content_comicNode")
+		.as_object()?
+		.get("data")
+		.as_object()?;
+
+	let categories = read_strings(&data, "genres")
+		.into_iter()
+		.map(|genre| {
+			// genres come as slugs, e.g. "slice_of_life"
+			let mut name = String::with_capacity(genre.len());
+			for (i, word) in genre.split('_').enumerate() {
+				if i > 0 {
+					name.push(' ');
+				}
+				let mut chars = word.chars();
+				if let Some(first) = chars.next() {
+					name.extend(first.to_uppercase());
+					name.push_str(chars.as_str());
+				}
+			}
+			name
+		})
+		.collect::<Vec<String>>();
+
+	let status = match read_string(&data, "uploadStatus").as_str() {
+		"" => read_string(&data, "originalStatus"),
+		status => String::from(status),
+	};
+	let status = match status.as_str() {
+		"ongoing" => MangaStatus::Ongoing,
+		"completed" => MangaStatus::Completed,
+		"hiatus" => MangaStatus::Hiatus,
+		"cancelled" => MangaStatus::Cancelled,
+		_ => MangaStatus::Unknown,
+	};
+
+	let nsfw = if categories.iter().any(|genre| {
+		matches!(
+			genre.as_str(),
+			"Hentai" | "Adult" | "Smut" | "Mature" | "Gore"
+		)
+	}) {
+		MangaContentRating::Nsfw
+	} else {
+		MangaContentRating::Safe
+	};
+	let viewer = if categories.iter().any(|genre| genre == "Webtoon") {
+		MangaViewer::Scroll
+	} else {
+		match read_string(&data, "readDirection").as_str() {
+			"rtl" => MangaViewer::Rtl,
+			"ltr" => MangaViewer::Ltr,
+			_ => MangaViewer::Scroll,
+		}
+	};
+
+	let description = data
+		.get("summary")
+		.as_object()
+		.map(|summary| read_string(&summary, "code"))
+		.unwrap_or_default();
+	let url_path = read_string(&data, "urlPath");
+
+	Ok(Manga {
+		id,
+		cover: absolute_url(&read_string(&data, "urlCoverOri")),
+		title: read_string(&data, "name"),
+		author: read_strings(&data, "authors").join(", "),
+		artist: read_strings(&data, "artists").join(", "),
+		description,
+		url: absolute_url(&url_path),
+		categories,
+		status,
+		nsfw,
+		viewer,
+	})
+}
+
+pub fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	let variables = format!(r#"{{"id":{}}}"#, json_string(series_number(&id)));
+	let result = query(CHAPTERS_QUERY, variables)?;
+	let lang = result
+		.get("get_content_comicNode")
+		.as_object()
+		.and_then(|comic| comic.get("data").as_object())
+		.map(|comic| read_string(&comic, "tranLang"))
+		.ok()
+		.filter(|lang| !lang.is_empty())
+		.unwrap_or_else(|| String::from("en"));
+	let list = result.get("get_content_chapterList").as_array()?;
+
+	let mut chapters: Vec<Chapter> = Vec::new();
+	for item in list {
+		let data = item.as_object()?.get("data").as_object()?;
+		let chapter_id = read_id(&data, "id");
+		if chapter_id.is_empty() {
+			continue;
+		}
+		let name = read_string(&data, "dname");
+		let title = read_string(&data, "title");
+
+		let groups = match data.get("groupNodes").as_array() {
+			Ok(groups) => groups
+				.filter_map(|group| group.as_object().ok())
+				.filter_map(|group| group.get("data").as_object().ok())
+				.map(|group| read_string(&group, "name"))
+				.filter(|name| !name.is_empty())
+				.collect::<Vec<String>>(),
+			Err(_) => Vec::new(),
+		};
+		let scanlator = if groups.is_empty() {
+			data.get("userNode")
+				.as_object()
+				.and_then(|user| user.get("data").as_object())
+				.map(|user| read_string(&user, "name"))
+				.unwrap_or_default()
+		} else {
+			groups.join(", ")
+		};
+
+		// milliseconds since the epoch
+		let date = data
+			.get("dateModify")
+			.as_float()
+			.or_else(|_| data.get("dateCreate").as_float())
+			.unwrap_or(0.0);
+
+		chapters.push(Chapter {
+			chapter: parse_chapter_number(&title, &name),
+			volume: parse_volume_number(&title, &name),
+			title,
+			date_updated: date / 1000.0,
+			scanlator,
+			url: absolute_url(&read_string(&data, "urlPath")),
+			id: chapter_id,
+			lang: lang.clone(),
+		});
+	}
+	// the API lists chapters oldest first
+	chapters.reverse();
+	Ok(chapters)
+}
+
+fn get_chapter_node(chapter_id: &str) -> Result<ObjectRef> {
+	let variables = format!(r#"{{"id":{}}}"#, json_string(chapter_id));
+	query(CHAPTER_QUERY, variables)?
+		.get("get_content_chapterNode")
+		.as_object()?
+		.get("data")
+		.as_object()
+}
+
+pub fn get_page_list(chapter_id: String) -> Result<Vec<Page>> {
+	let urls = get_chapter_node(&chapter_id)?
+		.get("imageFile")
+		.as_object()?
+		.get("urlList")
+		.as_array()?;
+
+	let mut pages: Vec<Page> = Vec::new();
+	for (index, url) in urls.enumerate() {
+		pages.push(Page {
+			index: index as i32,
+			url: url.as_string()?.read(),
+			..Default::default()
+		});
+	}
+	if pages.is_empty() {
+		return Err(missing_data());
+	}
+	Ok(pages)
+}
+
+/// Finds the title a chapter belongs to, for chapter links without it.
+pub fn get_chapter_manga_id(chapter_id: &str) -> Result<String> {
+	let id = read_id(&get_chapter_node(chapter_id)?, "comicId");
+	if id.is_empty() {
+		return Err(missing_data());
+	}
+	Ok(id)
+}
@@ -1,4 +1,111 @@
-use aidoku::std::{String, Vec};
+use aidoku::{
+	prelude::*,
+	std::{defaults::defaults_get, String, Vec},
+};
+
+const DEFAULT_URL: &str = "https://bato.to";
+
+/// The domain requests go to: the custom mirror if one is set, otherwise the
+/// one picked from the list.
+pub fn get_base_url() -> String {
+	let read = |key: &str| {
+		defaults_get(key)
+			.and_then(|v| v.as_string().map(|v| v.read()))
+			.map(|v| String::from(v.trim().trim_end_matches('/')))
+			.unwrap_or_default()
+	};
+	let custom = read("customSourceURL");
+	if custom.starts_with("http") {
+		return custom;
+	}
+	let selected = read("sourceURL");
+	if selected.is_empty() {
+		String::from(DEFAULT_URL)
+	} else {
+		selected
+	}
+}
+
+/// Whether the GraphQL API of the v3 site should be tried first.
+pub fn use_v3_api() -> bool {
+	defaults_get("useV3API")
+		.and_then(|v| v.as_bool())
+		.unwrap_or(true)
+}
+
+/// Quotes a string as a JSON string literal.
+pub fn json_string(string: &str) -> String {
+	let mut result = String::with_capacity(string.len() + 2);
+	result.push('"');
+	for c in string.chars() {
+		match c {
+			'"' => result.push_str("\\\""),
+			'\\' => result.push_str("\\\\"),
+			'\n' => result.push_str("\\n"),
+			'\r' => result.push_str("\\r"),
+			'\t' => result.push_str("\\t"),
+			c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+			c => result.push(c),
+		}
+	}
+	result.push('"');
+	result
+}
+
+/// The numeric part of a manga id, which is `<number>/<slug>` for titles from
+/// the classic site.
+pub fn series_number(id: &str) -> &str {
+	id.split(['/', '-']).next().unwrap_or(id)
+}
+
+/// Turns the path of a title page into a manga id, for both the classic
+/// (`/series/72873/slug`) and the v3 (`/title/72873-slug`) layout.
+pub fn manga_id_from_path(path: &str) -> Option<String> {
+	let path = path.split(['?', '#']).next().unwrap_or_default();
+	let mut segments = path.split('/').filter(|s| !s.is_empty());
+	while let Some(segment) = segments.next() {
+		match segment {
+			"series" => {
+				let number = segments.next()?;
+				return Some(match segments.next() {
+					Some(slug) => format!("{number}/{slug}"),
+					None => String::from(number),
+				});
+			}
+			"title" => {
+				let title = segments.next()?;
+				return Some(match title.split_once('-') {
+					Some((number, slug)) => format!("{number}/{slug}"),
+					None => String::from(title),
+				});
+			}
+			_ => continue,
+		}
+	}
+	None
+}
+
+/// Finds the chapter id in a chapter url of either layout: `/chapter/1234567`
+/// or `/title/72873-slug/1234567-ch_1`.
+pub fn chapter_id_from_path(path: &str) -> Option<String> {
+	let path = path.split(['?', '#']).next().unwrap_or_default();
+	let segments = path
+		.split('/')
+		.filter(|s| !s.is_empty())
+		.collect::<Vec<&str>>();
+	let pos = segments
+		.iter()
+		.position(|s| *s == "chapter" || *s == "title")?;
+	let chapter = match segments[pos] {
+		"chapter" => segments.get(pos + 1)?,
+		_ => segments.get(pos + 2)?,
+	};
+	let number = chapter.split('-').next().unwrap_or_default();
+	if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+	Some(String::from(number))
+}
 
 pub fn i32_to_string(mut integer: i32) -> String {
 	if integer == 0 {
@@ -1,16 +1,40 @@
 #![no_std]
 
 use aidoku::{
-	error::Result, prelude::*, std::defaults::defaults_get, std::net::HttpMethod,
-	std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter, Listing, Manga,
-	MangaPageResult, Page,
+	error::{AidokuError, AidokuErrorKind, Result},
+	prelude::*,
+	std::net::HttpMethod,
+	std::net::Request,
+	std::String,
+	std::Vec,
+	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaPageResult, Page,
 };
 
+use helper::{chapter_id_from_path, get_base_url, manga_id_from_path, use_v3_api};
+
+mod api;
 mod helper;
 mod parser;
 
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	if use_v3_api() {
+		let mut word = String::new();
+		for filter in filters.iter() {
+			if let (FilterType::Title, Ok(value)) = (&filter.kind, filter.value.as_string()) {
+				word = value.read();
+			}
+		}
+		let sort = match parser::get_sort(&filters) {
+			Some((_, sort)) => sort,
+			None if word.is_empty() => "field_name",
+			None => "",
+		};
+		if let Ok(result) = api::search(&word, sort, page) {
+			return Ok(result);
+		}
+	}
+
 	let mut result: Vec<Manga> = Vec::new();
 
 	let (url, search) = parser::get_filtered_url(filters, page);
@@ -30,23 +54,25 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 
 #[get_manga_listing]
 fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
-	let mut url = String::new();
-	if let Ok(url_str) = defaults_get("sourceURL")
-		.expect("missing sourceURL")
-		.as_string()
-	{
-		url.push_str(url_str.read().as_str());
+	let (sort_type, v3_sort) = match listing.name.as_str() {
+		"Popular" => ("views_a.za", "views_d000"),
+		"Latest" => ("update.za", "field_upload"),
+		"New Titles" => ("create.za", "field_public"),
+		_ => {
+			return Err(AidokuError {
+				reason: AidokuErrorKind::Unimplemented,
+			})
+		}
+	};
+	if use_v3_api() {
+		if let Ok(result) = api::search("", v3_sort, page) {
+			return Ok(result);
+		}
 	}
+
+	let mut url = get_base_url();
 	let mut result: Vec<Manga> = Vec::new();
-	if listing.name == "Popular" {
-		parser::get_list_url(&mut url, "views_a.za", page);
-	}
-	if listing.name == "Latest" {
-		parser::get_list_url(&mut url, "update.za", page);
-	}
-	if listing.name == "New Titles" {
-		parser::get_list_url(&mut url, "create.za", page);
-	}
+	parser::get_list_url(&mut url, sort_type, page);
 	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
 	parser::parse_listing(&html, &mut result);
 
@@ -59,55 +85,63 @@ fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
 
 #[get_manga_details]
 fn get_manga_details(manga_id: String) -> Result<Manga> {
-	let mut url = String::new();
-	if let Ok(url_str) = defaults_get("sourceURL")
-		.expect("missing sourceURL")
-		.as_string()
-	{
-		url.push_str(url_str.read().as_str());
-		url.push_str("/series/");
-		url.push_str(&manga_id);
+	if use_v3_api() {
+		if let Ok(manga) = api::get_manga_details(manga_id.clone()) {
+			return Ok(manga);
+		}
 	}
+	let url = format!("{}/series/{}", get_base_url(), manga_id);
 	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
 	parser::parse_manga(html, manga_id)
 }
 
 #[get_chapter_list]
 fn get_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
-	let mut url = String::new();
-	if let Ok(url_str) = defaults_get("sourceURL")
-		.expect("missing sourceURL")
-		.as_string()
-	{
-		url.push_str(url_str.read().as_str());
-		url.push_str("/series/");
-		url.push_str(&manga_id);
+	if use_v3_api() {
+		if let Ok(chapters) = api::get_chapter_list(manga_id.clone()) {
+			if !chapters.is_empty() {
+				return Ok(chapters);
+			}
+		}
 	}
+	let url = format!("{}/series/{}", get_base_url(), manga_id);
 	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
 	parser::get_chapter_list(html)
 }
 
 #[get_page_list]
 fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
-	let mut url = String::new();
-	if let Ok(url_str) = defaults_get("sourceURL")
-		.expect("missing sourceURL")
-		.as_string()
-	{
-		url.push_str(url_str.read().as_str());
-		url.push_str("/chapter/");
-		url.push_str(&chapter_id);
+	if use_v3_api() {
+		if let Ok(pages) = api::get_page_list(chapter_id.clone()) {
+			return Ok(pages);
+		}
 	}
+	let url = format!("{}/chapter/{}", get_base_url(), chapter_id);
 	let html = Request::new(url.as_str(), HttpMethod::Get).html()?;
 	parser::get_page_list(html)
 }
 
 #[handle_url]
 pub fn handle_url(url: String) -> Result<DeepLink> {
-	let parsed_manga_id = parser::parse_incoming_url(url);
+	let chapter_id = chapter_id_from_path(&url);
+	let manga_id = match manga_id_from_path(&url) {
+		Some(id) => id,
+		// classic chapter links don't name the title
+		None => match &chapter_id {
+			Some(chapter_id) => api::get_chapter_manga_id(chapter_id)?,
+			None => {
+				return Err(AidokuError {
+					reason: AidokuErrorKind::Unimplemented,
+				})
+			}
+		},
+	};
 
 	Ok(DeepLink {
-		manga: Some(get_manga_details(parsed_manga_id)?),
-		chapter: None,
+		manga: Some(get_manga_details(manga_id)?),
+		chapter: chapter_id.map(|id| Chapter {
+			id,
+			..Default::default()
+		}),
 	})
 }
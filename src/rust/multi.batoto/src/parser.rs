@@ -6,7 +6,7 @@ use aidoku::{
 	Chapter, Filter, FilterType, Manga, MangaContentRating, MangaStatus, MangaViewer, Page,
 };
 
-use crate::helper::{i32_to_string, lang_encoder, urlencode};
+use crate::helper::{get_base_url, i32_to_string, lang_encoder, urlencode};
 use chapter_recognition::{parse_chapter_number, parse_volume_number};
 extern crate alloc;
 
@@ -100,15 +100,7 @@ pub fn parse_manga(obj: Node, id: String) -> Result<Manga> {
 		viewer = MangaViewer::Scroll;
 	}
 
-	let mut url = String::new();
-	if let Ok(url_str) = defaults_get("sourceURL")
-		.expect("missing sourceURL")
-		.as_string()
-	{
-		url.push_str(url_str.read().as_str());
-		url.push_str("/series/");
-		url.push_str(&id);
-	}
+	let url = format!("{}/series/{}", get_base_url(), id);
 
 	let status = if status_str.contains("Ongoing") {
 		MangaStatus::Ongoing
@@ -195,25 +187,18 @@ pub fn get_chapter_list(obj: Node) -> Result<Vec<Chapter>> {
 		}
 
 		// Url
-		if let Ok(url_str) = defaults_get("sourceURL")
-			.expect("missing sourceURL")
-			.as_string()
-		{
-			let mut url = url_str.read();
-			url.push_str("/chapter/");
-			url.push_str(&id);
-
-			chapters.push(Chapter {
-				id,
-				title,
-				volume,
-				chapter,
-				date_updated,
-				scanlator,
-				url,
-				lang,
-			});
-		}
+		let url = format!("{}/chapter/{}", get_base_url(), id);
+
+		chapters.push(Chapter {
+			id,
+			title,
+			volume,
+			chapter,
+			date_updated,
+			scanlator,
+			url,
+			lang,
+		});
 	}
 	Ok(chapters)
 }
@@ -228,14 +213,12 @@ pub fn get_page_list(obj: Node) -> Result<Vec<Page>> {
 			continue;
 		}
 
-		let img_str;
-		match script_text.substring_after_last("const imgHttps = [\"") {
-			Some(v) => match v.substring_before("\"];") {
-				Some(w) => img_str = w,
-				None => panic!(),
-			},
-			None => panic!(),
-		}
+		let Some(img_str) = script_text
+			.substring_after_last("const imgHttps = [\"")
+			.and_then(|v| v.substring_before("\"];"))
+		else {
+			continue;
+		};
 		let img_arr = img_str.split("\",\"").collect::<Vec<&str>>();
 
 		for (index, item) in img_arr.iter().enumerate() {
@@ -251,16 +234,36 @@ pub fn get_page_list(obj: Node) -> Result<Vec<Page>> {
 	Ok(pages)
 }
 
+/// The browse sorts after "Relevance" in the Sort filter, as their v2 and v3
+/// values.
+const SORTS: [(&str, &str); 9] = [
+	("title.az", "field_name"),
+	("update.za", "field_upload"),
+	("create.za", "field_public"),
+	("views_a.za", "views_d000"),
+	("views_y.za", "views_d360"),
+	("views_m.za", "views_d030"),
+	("views_w.za", "views_d007"),
+	("views_d.za", "views_h024"),
+	("views_h.za", "views_h001"),
+];
+
+/// The `(v2, v3)` sort chosen in the filters, `None` for relevance.
+pub fn get_sort(filters: &[Filter]) -> Option<(&'static str, &'static str)> {
+	let filter = filters
+		.iter()
+		.find(|filter| matches!(filter.kind, FilterType::Sort))?;
+	let index = filter.value.as_object().ok()?.get("index").as_int().ok()?;
+	SORTS
+		.get(usize::try_from(index).ok()?.checked_sub(1)?)
+		.copied()
+}
+
 pub fn get_filtered_url(filters: Vec<Filter>, page: i32) -> (String, bool) {
-	let mut url = String::new();
+	let mut url = get_base_url();
 	let mut search = false;
+	let sort = get_sort(&filters);
 
-	if let Ok(url_str) = defaults_get("sourceURL")
-		.expect("missing sourceURL")
-		.as_string()
-	{
-		url.push_str(url_str.read().as_str());
-	}
 	for filter in filters {
 		match filter.kind {
 			FilterType::Title => {
@@ -277,7 +280,8 @@ pub fn get_filtered_url(filters: Vec<Filter>, page: i32) -> (String, bool) {
 		}
 	}
 	if !search {
-		get_list_url(&mut url, "title.az", page);
+		let sort_type = sort.map_or("title.az", |(sort_type, _)| sort_type);
+		get_list_url(&mut url, sort_type, page);
 	}
 	(url, search)
 }
@@ -300,23 +304,6 @@ pub fn get_list_url(url: &mut String, sort_type: &str, page: i32) {
 	url.push_str(&i32_to_string(page));
 }
 
-pub fn parse_incoming_url(url: String) -> String {
-	//bato.to/series/72873/who-made-me-a-princess-official
-
-	let split = url.as_str().split('/');
-	let vec = split.collect::<Vec<&str>>();
-	let mut manga_id = String::new();
-
-	if url.contains("/chapters/") {
-	} else {
-		manga_id.push_str(vec[vec.len() - 2]);
-		manga_id.push('/');
-		manga_id.push_str(vec[vec.len() - 1]);
-	}
-
-	manga_id
-}
-
 pub fn is_last_page(html: Node) -> bool {
 	// how does this work
 	// return html.select(".page-item").last().has_class("disabled");
@@ -101,19 +101,6 @@ pub fn id_to_status(id: i64) -> MangaStatus {
 	}
 }
 
-pub fn extract_f32_from_string(title: String, text: String) -> Vec<f32> {
-	text.replace(&title, "")
-		.chars()
-		.filter(|a| (*a >= '0' && *a <= '9') || *a == ' ' || *a == '.' || *a == '+')
-		.collect::<String>()
-		.split(' ')
-		.collect::<Vec<&str>>()
-		.into_iter()
-		.map(|a| a.parse::<f32>().unwrap_or(-1.0))
-		.filter(|a| *a >= 0.0)
-		.collect::<Vec<f32>>()
-}
-
 pub fn display_title() -> String {
 	if defaults_get("display_in_eng")
 		.and_then(|value| value.as_bool())
@@ -7,8 +7,9 @@ use aidoku::{Chapter, MangaContentRating, MangaViewer, Page};
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
-use crate::helpers::{display_title, extract_f32_from_string, id_to_status};
+use crate::helpers::{display_title, id_to_status};
 use crate::template::CDN;
+use common::chapter::parse_chapter;
 extern crate alloc;
 
 pub fn parse_manga_list(
@@ -175,32 +176,21 @@ pub fn parse_chapter_list(js: ObjectRef, id: &str, domain: &str) -> Result<Vec<C
 			// chapter_id: 1#1
 			// Scheme: number#volume
 
+			let number =
+				parse_chapter(&chapter_object.get("number").as_string()?.read(), "").chapter;
+			let volume =
+				parse_chapter(&chapter_object.get("volume").as_string()?.read(), "").chapter;
+
 			Ok(Chapter {
-				id: format!(
-					"{}#{}",
-					extract_f32_from_string(
-						String::new(),
-						chapter_object.get("number").as_string()?.read()
-					)[0],
-					extract_f32_from_string(
-						String::new(),
-						chapter_object.get("volume").as_string()?.read()
-					)[0]
-				),
+				id: format!("{}#{}", number, volume),
 
 				title: chapter_object
 					.get("name")
 					.as_string()
 					.unwrap_or("".into())
 					.to_string(),
-				volume: extract_f32_from_string(
-					String::new(),
-					chapter_object.get("volume").as_string()?.read(),
-				)[0],
-				chapter: extract_f32_from_string(
-					String::new(),
-					chapter_object.get("number").as_string()?.read(),
-				)[0],
+				volume,
+				chapter: number,
 				date_updated: chapter_object
 					.get("branches")
 					.as_array()?
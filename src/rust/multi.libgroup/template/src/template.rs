@@ -11,6 +11,7 @@ use aidoku::{
 	Chapter, Filter, Listing, Manga, MangaContentRating, MangaPageResult, Page,
 };
 use alloc::string::ToString;
+use common::net::CheckedRequest;
 extern crate alloc;
 
 use crate::{
@@ -56,7 +57,7 @@ impl SocialLibSource {
 				.header("Authorization", auth.as_str())
 				.header("Referer", domain.as_str())
 				.body(body);
-			let json = request.json()?.as_object()?;
+			let json = request.checked_json()?.as_object()?;
 
 			save_token(json);
 			return Ok(());
@@ -89,7 +90,7 @@ impl SocialLibSource {
 		let url = format!("{}manga?{}", DOMAIN_API, query);
 		let request = self.request_get(&url);
 
-		let json = request.json()?.as_object()?;
+		let json = request.checked_json()?.as_object()?;
 
 		parser::parse_manga_list(json, &self.domain.to_string(), self.nsfw)
 	}
@@ -104,7 +105,7 @@ impl SocialLibSource {
 
 			let url = format!("{}media/top-views?{}", DOMAIN_API, query);
 			let request = self.request_get(&url);
-			let json = request.json()?.as_object()?;
+			let json = request.checked_json()?.as_object()?;
 
 			parser::parse_manga_list(json, &self.domain.to_string(), self.nsfw)
 		} else {
@@ -125,7 +126,7 @@ impl SocialLibSource {
 		query.push("fields[]", Some("artists"));
 		let url = format!("{}manga/{}?{}", DOMAIN_API, id, query.to_string());
 		let request = self.request_get(&url);
-		let json = request.json()?.as_object()?;
+		let json = request.checked_json()?.as_object()?;
 
 		parser::parse_manga_details(json, self.domain, self.nsfw)
 	}
@@ -134,12 +135,18 @@ impl SocialLibSource {
 		let url = format!("{}manga/{}/chapters", DOMAIN_API, id);
 
 		let request = self.request_get(&url);
-		let json = request.json()?.as_object()?;
+		let json = request.checked_json()?.as_object()?;
 
 		parser::parse_chapter_list(json, &id, self.domain)
 	}
 
+	/// The pages of a chapter, with a failure the user can act on shown as a
+	/// text page, see [`common::error::pages_or_notice`].
 	pub fn get_page_list(&self, manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
+		common::error::pages_or_notice(self.fetch_page_list(manga_id, chapter_id))
+	}
+
+	fn fetch_page_list(&self, manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 		let numbers: Vec<&str> = chapter_id.split('#').collect::<Vec<&str>>();
 
 		let url = format!(
@@ -150,7 +157,7 @@ impl SocialLibSource {
 			numbers.get(1).unwrap()
 		);
 		let request = self.request_get(&url);
-		let json = request.json()?.as_object()?;
+		let json = request.checked_json()?.as_object()?;
 		let cdn = self.get_cdn_domains()?;
 
 		parser::parse_page_list(json, &cdn)
@@ -165,7 +172,7 @@ impl SocialLibSource {
 		let request = Request::get(url)
 			.header("Site-Id", self.site_id)
 			.header("User-Agent", USER_AGENT);
-		let json = request.json()?.as_object()?;
+		let json = request.checked_json()?.as_object()?;
 
 		let site_id = self.site_id.parse::<i64>().map_err(|_| AidokuError {
 			reason: AidokuErrorKind::ValueCast(aidoku::error::ValueCastError::NotInt),
@@ -1,12 +1,53 @@
 use aidoku::{
 	error::Result,
-	std::{ArrayRef, String, Vec},
+	prelude::*,
+	std::{
+		defaults::defaults_get,
+		net::{HttpMethod, Request},
+		ArrayRef, String, ValueRef, Vec,
+	},
 };
+use alloc::string::ToString;
+
+pub const DEFAULT_BASE_URL: &str = "https://nhentai.net";
+const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) GSA/300.0.598994205 Mobile/15E148 Safari/604";
+
+pub fn get_base_url() -> String {
+	defaults_get("baseURL")
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.map(|v| v.trim().trim_end_matches('/').to_string())
+		.ok()
+		.filter(|v| !v.is_empty())
+		.unwrap_or_else(|| String::from(DEFAULT_BASE_URL))
+}
+
+/// Whether listings and details should be fetched from the JSON API instead of
+/// the HTML front-end, which is often hidden behind a challenge page.
+pub fn use_api() -> bool {
+	defaults_get("useApi")
+		.and_then(|v| v.as_bool())
+		.unwrap_or(false)
+}
+
+pub fn get_cookie() -> String {
+	defaults_get("cookie")
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.map(|v| v.trim().to_string())
+		.unwrap_or_default()
+}
+
+pub fn request<T: AsRef<str>>(url: T) -> Request {
+	let request = Request::new(url, HttpMethod::Get).header("User-Agent", USER_AGENT);
+	let cookie = get_cookie();
+	if cookie.is_empty() {
+		request
+	} else {
+		request.header("Cookie", &cookie)
+	}
+}
 
 pub fn get_details_url(id: String) -> String {
-	let mut string = String::from("https://nhentai.net/api/gallery/");
-	string.push_str(&id);
-	string
+	format!("{}/api/gallery/{id}", get_base_url())
 }
 
 pub fn get_file_type(filetype: String) -> String {
@@ -33,10 +74,61 @@ pub fn get_tag_names_by_type(tags: ArrayRef, tag_type: &str) -> Result<Vec<Strin
 	Ok(names)
 }
 
+/// Gallery ids are numbers in the API, but some mirrors serialize them as
+/// strings.
+pub fn value_to_id(value: ValueRef) -> Result<String> {
+	match value.as_int() {
+		Ok(id) => Ok(id.to_string()),
+		Err(_) => Ok(value.as_string()?.read()),
+	}
+}
+
 pub fn is_number(s: &str) -> bool {
 	s.chars().all(|c| c.is_numeric())
 }
 
+/// Appends `namespace:"value"` terms for a comma separated filter value.
+/// Values prefixed with `-` are excluded instead.
+pub fn push_namespaced(query: &mut String, namespace: &str, value: &str) {
+	for term in value.split(',').map(|term| term.trim()) {
+		let (exclude, term) = match term.strip_prefix('-') {
+			Some(term) => (true, term.trim()),
+			None => (false, term),
+		};
+		if term.is_empty() {
+			continue;
+		}
+		query.push(' ');
+		if exclude {
+			query.push('-');
+		}
+		query.push_str(namespace);
+		query.push_str(":\"");
+		query.push_str(term);
+		query.push('"');
+	}
+}
+
+/// Appends a `pages:>=N` style term if `value` is a number.
+pub fn push_page_bound(query: &mut String, operator: &str, value: &str) {
+	if let Ok(pages) = value.trim().parse::<u32>() {
+		query.push_str(&format!(" pages:{operator}{pages}"));
+	}
+}
+
+/// Appends an `uploaded:<7d` style term if `value` is a relative duration
+/// understood by the site (`h`, `d`, `w`, `m` or `y`).
+pub fn push_upload_bound(query: &mut String, operator: &str, value: &str) {
+	let value = value.trim();
+	let Some(unit) = value.chars().last() else {
+		return;
+	};
+	let amount = &value[..value.len() - unit.len_utf8()];
+	if matches!(unit, 'h' | 'd' | 'w' | 'm' | 'y') && !amount.is_empty() && is_number(amount) {
+		query.push_str(&format!(" uploaded:{operator}{value}"));
+	}
+}
+
 pub fn find_media_server(big_string: &str) -> Option<&str> {
 	let begin_pattern = r#"window._n_app"#; // finds the script block that contains the media server
 	let start_pattern = r#"media_server: "#;
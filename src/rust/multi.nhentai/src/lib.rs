@@ -1,17 +1,14 @@
 #![no_std]
 use aidoku::{
 	error::Result, helpers::uri::encode_uri_component, prelude::*, std::defaults::defaults_get,
-	std::net::HttpMethod, std::net::Request, std::ObjectRef, std::String, std::Vec, Chapter,
-	DeepLink, Filter, FilterType, Listing, Manga, MangaContentRating, MangaPageResult, MangaStatus,
-	MangaViewer, Page,
+	std::ObjectRef, std::String, std::Vec, Chapter, DeepLink, Filter, FilterType, Listing, Manga,
+	MangaContentRating, MangaPageResult, MangaStatus, MangaViewer, Page,
 };
 extern crate alloc;
 use alloc::{string::ToString, vec};
 
 mod helper;
-
-const BASE_URL: &str = "https://nhentai.net";
-const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) GSA/300.0.598994205 Mobile/15E148 Safari/604";
+mod parser;
 
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
@@ -51,14 +48,34 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 					query.push_str(&title);
 				}
 			}
+			FilterType::Author => {
+				helper::push_namespaced(&mut query, "artist", &filter.value.as_string()?.read());
+			}
+			FilterType::Text => {
+				let value = filter.value.as_string()?.read();
+				match filter.name.as_str() {
+					"Groups" => helper::push_namespaced(&mut query, "group", &value),
+					"Parodies" => helper::push_namespaced(&mut query, "parody", &value),
+					"Characters" => helper::push_namespaced(&mut query, "character", &value),
+					"Minimum pages" => helper::push_page_bound(&mut query, ">=", &value),
+					"Maximum pages" => helper::push_page_bound(&mut query, "<=", &value),
+					"Uploaded within" => helper::push_upload_bound(&mut query, "<", &value),
+					"Uploaded before" => helper::push_upload_bound(&mut query, ">", &value),
+					_ => continue,
+				}
+			}
 			FilterType::Genre => {
+				// categories carry their full `category:<name>` term as the id
+				let term = match filter.object.get("id").as_string() {
+					Ok(id) => id.read(),
+					Err(_) => format!("tag:\"{}\"", filter.name),
+				};
 				match filter.value.as_int().unwrap_or(-1) {
-					0 => query.push_str(" -tag:\""),
-					1 => query.push_str(" tag:\""),
+					0 => query.push_str(" -"),
+					1 => query.push(' '),
 					_ => continue,
 				}
-				query.push_str(&filter.name);
-				query.push('\"');
+				query.push_str(&term);
 			}
 			FilterType::Sort => {
 				let value = match filter.value.as_object() {
@@ -89,40 +106,23 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 		});
 	}
 
+	let base_url = helper::get_base_url();
+
+	if helper::use_api() {
+		let url = format!(
+			"{base_url}/api/galleries/search?query={}&page={page}&sort={sort}",
+			encode_uri_component(query),
+		);
+		let json = helper::request(&url).json()?.as_object()?;
+		return parser::parse_gallery_search(json, page);
+	}
+
 	let url = format!(
-		"{BASE_URL}/search/?q={}&page={page}&sort={sort}",
+		"{base_url}/search/?q={}&page={page}&sort={sort}",
 		encode_uri_component(query),
 	);
 
-	let html = Request::new(&url, HttpMethod::Get)
-		.header("User-Agent", USER_AGENT)
-		.html()?;
-
-	let mut manga: Vec<Manga> = Vec::new();
-
-	for node in html
-		.select("#content .container:not(.index-popular) .gallery")
-		.array()
-	{
-		let node = node.as_node().expect("node array");
-
-		let rel_link = node.select("a").first().attr("href").read(); // /g/id/
-
-		let id = rel_link[3..rel_link.len() - 1].to_string();
-
-		let cover = node.select("img").first().attr("data-src").read();
-
-		let title = node.select(".caption").first().text().read();
-
-		manga.push(Manga {
-			id,
-			cover: format!("https:{}", cover),
-			title,
-			nsfw: MangaContentRating::Nsfw,
-			..Default::default()
-		});
-	}
-
+	let manga = parser::parse_gallery_list(helper::request(&url).html()?);
 	let has_more = !manga.is_empty();
 
 	Ok(MangaPageResult { manga, has_more })
@@ -130,6 +130,10 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 
 #[get_manga_listing]
 fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	if listing.name == "Favorites" {
+		return get_favorites(page);
+	}
+
 	let mut filters: Vec<Filter> = Vec::new();
 	let mut selection = ObjectRef::new();
 
@@ -155,12 +159,34 @@ fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
 	get_manga_list(filters, page)
 }
 
+/// Favorites are only available on the HTML front-end and need the session
+/// cookie of a logged in account, without it the listing fails.
+fn get_favorites(page: i32) -> Result<MangaPageResult> {
+	if helper::get_cookie().is_empty() {
+		return Err(aidoku::error::AidokuError {
+			reason: aidoku::error::AidokuErrorKind::DefaultNotFound,
+		});
+	}
+
+	let url = format!("{}/favorites/?page={page}", helper::get_base_url());
+	let html = helper::request(&url).html()?;
+	let has_more = !html.select("section.pagination a.next").array().is_empty();
+	let manga = parser::parse_gallery_list(html);
+
+	Ok(MangaPageResult { manga, has_more })
+}
+
 #[get_manga_details]
 fn get_manga_details(id: String) -> Result<Manga> {
-	let url = format!("{BASE_URL}/g/{id}/");
-	let html = Request::new(&url, HttpMethod::Get)
-		.header("User-Agent", USER_AGENT)
-		.html()?;
+	if helper::use_api() {
+		let json = helper::request(helper::get_details_url(id))
+			.json()?
+			.as_object()?;
+		return parser::parse_gallery(json);
+	}
+
+	let url = format!("{}/g/{id}/", helper::get_base_url());
+	let html = helper::request(&url).html()?;
 
 	let cover = html.select("#cover img").first().attr("data-src").read();
 
@@ -213,12 +239,11 @@ fn get_manga_details(id: String) -> Result<Manga> {
 
 #[get_chapter_list]
 fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
-	let json = Request::new(helper::get_details_url(id.clone()), HttpMethod::Get)
-		.header("User-Agent", USER_AGENT)
+	let json = helper::request(helper::get_details_url(id.clone()))
 		.json()?
 		.as_object()?;
 
-	let url = format!("{BASE_URL}/g/{id}/");
+	let url = format!("{}/g/{id}/", helper::get_base_url());
 
 	let date_updated = json.get("upload_date").as_float().unwrap_or(0.0);
 
@@ -244,25 +269,28 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 
 #[get_page_list]
 fn get_page_list(_: String, id: String) -> Result<Vec<Page>> {
-	let html = Request::new(format!("{BASE_URL}/g/{id}/"), HttpMethod::Get)
-		.header("User-Agent", USER_AGENT)
-		.string()?;
+	// the media server is only exposed by the reader page, which the API mode
+	// avoids; the default image host serves every gallery as well
+	let html = if helper::use_api() {
+		String::new()
+	} else {
+		helper::request(format!("{}/g/{id}/", helper::get_base_url())).string()?
+	};
 	let media_server = helper::find_media_server(&html).unwrap_or("");
 
-	let json = Request::new(helper::get_details_url(id).as_str(), HttpMethod::Get)
-		.header("User-Agent", USER_AGENT)
+	let json = helper::request(helper::get_details_url(id))
 		.json()?
 		.as_object()?;
 
 	let images = json.get("images").as_object()?;
 	let pages_arr = images.get("pages").as_array()?;
 
+	let media_id = helper::value_to_id(json.get("media_id"))?;
 	let mut pages = Vec::new();
 
 	for (i, page) in pages_arr.enumerate() {
 		let page_obj = page.as_object()?;
 
-		let media_id = json.get("media_id").as_string()?.read();
 		let file_type = helper::get_file_type(page_obj.get("t").as_string()?.read());
 
 		let url = format!(
@@ -282,9 +310,11 @@ fn get_page_list(_: String, id: String) -> Result<Vec<Page>> {
 
 #[handle_url]
 pub fn handle_url(url: String) -> Result<DeepLink> {
-	let url = &url[BASE_URL.len()..]; // remove "https://nhentai.net"
+	// mirrors share the path layout, so only look at what follows the host
+	let path = url.split_once("://").map(|(_, rest)| rest).unwrap_or(&url);
+	let path = &path[path.find('/').unwrap_or(path.len())..];
 
-	if let Some(id) = url.strip_prefix("/g/") {
+	if let Some(id) = path.strip_prefix("/g/") {
 		let end = match id.find('/') {
 			Some(end) => end,
 			None => id.len(),
@@ -0,0 +1,110 @@
+use aidoku::{
+	error::Result,
+	prelude::*,
+	std::{html::Node, ObjectRef, String, Vec},
+	Manga, MangaContentRating, MangaPageResult, MangaStatus, MangaViewer,
+};
+use alloc::string::ToString;
+
+use crate::helper;
+
+/// Parses the gallery grid shared by the search, listing and favorites pages.
+pub fn parse_gallery_list(html: Node) -> Vec<Manga> {
+	let mut manga: Vec<Manga> = Vec::new();
+
+	for node in html
+		.select("#content .container:not(.index-popular) .gallery")
+		.array()
+	{
+		let Ok(node) = node.as_node() else {
+			continue;
+		};
+
+		let rel_link = node.select("a").first().attr("href").read(); // /g/id/
+
+		let id = rel_link
+			.trim_start_matches("/g/")
+			.trim_end_matches('/')
+			.to_string();
+		if id.is_empty() {
+			continue;
+		}
+
+		let cover = node.select("img").first().attr("data-src").read();
+
+		let title = node.select(".caption").first().text().read();
+
+		manga.push(Manga {
+			id,
+			cover: if cover.starts_with("//") {
+				format!("https:{}", cover)
+			} else {
+				cover
+			},
+			title,
+			nsfw: MangaContentRating::Nsfw,
+			..Default::default()
+		});
+	}
+
+	manga
+}
+
+/// Parses a `/api/galleries/search` (or `/api/galleries/all`) response.
+pub fn parse_gallery_search(json: ObjectRef, page: i32) -> Result<MangaPageResult> {
+	let num_pages = json.get("num_pages").as_int().unwrap_or(0);
+
+	let mut manga: Vec<Manga> = Vec::new();
+	for gallery in json.get("result").as_array()? {
+		manga.push(parse_gallery(gallery.as_object()?)?);
+	}
+
+	let has_more = i64::from(page) < num_pages;
+
+	Ok(MangaPageResult { manga, has_more })
+}
+
+/// Parses a gallery object returned by the API.
+pub fn parse_gallery(gallery: ObjectRef) -> Result<Manga> {
+	let id = helper::value_to_id(gallery.get("id"))?;
+	let media_id = helper::value_to_id(gallery.get("media_id"))?;
+
+	let titles = gallery.get("title").as_object()?;
+	let title = titles
+		.get("pretty")
+		.as_string()
+		.or_else(|_| titles.get("english").as_string())
+		.map(|v| v.read())
+		.unwrap_or_default();
+
+	let cover_type = gallery
+		.get("images")
+		.as_object()
+		.and_then(|images| images.get("cover").as_object())
+		.and_then(|cover| cover.get("t").as_string())
+		.map(|t| helper::get_file_type(t.read()))
+		.unwrap_or_else(|_| String::from("jpg"));
+	let cover = format!("https://t.nhentai.net/galleries/{media_id}/cover.{cover_type}");
+
+	let author =
+		helper::get_tag_names_by_type(gallery.get("tags").as_array()?, "artist")?.join(", ");
+	let artist = author.clone();
+	let categories = helper::get_tag_names_by_type(gallery.get("tags").as_array()?, "tag")?
+		.into_iter()
+		.filter(|tag| !tag.is_empty())
+		.collect();
+
+	Ok(Manga {
+		url: format!("{}/g/{id}/", helper::get_base_url()),
+		description: format!("#{id}"),
+		id,
+		cover,
+		title,
+		author,
+		artist,
+		categories,
+		status: MangaStatus::Completed,
+		nsfw: MangaContentRating::Nsfw,
+		viewer: MangaViewer::Rtl,
+	})
+}
@@ -1,21 +0,0 @@
-use aidoku::std::{String, Vec};
-
-/// Returns an array of f32s contained within a string.
-pub fn extract_f32_from_string(text: String) -> Vec<f32> {
-	let mut last_char_was_digit: bool = false;
-	text.chars()
-		.filter(|a| {
-			if (*a).is_ascii_digit() {
-				last_char_was_digit = true;
-				return true;
-			} else if *a == '.' && last_char_was_digit || *a == '+' || *a == ' ' {
-				last_char_was_digit = false;
-				return true;
-			}
-			false
-		})
-		.collect::<String>()
-		.split(' ')
-		.filter_map(|a| a.parse::<f32>().ok())
-		.collect::<Vec<f32>>()
-}
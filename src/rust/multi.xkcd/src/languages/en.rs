@@ -1,5 +1,4 @@
 use super::THUMBNAIL_URL;
-use crate::helper::extract_f32_from_string;
 use aidoku::{
 	error::Result,
 	prelude::format,
@@ -10,6 +9,7 @@ use aidoku::{
 	Chapter, Manga, MangaContentRating, MangaStatus, MangaViewer, Page,
 };
 use alloc::string::ToString;
+use common::chapter::parse_chapter;
 
 pub fn comic_info() -> Manga {
 	Manga {
@@ -41,7 +41,7 @@ pub fn get_chapter_list() -> Result<Vec<Chapter>> {
 						.0
 						.as_date("yyyy-M-d", None, None)
 						.unwrap_or(-1.0);
-					let chapter = extract_f32_from_string(node.attr("href").read())[0];
+					let chapter = parse_chapter(&node.attr("href").read(), "").chapter;
 					Chapter {
 						id: chapter.to_string(),
 						title: node.text().read(),
@@ -1,5 +1,4 @@
 use super::{word_wrap, ToImageUrl, THUMBNAIL_URL};
-use crate::helper::extract_f32_from_string;
 use aidoku::{
 	error::Result,
 	prelude::*,
@@ -11,6 +10,7 @@ use aidoku::{
 	Chapter, Manga, MangaContentRating, MangaStatus, MangaViewer, Page,
 };
 use alloc::vec;
+use common::chapter::parse_chapter;
 
 pub fn comic_info() -> Manga {
 	Manga {
@@ -49,7 +49,7 @@ pub fn get_chapter_list() -> Result<Vec<Chapter>> {
 						id: chapter.clone(),
 						title: node.text().read(),
 						volume: -1.0,
-						chapter: extract_f32_from_string(chapter)[0],
+						chapter: parse_chapter(&chapter, "").chapter,
 						date_updated: -1.0,
 						scanlator: String::new(),
 						url,
@@ -1,5 +1,4 @@
 use super::{word_wrap, ToImageUrl, THUMBNAIL_URL};
-use crate::helper::extract_f32_from_string;
 use aidoku::{
 	error::Result,
 	prelude::format,
@@ -10,6 +9,7 @@ use aidoku::{
 	Chapter, Manga, MangaContentRating, MangaStatus, MangaViewer, Page,
 };
 use alloc::{string::ToString, vec};
+use common::chapter::parse_chapter;
 
 pub fn comic_info() -> Manga {
 	Manga {
@@ -36,7 +36,7 @@ pub fn get_chapter_list() -> Result<Vec<Chapter>> {
 			elem.as_node()
 				.map(|node| {
 					let url = node.attr("abs:href").read();
-					let chapter = extract_f32_from_string(node.attr("href").read())[0];
+					let chapter = parse_chapter(&node.attr("href").read(), "").chapter;
 					let title = node.select("img").attr("alt").read();
 					Chapter {
 						id: chapter.to_string(),
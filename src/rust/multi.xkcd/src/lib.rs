@@ -1,5 +1,4 @@
 #![no_std]
-mod helper;
 mod languages;
 extern crate alloc;
 use aidoku::{
@@ -7,11 +7,19 @@ use aidoku::{
 	Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, Page,
 };
 use alloc::vec;
-use manga_chan_template::template::{cache_manga_page, MangaChanSource, CACHED_MANGA};
+use manga_chan_template::{
+	helper::ParsedChapter,
+	template::{cache_manga_page, MangaChanSource, CACHED_MANGA},
+};
 
 static INSTANCE: MangaChanSource = MangaChanSource {
 	base_url: "https://hentaichan.live",
-	vol_chap_parser: |_, _| (-1.0, -1.0),
+	vol_chap_parser: |_, title| ParsedChapter {
+		volume: -1.0,
+		chapter: -1.0,
+		chapter_end: None,
+		title: String::from(title.trim()),
+	},
 	author_selector: "div.row:contains(Автор) div.item2 a",
 	custom_new_path: Some("manga"),
 };
@@ -6,20 +6,11 @@ use aidoku::{
 	Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, Page,
 };
 
-use manga_chan_template::{helper::extract_f32_from_string, template::MangaChanSource};
+use manga_chan_template::{helper::parse_chapter, template::MangaChanSource};
 
 static INSTANCE: MangaChanSource = MangaChanSource {
 	base_url: "https://manga-chan.me",
-	vol_chap_parser: |_, title| {
-		let numbers = extract_f32_from_string(String::new(), title.clone());
-		if numbers.len() > 1 && title.contains("Том") {
-			(numbers[0], numbers[1])
-		} else if !numbers.is_empty() {
-			(-1.0, numbers[0])
-		} else {
-			(-1.0, -1.0)
-		}
-	},
+	vol_chap_parser: |_, title| parse_chapter(title, ""),
 	author_selector: "table.mangatitle tr:contains(Автор) span.translation a",
 	custom_new_path: None,
 };
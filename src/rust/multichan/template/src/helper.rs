@@ -1,24 +1,7 @@
 use aidoku::{std::html::Node, std::String, std::Vec, MangaContentRating, MangaViewer};
+use common::classify::Classifier;
 
-pub fn extract_f32_from_string(title: String, text: String) -> Vec<f32> {
-	let mut last_char_was_digit: bool = false;
-	text.replace(&title, "")
-		.chars()
-		.filter(|a| {
-			if (*a).is_ascii_digit() {
-				last_char_was_digit = true;
-				return true;
-			} else if *a == '.' && last_char_was_digit || *a == '+' || *a == ' ' {
-				last_char_was_digit = false;
-				return true;
-			}
-			false
-		})
-		.collect::<String>()
-		.split(' ')
-		.filter_map(|a| a.parse::<f32>().ok())
-		.collect::<Vec<f32>>()
-}
+pub use common::chapter::{parse_chapter, ParsedChapter};
 
 pub fn urlencode(string: String) -> String {
 	let mut result: Vec<u8> = Vec::with_capacity(string.len() * 3);
@@ -56,28 +39,8 @@ pub fn text_with_newlines(node: Node) -> String {
 	}
 }
 
-// TODO: I am probably leaving out a few questionable tags
 pub fn category_parser(categories: &Vec<String>) -> (MangaContentRating, MangaViewer) {
-	let mut nsfw = MangaContentRating::Safe;
-	let mut viewer = MangaViewer::Rtl;
-	for category in categories {
-		match category.as_str() {
-			// "sex" | "18_plus"
-			"секс" | "18_плюс" => nsfw = MangaContentRating::Nsfw,
-			// "erotica"
-			"школа" => {
-				nsfw = match nsfw {
-					MangaContentRating::Nsfw => MangaContentRating::Nsfw,
-					_ => MangaContentRating::Suggestive,
-				}
-			}
-			"Комикс" | "Руманга" => viewer = MangaViewer::Ltr,
-			// "Comic" | "Rumanga" | "Manhwa" | "Manhua"
-			"Манхва" | "Маньхуа" => viewer = MangaViewer::Scroll,
-			_ => continue,
-		}
-	}
-	(nsfw, viewer)
+	Classifier::new("ru").classify(categories, "", "")
 }
 
 // removes the base url (https://example.com) from a url
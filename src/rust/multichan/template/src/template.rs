@@ -11,6 +11,7 @@ use aidoku::{
 };
 
 use crate::helper::*;
+use common::net::CheckedRequest;
 
 pub static mut CACHED_MANGA_URL: Option<String> = None;
 pub static mut CACHED_MANGA: Option<Vec<u8>> = None;
@@ -28,7 +29,7 @@ pub fn cache_manga_page(url: &str) {
 
 pub struct MangaChanSource {
 	pub base_url: &'static str,
-	pub vol_chap_parser: fn(String, String) -> (f32, f32),
+	pub vol_chap_parser: fn(&str, &str) -> ParsedChapter,
 	pub author_selector: &'static str,
 	pub custom_new_path: Option<&'static str>,
 }
@@ -169,7 +170,7 @@ impl MangaChanSource {
 				need_sort_date=if order_by_date_when_search { "&need_sort_date=true" } else { "" },
 			)
 		};
-		let html = Request::new(&url, HttpMethod::Get).html()?;
+		let html = Request::new(&url, HttpMethod::Get).checked_html()?;
 		self.parse_manga_list(html, if !title.is_empty() { 40 } else { 20 })
 	}
 
@@ -179,7 +180,7 @@ impl MangaChanSource {
 				format!("{}/manga/random", self.base_url).as_str(),
 				HttpMethod::Get,
 			)
-			.html()?;
+			.checked_html()?;
 			self.parse_manga_list(html, 10)
 		} else {
 			Err(AidokuError {
@@ -274,24 +275,13 @@ impl MangaChanSource {
 					.0
 					.as_date("yyyy-MM-dd", None, None)
 					.unwrap_or(-1.0);
-				let mut title = chapter_node.select("a").text().read();
-				let (volume, chapter) = (self.vol_chap_parser)(manga_title.clone(), title.clone());
-				if chapter >= 0.0 {
-					let splitter = format!(" {}", chapter);
-					if title.contains(&splitter) {
-						let split = title.splitn(2, &splitter).collect::<Vec<&str>>();
-						title = String::from(split[1]).replacen(
-							|char| char == ':' || char == '-',
-							"",
-							1,
-						);
-					}
-				}
+				let title = chapter_node.select("a").text().read();
+				let parsed = (self.vol_chap_parser)(&manga_title, &title);
 				Chapter {
 					id,
-					title: String::from(title.trim()),
-					volume,
-					chapter,
+					title: parsed.title,
+					volume: parsed.volume,
+					chapter: parsed.chapter,
 					date_updated,
 					scanlator: scanlator.clone(),
 					url,
@@ -301,7 +291,13 @@ impl MangaChanSource {
 			.collect::<Vec<_>>())
 	}
 
+	/// The pages of a chapter, with a failure the user can act on shown as a
+	/// text page, see [`common::error::pages_or_notice`].
 	pub fn get_page_list(&self, id: String) -> Result<Vec<Page>> {
+		common::error::pages_or_notice(self.fetch_page_list(id))
+	}
+
+	fn fetch_page_list(&self, id: String) -> Result<Vec<Page>> {
 		let url = if id.starts_with("http") {
 			// exhentai-dono.me
 			format!("{id}&development_access=true")
@@ -10,6 +10,10 @@ use aidoku::{
 	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
 	MangaViewer, Page,
 };
+use common::{
+	chapter::parse_chapter,
+	net::{self, CheckedRequest},
+};
 
 pub struct MyMangaSource {
 	pub base_url: &'static str,
@@ -45,7 +49,7 @@ fn cache_manga_page(url: &str) {
 	}
 
 	unsafe {
-		CACHED_MANGA = Some(Request::new(url, HttpMethod::Get).data());
+		CACHED_MANGA = Some(net::request(url, HttpMethod::Get).data());
 		CACHED_MANGA_ID = Some(String::from(url));
 	};
 }
@@ -141,7 +145,7 @@ impl MyMangaSource {
 		if !included_tags.is_empty() {
 			url.push_str(format!("&accept_genres={}", included_tags.join(",")).as_str());
 		}
-		let html = Request::new(&url, HttpMethod::Get).html()?;
+		let html = net::request(&url, HttpMethod::Get).checked_html()?;
 		let node = html.select("div.thumb-item-flow.col-6.col-md-2");
 		let elems = node.array();
 		let (manga, has_more) = self.parse_manga_list(elems);
@@ -234,31 +238,15 @@ impl MyMangaSource {
 				)
 			}
 			.unwrap_or(-1.0);
-			let mut title = String::from(decode_html_entities(
-				&chapter_node.select("div.chapter-name").text().read(),
-			));
-			let numbers = extract_f32_from_string(String::new(), String::from(&title));
-			let (volume, chapter) =
-				if numbers.len() > 1 && title.to_ascii_lowercase().contains("vol") {
-					(numbers[0], numbers[1])
-				} else if !numbers.is_empty() {
-					(-1.0, numbers[0])
-				} else {
-					(-1.0, -1.0)
-				};
-			if chapter >= 0.0 {
-				let splitter = format!(" {}", chapter);
-				if title.contains(&splitter) {
-					let split = title.splitn(2, &splitter).collect::<Vec<&str>>();
-					title =
-						String::from(split[1]).replacen(|char| char == ':' || char == '-', "", 1);
-				}
-			}
+			let parsed = parse_chapter(
+				&decode_html_entities(&chapter_node.select("div.chapter-name").text().read()),
+				"",
+			);
 			chapters.push(Chapter {
 				id,
-				title: String::from(title.trim()),
-				volume,
-				chapter,
+				title: parsed.title,
+				volume: parsed.volume,
+				chapter: parsed.chapter,
 				date_updated,
 				scanlator: scanlator.clone(),
 				url,
@@ -268,9 +256,15 @@ impl MyMangaSource {
 		Ok(chapters)
 	}
 
+	/// The pages of a chapter, with a failure the user can act on shown as a
+	/// text page, see [`common::error::pages_or_notice`].
 	pub fn get_page_list(&self, id: String) -> Result<Vec<Page>> {
+		common::error::pages_or_notice(self.fetch_page_list(id))
+	}
+
+	fn fetch_page_list(&self, id: String) -> Result<Vec<Page>> {
 		let url = format!("{}{id}", self.base_url);
-		let html = Request::new(&url, HttpMethod::Get).html()?;
+		let html = net::request(&url, HttpMethod::Get).checked_html()?;
 		let node = html.select("div#chapter-content img");
 		let elems = node.array();
 		let mut pages = Vec::with_capacity(elems.len());
@@ -279,7 +273,7 @@ impl MyMangaSource {
 			let url = node.attr("data-src").read();
 			pages.push(Page {
 				index: idx as i32,
-				url,
+				url: net::relay_image_url(url),
 				base64: String::new(),
 				text: String::new(),
 			})
@@ -1,21 +1,6 @@
-use aidoku::{
-	std::{current_date, defaults::defaults_get, html::Node, String, StringRef, Vec},
-	MangaContentRating, MangaViewer,
-};
+use aidoku::std::{defaults::defaults_get, html::Node, String, Vec};
 use alloc::string::ToString;
-
-pub fn extract_f32_from_string(title: String, text: String) -> f32 {
-	text.replace(&title, "")
-		.chars()
-		.filter(|a| (*a >= '0' && *a <= '9') || *a == ' ' || *a == '.')
-		.collect::<String>()
-		.split(' ')
-		.collect::<Vec<&str>>()
-		.into_iter()
-		.map(|a| a.parse::<f32>().unwrap_or(0.0))
-		.find(|a| *a > 0.0)
-		.unwrap_or(0.0)
-}
+use common::date::DateParser;
 
 pub fn urlencode(string: String) -> String {
 	let mut result: Vec<u8> = Vec::with_capacity(string.len() * 3);
@@ -65,26 +50,6 @@ pub fn text_with_newlines(node: Node) -> String {
 	}
 }
 
-pub fn category_parser(categories: &Vec<String>) -> (MangaContentRating, MangaViewer) {
-	let mut nsfw = MangaContentRating::Safe;
-	let mut viewer = MangaViewer::Rtl;
-	for category in categories {
-		match category.as_str() {
-			"Adult" | "Smut" | "Mature" | "18+" => nsfw = MangaContentRating::Nsfw,
-			"Ecchi" | "16+" => {
-				nsfw = match nsfw {
-					MangaContentRating::Nsfw => MangaContentRating::Nsfw,
-					_ => MangaContentRating::Suggestive,
-				}
-			}
-			"Webtoon" | "Manhwa" | "Manhua" => viewer = MangaViewer::Scroll,
-			"VnComic" => viewer = MangaViewer::Ltr,
-			_ => continue,
-		}
-	}
-	(nsfw, viewer)
-}
-
 pub fn capitalize_first_letter(name: String) -> String {
 	let preprocess = name.chars().collect::<Vec<_>>();
 	let mut ret = String::with_capacity(preprocess.len() * 2);
@@ -104,21 +69,10 @@ pub fn capitalize_first_letter(name: String) -> String {
 }
 
 pub fn convert_time(ago: String) -> f64 {
-	if ago.contains("cách đây") {
-		let multiplier = match ago.split(char::is_whitespace).collect::<Vec<_>>()[3] {
-			"giây" => 1.0,
-			"phút" => 60.0,
-			"tiếng" => 3600.0,
-			"ngày" => 86400.0,
-			_ => return -1.0,
-		};
-		current_date() - (extract_f32_from_string(String::new(), ago) * multiplier) as f64
-	} else {
-		StringRef::from(ago)
-			.0
-			.as_date("dd/MM/yy", Some("en_US"), Some("Asia/Ho_Chi_Minh"))
-			.unwrap_or(-1.0)
-	}
+	DateParser::new(&["dd/MM/yy"], Some("en_US"))
+		.with_timezone("Asia/Ho_Chi_Minh")
+		.parse(&ago)
+		.unwrap_or(-1.0)
 }
 
 pub fn url_replacer(url: String, vi: String) -> String {
@@ -12,6 +12,11 @@ use aidoku::{
 };
 
 use crate::helper::*;
+use common::{
+	chapter::parse_chapter,
+	classify::Classifier,
+	net::{self, CheckedRequest},
+};
 
 static mut CACHED_MANGA_ID: Option<String> = None;
 static mut CACHED_MANGA: Option<Vec<u8>> = None;
@@ -22,7 +27,7 @@ fn cache_manga_page(url: &str) {
 	}
 
 	unsafe {
-		CACHED_MANGA = Some(Request::new(url, HttpMethod::Get).data());
+		CACHED_MANGA = Some(net::request(url, HttpMethod::Get).data());
 		CACHED_MANGA_ID = Some(String::from(url));
 	};
 }
@@ -53,12 +58,11 @@ impl OtakuSanctuarySource {
 			);
 			let comic_variant_node = node
 				.select("div.mdl-card__supporting-text a:matchesOwn(Manga|Manhwa|Manhua|.*Novel)");
-			let viewer = match comic_variant_node.text().read().trim() {
-				"Manhua" | "Manhwa" => MangaViewer::Scroll,
-				"Manga" => MangaViewer::Rtl,
-				"Light Novel" | "Web Novel" => continue,
-				_ => continue,
-			};
+			let variant = comic_variant_node.text().read();
+			if variant.trim().is_empty() || variant.contains("Novel") {
+				continue;
+			}
+			let (_, viewer) = Classifier::new(&get_lang_code()).classify(&[], variant.trim(), "");
 
 			manga.push(Manga {
 				id: id.clone(),
@@ -128,7 +132,7 @@ impl OtakuSanctuarySource {
 		let resp = if !title.is_empty() {
 			let url = format!("{}/Home/Search?search={title}", self.base_url);
 			search_request = true;
-			Request::new(&url, HttpMethod::Get).html()?
+			net::request(&url, HttpMethod::Get).checked_html()?
 		} else {
 			let mut request = format!(
 				"Lang={}&Page={page}&Type=Include&Dir=NewPostedDate",
@@ -137,7 +141,7 @@ impl OtakuSanctuarySource {
 			for (idx, tag) in tags.iter().enumerate() {
 				request.push_str(format!("&FilterCategory[{idx}]={tag}").as_str());
 			}
-			Request::new(
+			net::request(
 				format!("{}/Manga/Newest", self.base_url).as_str(),
 				HttpMethod::Post,
 			)
@@ -146,7 +150,7 @@ impl OtakuSanctuarySource {
 				"Content-Type",
 				"application/x-www-form-urlencoded; charset=UTF-8",
 			)
-			.html()?
+			.checked_html()?
 		};
 		let (manga, has_more) = if search_request {
 			let collections_node = resp.select("div.collection");
@@ -183,22 +187,22 @@ impl OtakuSanctuarySource {
 					"Lang={}&Page={page}&Type=Include&Dir=NewPostedDate",
 					get_lang_code()
 				);
-				let resp = &Request::new(url.as_str(), HttpMethod::Post)
+				let resp = &net::request(url.as_str(), HttpMethod::Post)
 					.body(request.as_bytes())
 					.header(
 						"Content-Type",
 						"application/x-www-form-urlencoded; charset=UTF-8",
 					)
-					.html()?;
+					.checked_html()?;
 				let (manga, has_more) = self.parse_manga_list(resp.select("div.mdl-card").array());
 				Ok(MangaPageResult { manga, has_more })
 			}
 			"Wallpaper" | "Cosplay" => {
-				let resp = &Request::new(
+				let resp = &net::request(
 					format!("{}{}", url, (page - 1) * 18).as_str(),
 					HttpMethod::Get,
 				)
-				.html()?;
+				.checked_html()?;
 				let node = resp.select("div.picture-mason");
 				let elems = node.array();
 				let (manga, has_more) = self.parse_image_list(elems);
@@ -250,21 +254,16 @@ impl OtakuSanctuarySource {
 				"Done" => MangaStatus::Completed,
 				_ => MangaStatus::Unknown,
 			};
-			let viewer = match html
+			let series_type = html
 				.select("tr:contains(Loại Truyện) td")
 				.array()
 				.get(0)
 				.as_node()
 				.expect("node array")
 				.text()
-				.read()
-				.trim()
-			{
-				"Manhua" | "Manhwa" => MangaViewer::Scroll,
-				"VnComic" => MangaViewer::Ltr,
-				_ => MangaViewer::Rtl,
-			};
-			let (mut nsfw, _) = category_parser(&categories);
+				.read();
+			let (mut nsfw, viewer) =
+				Classifier::new(&get_lang_code()).classify(&categories, series_type.trim(), "");
 			if html.select("div.alert:contains(18 tuổi)").array().len() > 0 {
 				nsfw = MangaContentRating::Nsfw;
 			}
@@ -394,8 +393,7 @@ impl OtakuSanctuarySource {
 					let node = cell.as_node().expect("node array");
 					match idx {
 						0 => {
-							chapter.chapter =
-								extract_f32_from_string(String::new(), node.text().read());
+							chapter.chapter = parse_chapter(&node.text().read(), "").chapter;
 						}
 						1 => {
 							let anchor = node.select("a");
@@ -436,13 +434,19 @@ impl OtakuSanctuarySource {
 		}
 	}
 
+	/// The pages of a chapter, with a failure the user can act on shown as a
+	/// text page, see [`common::error::pages_or_notice`].
 	pub fn get_page_list(&self, id: String) -> Result<Vec<Page>> {
+		common::error::pages_or_notice(self.fetch_page_list(id))
+	}
+
+	fn fetch_page_list(&self, id: String) -> Result<Vec<Page>> {
 		if id.contains("chapter") {
-			let resp =
-				Request::new(format!("{}{id}", self.base_url).as_str(), HttpMethod::Get).html()?;
+			let resp = net::request(format!("{}{id}", self.base_url).as_str(), HttpMethod::Get)
+				.checked_html()?;
 			let vi = resp.select("#dataip").attr("value").read();
 			let numeric_id = resp.select("#inpit-c").attr("data-chapter-id").read();
-			let json = Request::new(
+			let json = net::request(
 				format!("{}/Manga/CheckingAlternate", self.base_url).as_str(),
 				HttpMethod::Post,
 			)
@@ -451,11 +455,11 @@ impl OtakuSanctuarySource {
 				"Content-Type",
 				"application/x-www-form-urlencoded; charset=UTF-8",
 			)
-			.json()?;
+			.checked_json()?;
 			let json_object = json.as_object()?;
 			let raw_pages_arr_value = json_object.get("Content");
 			let raw_pages_arr = if raw_pages_arr_value.is_none() {
-				let json = Request::new(
+				let json = net::request(
 					format!("{}/Manga/UpdateView", self.base_url).as_str(),
 					HttpMethod::Post,
 				)
@@ -464,7 +468,7 @@ impl OtakuSanctuarySource {
 					"Content-Type",
 					"application/x-www-form-urlencoded; charset=UTF-8",
 				)
-				.json()?;
+				.checked_json()?;
 				let json_object = json.as_object()?;
 				let raw_pages_arr_value = json_object.get("view");
 				raw_pages_arr_value.as_string()?.read()
@@ -477,23 +481,23 @@ impl OtakuSanctuarySource {
 				let url = url_replacer(page.as_string()?.read(), vi.clone());
 				page_arr.push(Page {
 					index: index as i32,
-					url,
+					url: net::relay_image_url(url),
 					base64: String::new(),
 					text: String::new(),
 				});
 			}
 			Ok(page_arr)
 		} else if id.contains("wallpaper") || id.contains("Cosplay") {
-			let html = Request::new(
+			let html = net::request(
 				format!("{}{}", self.base_url, id.replace("/image", "")).as_str(),
 				HttpMethod::Get,
 			)
-			.html()?;
+			.checked_html()?;
 			let mut page_arr: Vec<Page> = Vec::with_capacity(1);
 			let url = html.select("div#image_content img").attr("src").read();
 			page_arr.push(Page {
 				index: 0,
-				url,
+				url: net::relay_image_url(url),
 				base64: String::new(),
 				text: String::new(),
 			});
@@ -515,7 +519,7 @@ impl OtakuSanctuarySource {
 				chapter: None,
 			})
 		} else if url.contains("chapter") {
-			let resp = Request::new(&url, HttpMethod::Get).html()?;
+			let resp = net::request(&url, HttpMethod::Get).checked_html()?;
 			let breadcrumbs_node = resp.select("a.itemcrumb.active");
 			let manga_id = breadcrumbs_node.attr("href").read();
 			let manga = Some(self.get_manga_details(manga_id)?);
@@ -1,9 +1,6 @@
 #![no_std]
 mod helper;
-use crate::helper::{
-	category_parser, extract_f32_from_string, genre_map, status_from_string, text_with_newlines,
-	urlencode,
-};
+use crate::helper::{category_parser, genre_map, status_from_string, text_with_newlines, urlencode};
 use aidoku::{
 	error::Result,
 	prelude::*,
@@ -15,6 +12,7 @@ use aidoku::{
 	},
 	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, Page,
 };
+use common::chapter::parse_chapter;
 
 static mut CACHED_MANGA_ID: Option<String> = None;
 static mut CACHED_MANGA: Option<Vec<u8>> = None;
@@ -253,26 +251,8 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 	for chapter_item in html.select("p[id^=\"chapter\"]").array() {
 		let chapter_node = chapter_item.as_node().expect("node array");
 		let chapter_id = chapter_node.select("span.title > a").attr("href").read();
-		let mut title = chapter_node
-			.select("span.title > a")
-			.text()
-			.read()
-			.replace(manga_title.trim(), "");
-		let numbers = extract_f32_from_string(String::from(&manga_title), String::from(&title));
-		let (volume, chapter) = if numbers.len() > 1 && title.to_ascii_lowercase().contains("vol") {
-			(numbers[0], numbers[1])
-		} else if !numbers.is_empty() {
-			(-1.0, numbers[0])
-		} else {
-			(-1.0, -1.0)
-		};
-		if chapter >= 0.0 {
-			let splitter = format!(" {}", chapter);
-			if title.contains(&splitter) {
-				let split = title.splitn(2, &splitter).collect::<Vec<&str>>();
-				title = String::from(split[1]).replacen(|char| char == ':' || char == '-', "", 1);
-			}
-		}
+		let title = chapter_node.select("span.title > a").text().read();
+		let parsed = parse_chapter(&title, manga_title.trim());
 		let date_updated = chapter_node
 			.select("span.publishedDate")
 			.text()
@@ -282,9 +262,9 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 		let url = format!("{BASE_URL}{chapter_id}");
 		chapter_arr.push(Chapter {
 			id: chapter_id,
-			title: String::from(title.trim()),
-			volume,
-			chapter,
+			title: parsed.title,
+			volume: parsed.volume,
+			chapter: parsed.chapter,
 			date_updated,
 			scanlator: String::from(&scanlator_string),
 			url,
@@ -357,27 +337,13 @@ fn handle_url(url: String) -> Result<DeepLink> {
 			.attr("href")
 			.read();
 		let manga = get_manga_details(manga_id)?;
-		let mut title = html.select("header h1").text().read();
-		let numbers = extract_f32_from_string(String::from(&manga.title), String::from(&title));
-		let (volume, chapter) = if numbers.len() > 1 && title.to_ascii_lowercase().contains("vol") {
-			(numbers[0], numbers[1])
-		} else if !numbers.is_empty() {
-			(-1.0, numbers[0])
-		} else {
-			(-1.0, -1.0)
-		};
-		if chapter >= 0.0 {
-			let splitter = format!(" {}", chapter);
-			if title.contains(&splitter) {
-				let split = title.splitn(2, &splitter).collect::<Vec<&str>>();
-				title = String::from(split[1]).replacen(|char| char == ':' || char == '-', "", 1);
-			}
-		}
+		let title = html.select("header h1").text().read();
+		let parsed = parse_chapter(&title, &manga.title);
 		let chapter = Chapter {
 			id,
-			title,
-			volume,
-			chapter,
+			title: parsed.title,
+			volume: parsed.volume,
+			chapter: parsed.chapter,
 			date_updated: -1.0,
 			scanlator: String::new(),
 			url,
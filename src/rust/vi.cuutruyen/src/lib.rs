@@ -5,14 +5,37 @@ mod parser;
 mod search;
 
 use aidoku::{
-	error::{AidokuError, Result}, prelude::*, std::{net::Request, String, Vec}, Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, Page
+	error::{AidokuError, Result}, prelude::*, std::{defaults::defaults_get, net::Request, String, Vec}, Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, Page
 };
 use alloc::string::ToString;
-use parser::{parse_chapter_list, parse_manga_details, parse_page_list, parse_search_page};
-use search::get_search_url;
+use parser::{
+	parse_chapter_list, parse_collection_details, parse_manga_details, parse_page_list,
+	parse_search_page,
+};
+use search::{get_collection_url, get_favourites_url, get_search_url, get_tag_url, get_team_url};
 
 pub static BASE_URL: &str = "https://cuutruyen.net";
 
+const TEAM_KEY: &str = "teamID";
+const TAG_KEY: &str = "tagSlug";
+const TOKEN_KEY: &str = "token";
+
+/// Site paths of the pages listing the works of a team or tag. Links to them
+/// open as a manga with the chapters of all the works, with the path as id.
+const COLLECTIONS: [&str; 2] = ["teams", "tags"];
+
+fn is_collection(id: &str) -> bool {
+	id.split_once('/')
+		.is_some_and(|(kind, _)| COLLECTIONS.contains(&kind))
+}
+
+fn read_setting(key: &str) -> Option<String> {
+	defaults_get(key)
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.map(|v| String::from(v.trim()))
+		.filter(|v| !v.is_empty())
+}
+
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	let search_url = get_search_url(filters, page);
@@ -26,33 +49,86 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 
 #[get_manga_listing]
 fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
-	let page_url = match listing.name.as_str() {
-		"Mới cập nhật" => "recently_updated",
+	let url = match listing.name.as_str() {
+		"Mới cập nhật" => format!("{BASE_URL}/api/v2/mangas/recently_updated?page={page}&per_page=30"),
+		"Nhóm dịch" => match read_setting(TEAM_KEY) {
+			Some(id) => get_team_url(&id, page),
+			None => return Ok(MangaPageResult {
+				manga: Vec::new(),
+				has_more: false,
+			}),
+		},
+		"Thẻ" => match read_setting(TAG_KEY) {
+			Some(slug) => get_tag_url(&slug, page),
+			None => return Ok(MangaPageResult {
+				manga: Vec::new(),
+				has_more: false,
+			}),
+		},
+		"Yêu thích" => {
+			let token = read_setting(TOKEN_KEY).ok_or(AidokuError {
+				reason: aidoku::error::AidokuErrorKind::DefaultNotFound,
+			})?;
+			let req = Request::get(get_favourites_url(page))
+				.header("Referer", BASE_URL)
+				.header("Authorization", &format!("Bearer {}", token.trim_start_matches("Bearer ")));
+			return parse_search_page(req.json()?.as_object()?, page);
+		}
 		_ => {
 			return Err(AidokuError {
 				reason: aidoku::error::AidokuErrorKind::Unimplemented,
 			});
 		}
 	};
-	let req = Request::get(format!(
-		"{BASE_URL}/api/v2/mangas/{page_url}?page={page}&per_page=30"
-	))
-	.header("Referer", BASE_URL);
+	let req = Request::get(url).header("Referer", BASE_URL);
 	parse_search_page(req.json()?.as_object()?, page)
 }
 
 #[get_manga_details]
 fn get_manga_details(id: String) -> Result<Manga> {
+	if is_collection(&id) {
+		let req = Request::get(get_collection_url(&id, 1)).header("Referer", BASE_URL);
+		return parse_collection_details(req.json()?.as_object()?, id);
+	}
 	let req = Request::get(format!("{BASE_URL}/api/v2/mangas/{id}")).header("Referer", BASE_URL);
 	parse_manga_details(req.json()?.as_object()?)
 }
 
 #[get_chapter_list]
 fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
+	if is_collection(&id) {
+		return get_collection_chapters(&id);
+	}
 	let req = Request::get(format!("{BASE_URL}/api/v2/mangas/{id}/chapters")).header("Referer", BASE_URL);
 	parse_chapter_list(req.json()?.as_object()?, id)
 }
 
+/// The chapters of every work of a team or tag, titled after their work.
+fn get_collection_chapters(id: &str) -> Result<Vec<Chapter>> {
+	let mut chapters = Vec::new();
+	let mut page = 1;
+	loop {
+		let req = Request::get(get_collection_url(id, page)).header("Referer", BASE_URL);
+		let works = parse_search_page(req.json()?.as_object()?, page)?;
+		for work in works.manga {
+			let req = Request::get(format!("{BASE_URL}/api/v2/mangas/{}/chapters", work.id))
+				.header("Referer", BASE_URL);
+			for mut chapter in parse_chapter_list(req.json()?.as_object()?, work.id)? {
+				chapter.title = if chapter.title.is_empty() {
+					work.title.clone()
+				} else {
+					format!("{} - {}", work.title, chapter.title)
+				};
+				chapters.push(chapter);
+			}
+		}
+		if !works.has_more {
+			return Ok(chapters);
+		}
+		page += 1;
+	}
+}
+
 #[get_page_list]
 fn get_page_list(_: String, id: String) -> Result<Vec<Page>> {
 	let req = Request::get(format!("{BASE_URL}/api/v2/chapters/{id}")).header("Referer", BASE_URL);
@@ -71,8 +147,24 @@ fn handle_url(url: String) -> Result<DeepLink> {
 
     if let Some(pos) = segments.iter().position(|&s| s == "mangas") {
         if let Some(manga_id) = segments.get(pos + 1) {
+            let chapter = match (segments.get(pos + 2), segments.get(pos + 3)) {
+                (Some(&"chapters"), Some(chapter_id)) => Some(Chapter {
+                    id: chapter_id.to_string(),
+                    ..Default::default()
+                }),
+                _ => None,
+            };
+            return Ok(DeepLink {
+                manga: Some(get_manga_details(manga_id.to_string())?),
+                chapter,
+            });
+        }
+    }
+
+    if let Some(pos) = segments.iter().position(|s| COLLECTIONS.contains(s)) {
+        if let Some(collection_id) = segments.get(pos + 1).filter(|s| !s.is_empty()) {
             return Ok(DeepLink {
-                manga: get_manga_details(manga_id.to_string()).ok(),
+                manga: Some(get_manga_details(format!("{}/{collection_id}", segments[pos]))?),
                 chapter: None,
             });
         }
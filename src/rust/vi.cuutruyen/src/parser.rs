@@ -6,78 +6,111 @@ use aidoku::{
 	prelude::format,
 	std::{ObjectRef, String, Vec},
 };
+use common::descramble::{RowBlock, Scramble, decode_base64, xor_with_key};
 use time::format_description::well_known::Rfc3339;
 
 use crate::BASE_URL;
 
+fn parse_error() -> AidokuError {
+	AidokuError {
+		reason: AidokuErrorKind::JsonParseError,
+	}
+}
+
+fn read_string(object: &ObjectRef, key: &str) -> String {
+	object
+		.get(key)
+		.as_string()
+		.map(|v| v.read())
+		.unwrap_or_default()
+}
+
 pub fn parse_search_page(data: ObjectRef, page: i32) -> Result<MangaPageResult> {
+	// tag and team pages nest the list next to the details of the tag or team
 	let mangas = if let Ok(arr) = data.get("data").as_array() {
 		arr
 	} else {
 		data.get("data").as_object()?.get("mangas").as_array()?
 	};
 
+	let mut manga = Vec::new();
+	for item in mangas {
+		let item = item.as_object()?;
+		manga.push(Manga {
+			id: item.get("id").as_int()?.to_string(),
+			cover: read_string(&item, "cover_mobile_url"),
+			title: read_string(&item, "name"),
+			author: read_string(&item, "author_name"),
+			description: read_string(&item, "newest_chapter_number"),
+			..Default::default()
+		});
+	}
+
+	let total_pages = data
+		.get("_metadata")
+		.as_object()
+		.and_then(|meta| meta.get("total_pages").as_int())
+		.unwrap_or(0);
+
 	Ok(MangaPageResult {
-		manga: mangas
-			.map(|manga| {
-				let item = manga.as_object().unwrap();
-				Manga {
-					id: item.get("id").as_int().unwrap().to_string(),
-					cover: item.get("cover_mobile_url").as_string().unwrap().read(),
-					title: item.get("name").as_string().unwrap().read(),
-					author: item
-						.get("author_name")
-						.as_string()
-						.unwrap_or_default()
-						.read(),
-					description: item
-						.get("newest_chapter_number")
-						.as_string()
-						.unwrap_or_default()
-						.read(),
-					..Default::default()
-				}
-			})
-			.collect(),
-		has_more: page
-			< data
-				.get("_metadata")
-				.as_object()
-				.unwrap()
-				.get("total_pages")
-				.as_int()
-				.unwrap() as i32,
+		manga,
+		has_more: (page as i64) < total_pages,
+	})
+}
+
+/// A team or tag shown as a manga, its description listing the works. `id` is
+/// the path of the collection, e.g. `teams/<id>`.
+pub fn parse_collection_details(data: ObjectRef, id: String) -> Result<Manga> {
+	let meta = data.get("data").as_object()?;
+	let works = parse_search_page(data, 1)?.manga;
+
+	let title = match read_string(&meta, "name") {
+		name if name.is_empty() => id.rsplit('/').next().unwrap_or_default().to_string(),
+		name => name,
+	};
+	let cover = works
+		.first()
+		.map(|work| work.cover.clone())
+		.unwrap_or_default();
+	let description = works
+		.iter()
+		.map(|work| format!("• {}", work.title))
+		.collect::<Vec<_>>()
+		.join("\n");
+
+	Ok(Manga {
+		url: format!("{BASE_URL}/{id}"),
+		id,
+		cover,
+		title,
+		description,
+		..Default::default()
 	})
 }
 
 pub fn parse_manga_details(data: ObjectRef) -> Result<Manga> {
-	let meta = data.get("data").as_object().unwrap();
+	let meta = data.get("data").as_object()?;
 
-	let id = meta.get("id").as_int().unwrap().to_string();
-	let cover = meta.get("cover_mobile_url").as_string().unwrap().read();
-	let title = meta.get("name").as_string().unwrap().read();
+	let id = meta.get("id").as_int()?.to_string();
+	let cover = read_string(&meta, "cover_mobile_url");
+	let title = read_string(&meta, "name");
 	let author = meta
 		.get("author")
 		.as_object()
-		.unwrap()
-		.get("name")
-		.as_string()
-		.unwrap()
-		.read();
-	let description = meta.get("full_description").as_string().unwrap().read();
-	let url = format!("{BASE_URL}/mangas/${id}");
-	let categories = meta
-		.get("tags")
-		.as_array()
-		.unwrap()
-		.map(|tag_ref| {
-			let tag = tag_ref.as_object().unwrap();
-
-			tag.get("name").as_string().unwrap().read()
-		})
-		.collect();
+		.map(|author| read_string(&author, "name"))
+		.unwrap_or_default();
+	let description = read_string(&meta, "full_description");
+	let url = format!("{BASE_URL}/mangas/{id}");
+	let categories = match meta.get("tags").as_array() {
+		Ok(tags) => tags
+			.filter_map(|tag| tag.as_object().ok())
+			.map(|tag| read_string(&tag, "name"))
+			.filter(|name| !name.is_empty())
+			.collect(),
+		Err(_) => Vec::new(),
+	};
 	let status = MangaStatus::Unknown;
-	let nsfw = if meta.get("is_nsfw").as_bool().unwrap() {
+	let nsfw = if meta.get("is_nsfw").as_bool().unwrap_or(false) {
 		MangaContentRating::Nsfw
 	} else {
 		MangaContentRating::Safe
@@ -98,84 +131,63 @@ pub fn parse_manga_details(data: ObjectRef) -> Result<Manga> {
 }
 
 pub fn parse_chapter_list(data: ObjectRef, manga_id: String) -> Result<Vec<Chapter>> {
-	let total = data.get("data").as_array().iter().len() as f32;
-
-	Ok(data
-		.get("data")
-		.as_array()
-		.unwrap()
-		.enumerate()
-		.map(|(idx, chapter_ref)| {
-			let chapter_data = chapter_ref.as_object().unwrap();
-
-			let id = chapter_data.get("id").as_int().unwrap().to_string();
-			let title = chapter_data
-				.get("name")
-				.as_string()
-				.unwrap_or("".into())
-				.read();
-
-			let number = chapter_data.get("number").as_string().unwrap().read();
-			let (volume, chapter) = if number.contains("vol.") {
-				// number sample: Bonus vol. 1
-				// chapter = -1.0
-				let vol_value = number
-					.split("vol.")
-					.nth(1)
-					.map(|s| s.trim())
-					.and_then(|s| s.parse::<f32>().ok())
-					.unwrap_or(-1.0);
-
-				(vol_value, total - idx as f32)
-			} else {
-				(
-					-1.0,
-					chapter_data
-						.get("number")
-						.as_string()
-						.and_then(|s| {
-							s.read()
-								.to_string()
-								.parse::<f32>()
-								.map_err(|_| AidokuError {
-									reason: AidokuErrorKind::JsonParseError,
-								})
-						})
-						.unwrap_or(total - idx as f32),
-				)
-			};
-
-			Chapter {
-				id: id.clone(),
-				title,
-				volume,
-				chapter,
-				date_updated: chapter_data
-					.get("updated_at")
-					.as_string()
-					.and_then(|s| {
-						time::OffsetDateTime::parse(&s.read(), &Rfc3339).map_err(|_| AidokuError {
-							reason: AidokuErrorKind::JsonParseError,
-						})
-					})
-					.map(|dt| dt.unix_timestamp() as f64)
-					.unwrap_or(-1.0),
-				url: format!("{BASE_URL}/mangas/{manga_id}/chapters/{id}"),
-				lang: "en".to_string(),
-				..Default::default()
-			}
-		})
-		.collect())
+	let list = data.get("data").as_array()?;
+	let total = list.clone().count() as f32;
+
+	let mut chapters = Vec::new();
+	for (idx, chapter_ref) in list.enumerate() {
+		let chapter_data = chapter_ref.as_object()?;
+
+		let id = chapter_data.get("id").as_int()?.to_string();
+		let title = read_string(&chapter_data, "name");
+
+		let number = read_string(&chapter_data, "number");
+		let (volume, chapter) = if number.contains("vol.") {
+			// number sample: Bonus vol. 1
+			// chapter = -1.0
+			let vol_value = number
+				.split("vol.")
+				.nth(1)
+				.map(|s| s.trim())
+				.and_then(|s| s.parse::<f32>().ok())
+				.unwrap_or(-1.0);
+
+			(vol_value, total - idx as f32)
+		} else {
+			(
+				-1.0,
+				number
+					.trim()
+					.parse::<f32>()
+					.unwrap_or(total - idx as f32),
+			)
+		};
+
+		let date_updated = chapter_data
+			.get("updated_at")
+			.as_string()
+			.ok()
+			.and_then(|s| time::OffsetDateTime::parse(&s.read(), &Rfc3339).ok())
+			.map(|dt| dt.unix_timestamp() as f64)
+			.unwrap_or(-1.0);
+
+		chapters.push(Chapter {
+			id: id.clone(),
+			title,
+			volume,
+			chapter,
+			date_updated,
+			url: format!("{BASE_URL}/mangas/{manga_id}/chapters/{id}"),
+			lang: "en".to_string(),
+			..Default::default()
+		});
+	}
+
+	Ok(chapters)
 }
 
 pub fn parse_page_list(data: ObjectRef) -> Result<Vec<Page>> {
-	let chapters = data
-		.get("data")
-		.as_object()
-		.unwrap()
-		.get("pages")
-		.as_array()
-		.unwrap();
+	let chapters = data.get("data").as_object()?.get("pages").as_array()?;
 
 	if chapters.clone().count() == 0 {
 		return Err(AidokuError {
@@ -183,125 +195,56 @@ pub fn parse_page_list(data: ObjectRef) -> Result<Vec<Page>> {
 		});
 	};
 
-	let pages = chapters
-		.map(|chapter_ref| {
-			let chapter = chapter_ref.as_object().unwrap();
-
-			let index = chapter.get("order").as_int().unwrap() as i32;
-			let url = chapter.get("image_url").as_string().unwrap().read();
-			let drm_data = chapter.get("drm_data").as_string().unwrap().read();
-
-			let blocks = decode_drm(drm_data.replace('\n', "").trim()).unwrap();
-			let base64 = {
-				let mut s = String::from("[");
-				for (i, rb) in blocks.iter().enumerate() {
-					if i > 0 {
-						s.push(',');
-					}
-					s.push_str(&format!(
-						r#"{{"sx":0,"sy":-1,"dx":0,"dy":{},"width":0,"height":{}}}"#,
-						rb.dy, rb.height
-					));
-				}
-				s.push(']');
-				s
-			};
-
-			Page {
-				index,
-				url,
-				base64,
-				..Default::default()
-			}
-		})
-		.collect();
+	let mut pages = Vec::new();
+	for chapter_ref in chapters {
+		let chapter = chapter_ref.as_object()?;
+
+		let index = chapter.get("order").as_int()? as i32;
+		let url = chapter.get("image_url").as_string()?.read();
+		let drm_data = read_string(&chapter, "drm_data");
+
+		// pages without drm data are served as they are
+		let base64 = if drm_data.trim().is_empty() {
+			String::new()
+		} else {
+			decode_drm(drm_data.replace('\n', "").trim())?.serialize()
+		};
+
+		pages.push(Page {
+			index,
+			url,
+			base64,
+			..Default::default()
+		});
+	}
 
 	Ok(pages)
 }
 
-#[derive(Debug, Clone)]
-pub struct RowBlock {
-	pub dy: i32,
-	pub height: i32,
-}
+const DRM_KEY: &[u8; 16] = b"3141592653589793";
 
-fn decode_xor_cipher(data: &[u8]) -> Vec<u8> {
-	let key_bytes: [u8; 16] = [
-		51, 49, 52, 49, 53, 57, 50, 54, 53, 51, 53, 56, 57, 55, 57, 51,
-	];
-	let mut output: Vec<u8> = Vec::with_capacity(data.len());
+fn decode_drm(drm_data: &str) -> Result<Scramble> {
+	let decoded = decode_base64(drm_data)?;
+	let xored = xor_with_key(&decoded, DRM_KEY);
 
-	for (i, &b) in data.iter().enumerate() {
-		let kb = key_bytes[i % key_bytes.len()];
-		let x = b ^ kb;
-		output.push(x);
-	}
-
-	output
-}
-
-fn decode_drm(drm_data: &str) -> Result<Vec<RowBlock>> {
-	let decoded = decode_base64(&drm_data.replace('\n', ""))
-		.map_err(|_| "Base64 decode failed")
-		.unwrap();
-	let xored = decode_xor_cipher(&decoded);
-
-	let decoded_str = str::from_utf8(&xored).map_err(|_| "Invalid UTF-8").unwrap();
+	let decoded_str = str::from_utf8(&xored).map_err(|_| parse_error())?;
 
 	if !decoded_str.starts_with("#v4|") {
-		// return Err("Invalid DRM data (does not start with expected magic bytes)");
-
-		return Err(AidokuError {
-			reason: AidokuErrorKind::JsonParseError,
-		});
+		return Err(parse_error());
 	}
 
-	let parts: Vec<&str> = decoded_str.split('|').skip(1).collect();
 	let mut blocks = Vec::new();
 
-	for part in parts {
+	for part in decoded_str.split('|').skip(1) {
 		let values: Vec<&str> = part.split('-').collect();
 		if values.len() == 2 {
-			let dy: i32 = values[0].parse().unwrap();
-			let height: i32 = values[1].parse().unwrap();
+			let dy: i32 = values[0].trim().parse().map_err(|_| parse_error())?;
+			let height: i32 = values[1].trim().parse().map_err(|_| parse_error())?;
 			blocks.push(RowBlock { dy, height });
 		}
 	}
 
-	Ok(blocks)
-}
-
-fn decode_base64(input: &str) -> Result<Vec<u8>> {
-	let mut out = Vec::with_capacity(input.len() * 3 / 4 + 1);
-	let mut buffer: u32 = 0;
-	let mut bits_collected = 0;
-
-	for &b in input.as_bytes() {
-		let val = match b {
-			b'A'..=b'Z' => b - b'A',
-			b'a'..=b'z' => b - b'a' + 26,
-			b'0'..=b'9' => b - b'0' + 52,
-			b'+' => 62,
-			b'/' => 63,
-			b'=' => 0,
-			b'\n' | b'\r' | b'\t' | b' ' => continue,
-			_ => {
-				return Err(AidokuError {
-					reason: AidokuErrorKind::DefaultNotFound,
-				});
-			}
-		} as u32;
-
-		buffer = (buffer << 6) | val;
-		bits_collected += 6;
-
-		if bits_collected >= 8 {
-			bits_collected -= 8;
-			let byte = (buffer >> bits_collected) as u8;
-			out.push(byte);
-			buffer &= (1 << bits_collected) - 1;
-		}
-	}
-
-	Ok(out)
+	let scramble = Scramble::Rows(blocks);
+	scramble.validate()?;
+	Ok(scramble)
 }
@@ -20,11 +20,9 @@ pub fn get_search_url(filters: Vec<Filter>, page: i32) -> String {
 				}
 			}
 			FilterType::Genre => {
-				return format!(
-					"{BASE_URL}/api/v2/tags/{}?page={}&per_page=30",
-					&filter.object.get("id").as_string().unwrap(),
-					&page.to_string()
-				);
+				if let Ok(id) = filter.object.get("id").as_string() {
+					return get_tag_url(&id.read(), page);
+				}
 			}
 			_ => {}
 		}
@@ -32,3 +30,20 @@ pub fn get_search_url(filters: Vec<Filter>, page: i32) -> String {
 
 	return BASE_URL.to_string();
 }
+
+pub fn get_tag_url(slug: &str, page: i32) -> String {
+	get_collection_url(&format!("tags/{slug}"), page)
+}
+
+pub fn get_team_url(id: &str, page: i32) -> String {
+	get_collection_url(&format!("teams/{id}"), page)
+}
+
+/// The works of a team or tag, `path` being e.g. `teams/<id>`.
+pub fn get_collection_url(path: &str, page: i32) -> String {
+	format!("{BASE_URL}/api/v2/{path}?page={page}&per_page=30")
+}
+
+pub fn get_favourites_url(page: i32) -> String {
+	format!("{BASE_URL}/api/v2/personal/favorites?page={page}&per_page=30")
+}
@@ -11,9 +11,8 @@ use aidoku::{
 	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
 	MangaViewer, Page,
 };
-use helper::{
-	append_protocol, category_parser, extract_f32_from_string, text_with_newlines, urlencode,
-};
+use common::chapter::parse_chapter;
+use helper::{append_protocol, category_parser, text_with_newlines, urlencode};
 
 static mut CACHED_MANGA_ID: Option<String> = None;
 static mut CACHED_MANGA: Option<Vec<u8>> = None;
@@ -278,15 +277,7 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 			.read()
 			.replace("- ", "");
 		let vol_chap = chapter_node.select("strong").text().read();
-		let numbers = extract_f32_from_string(String::new(), vol_chap.clone());
-		let (volume, chapter) =
-			if numbers.len() > 1 && vol_chap.to_ascii_lowercase().contains("vol") {
-				(numbers[0], numbers[1])
-			} else if !numbers.is_empty() {
-				(-1.0, numbers[0])
-			} else {
-				(-1.0, -1.0)
-			};
+		let parsed = parse_chapter(&vol_chap, "");
 		let date_updated = chapter_node
 			.select("time")
 			.attr("datetime")
@@ -296,8 +287,8 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 		chapters.push(Chapter {
 			id: chapter_id,
 			title: String::from(title.trim()),
-			volume,
-			chapter,
+			volume: parsed.volume,
+			chapter: parsed.chapter,
 			date_updated,
 			scanlator: String::from(&scanlator),
 			url,
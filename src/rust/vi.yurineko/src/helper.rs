@@ -247,19 +247,6 @@ pub fn urlencode(string: String) -> String {
 	String::from_utf8(result).unwrap_or_default()
 }
 
-pub fn extract_f32_from_string(title: String, text: String) -> f32 {
-	text.replace(&title, " ")
-		.chars()
-		.filter(|a| (*a >= '0' && *a <= '9') || *a == ' ' || *a == '.')
-		.collect::<String>()
-		.split(' ')
-		.collect::<Vec<&str>>()
-		.into_iter()
-		.map(|a| a.parse::<f32>().unwrap_or(0.0))
-		.find(|a| *a > 0.0)
-		.unwrap_or(0.0)
-}
-
 pub fn get_search_url(base_url: String, query: String, tag: String, page: i32) -> String {
 	if !query.is_empty() {
 		format!("{base_url}/search?query={query}&page={page}")
@@ -1,10 +1,11 @@
-use crate::helper::{extract_f32_from_string, i32_to_string, status_map, text_with_newlines};
+use crate::helper::{i32_to_string, status_map, text_with_newlines};
 use aidoku::{
 	error::Result,
 	prelude::format,
 	std::{ObjectRef, String, Vec},
 	Chapter, Manga, MangaContentRating, MangaViewer,
 };
+use common::chapter;
 
 pub fn parse_manga(manga_object: ObjectRef) -> Result<Manga> {
 	let id = manga_object.get("id").as_int().unwrap_or(-1);
@@ -69,7 +70,7 @@ pub fn parse_chapter(scanlator: String, chapter_object: ObjectRef) -> Result<Cha
 	let chapter_id = format!("{manga_id}/{id}");
 	let url = format!("https://yurineko.net/read/{chapter_id}");
 	let title = chapter_object.get("name").as_string()?.read();
-	let chapter_number = extract_f32_from_string(String::from("-"), String::from(&title));
+	let numbers = chapter::parse_chapter(&title, "");
 
 	let date_string = chapter_object.get("date").as_string()?;
 	let date_object = date_string
@@ -80,8 +81,8 @@ pub fn parse_chapter(scanlator: String, chapter_object: ObjectRef) -> Result<Cha
 	Ok(Chapter {
 		id: chapter_id,
 		title,
-		volume: -1.0,
-		chapter: chapter_number,
+		volume: numbers.volume,
+		chapter: numbers.chapter,
 		date_updated: date_object,
 		scanlator,
 		url,
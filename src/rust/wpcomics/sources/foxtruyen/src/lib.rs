@@ -5,7 +5,11 @@ use aidoku::{
 	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
 	FilterType, Listing, Manga, MangaPageResult, MangaViewer, Page,
 };
-use wpcomics_template::{helper::urlencode, template, template::WPComicsSource};
+use wpcomics_template::{
+	helper::{convert_vi_time, urlencode},
+	template,
+	template::WPComicsSource,
+};
 
 const BASE_URL: &str = "https://foxtruyen.com";
 
@@ -47,12 +51,7 @@ fn get_instance() -> WPComicsSource {
 					.trim_end_matches(".html"),
 			)
 		},
-		time_converter: |ago| {
-			aidoku::std::StringRef::from(ago)
-				.0
-				.as_date("dd/MM/yyyy", None, Some("Asia/Ho_Chi_Minh"))
-				.unwrap_or(-1.0)
-		},
+		time_converter: convert_vi_time,
 		manga_viewer_page: ".content_detail_manga img",
 		..Default::default()
 	}
@@ -70,7 +69,10 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 		}
 	}
 	let instance = get_instance();
-	instance.get_manga_list(get_search_url(instance.base_url.clone(), title, page), None)
+	instance.get_manga_list(
+		get_search_url(instance.active_base_url(), title, page),
+		None,
+	)
 }
 
 #[get_manga_listing]
@@ -80,26 +82,39 @@ fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
 
 #[get_manga_details]
 fn get_manga_details(id: String) -> Result<Manga> {
-	get_instance().get_manga_details(format!("{}/truyen-tranh/{}", BASE_URL, id))
+	let instance = get_instance();
+	instance.get_manga_details(format!(
+		"{}/truyen-tranh/{}",
+		instance.active_base_url(),
+		id
+	))
 }
 
 #[get_chapter_list]
 fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
-	get_instance().get_chapter_list(format!("{}/truyen-tranh/{}", BASE_URL, id))
+	let instance = get_instance();
+	instance.get_chapter_list(format!(
+		"{}/truyen-tranh/{}",
+		instance.active_base_url(),
+		id
+	))
 }
 
 #[get_page_list]
 fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
-	get_instance().get_page_list(format!(
+	let instance = get_instance();
+	instance.get_page_list(format!(
 		"{}/truyen-tranh/{}-chap-{}",
-		BASE_URL, manga_id, chapter_id
+		instance.active_base_url(),
+		manga_id,
+		chapter_id
 	))
 }
 
 #[modify_image_request]
 fn modify_image_request(request: Request) {
 	template::modify_image_request(
-		String::from(BASE_URL),
+		get_instance().active_base_url(),
 		String::from("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/101.0.4951.54 Safari/537.36 Edg/101.0.1210.39"),
 		request,
 	)
@@ -5,7 +5,11 @@ use aidoku::{
 	error::Result, prelude::*, std::net::Request, std::String, std::Vec, Chapter, DeepLink, Filter,
 	FilterType, Listing, Manga, MangaPageResult, MangaViewer, Page,
 };
-use wpcomics_template::{helper::urlencode, template, template::WPComicsSource};
+use wpcomics_template::{
+	helper::{convert_vi_time, urlencode},
+	template,
+	template::WPComicsSource,
+};
 
 const BASE_URL: &str = "https://nettruyenvia.com";
 
@@ -23,7 +27,7 @@ fn get_instance() -> WPComicsSource {
 			})
 		},
 		status_mapping: status_map,
-		time_converter: convert_time,
+		time_converter: convert_vi_time,
 		manga_viewer_page_attr: "data-src",
 		manga_parse_id: |url| {
 			String::from(
@@ -34,6 +38,7 @@ fn get_instance() -> WPComicsSource {
 			)
 		},
 		chapter_parse_id: |url| String::from(url.trim_end_matches('/').rsplit('/').next().unwrap()),
+		chapter_list_ajax: true,
 		..Default::default()
 	}
 }
@@ -83,7 +88,7 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	let instance = get_instance();
 	instance.get_manga_list(
 		get_search_url(
-			instance.base_url.clone(),
+			instance.active_base_url(),
 			title,
 			page,
 			category,
@@ -101,26 +106,39 @@ fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
 
 #[get_manga_details]
 fn get_manga_details(id: String) -> Result<Manga> {
-	get_instance().get_manga_details(format!("{}/truyen-tranh/{}", BASE_URL, id))
+	let instance = get_instance();
+	instance.get_manga_details(format!(
+		"{}/truyen-tranh/{}",
+		instance.active_base_url(),
+		id
+	))
 }
 
 #[get_chapter_list]
 fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
-	get_instance().get_chapter_list(format!("{}/truyen-tranh/{}", BASE_URL, id))
+	let instance = get_instance();
+	instance.get_chapter_list(format!(
+		"{}/truyen-tranh/{}",
+		instance.active_base_url(),
+		id
+	))
 }
 
 #[get_page_list]
 fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
-	get_instance().get_page_list(format!(
+	let instance = get_instance();
+	instance.get_page_list(format!(
 		"{}/truyen-tranh/{}/{}",
-		BASE_URL, manga_id, chapter_id
+		instance.active_base_url(),
+		manga_id,
+		chapter_id
 	))
 }
 
 #[modify_image_request]
 fn modify_image_request(request: Request) {
 	template::modify_image_request(
-		String::from(BASE_URL),
+		get_instance().active_base_url(),
 		String::from("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/101.0.4951.54 Safari/537.36 Edg/101.0.1210.39"),
 		request,
 	)
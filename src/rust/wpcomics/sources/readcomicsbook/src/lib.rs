@@ -14,9 +14,11 @@ use wpcomics_template::{
 	template::{self, *},
 };
 
+const BASE_URL: &str = "https://readcomicsbook.com";
+
 fn get_instance() -> WPComicsSource {
 	WPComicsSource {
-		base_url: String::from("https://readcomicsbook.com"),
+		base_url: String::from(BASE_URL),
 		listing_mapping,
 
 		manga_cell: "li[itemtype=\"https://schema.org/Book\"]",
@@ -61,9 +63,11 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 			},
 		}
 	}
+	let instance = get_instance();
+	let base_url = instance.active_base_url();
 	if !title.is_empty() {
 		let json = Request::new(
-			format!("https://readcomicsbook.com/ajax/search?q={title}").as_str(),
+			format!("{base_url}/ajax/search?q={title}").as_str(),
 			HttpMethod::Get,
 		)
 		.json()?
@@ -72,7 +76,7 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 		let mut manga_arr: Vec<Manga> = Vec::new();
 		for manga in result {
 			let manga_obj = manga.as_object()?;
-			if let Ok(manga) = parse_comic(String::from("https://readcomicsbook.com"), manga_obj) {
+			if let Ok(manga) = parse_comic(base_url.clone(), manga_obj) {
 				manga_arr.push(manga);
 			}
 		}
@@ -81,10 +85,7 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 			has_more: false,
 		})
 	} else {
-		get_instance().get_manga_list(
-			get_search_url(String::from("https://readcomicsbook.com"), genre, page),
-			None,
-		)
+		instance.get_manga_list(get_search_url(base_url, genre, page), None)
 	}
 }
 
@@ -111,7 +112,7 @@ fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 #[modify_image_request]
 fn modify_image_request(request: Request) {
 	template::modify_image_request(
-		String::from("https://readcomicsbook.com"),
+		get_instance().active_base_url(),
 		String::from("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/101.0.4951.54 Safari/537.36 Edg/101.0.1210.39"),
 		request,
 	)
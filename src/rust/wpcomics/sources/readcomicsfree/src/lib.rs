@@ -10,9 +10,11 @@ use wpcomics_template::{
 	template::WPComicsSource,
 };
 
+const BASE_URL: &str = "https://readcomicsfree.com";
+
 fn get_instance() -> WPComicsSource {
 	WPComicsSource {
-		base_url: String::from("https://readcomicsfree.com"),
+		base_url: String::from(BASE_URL),
 		listing_mapping: listing_map,
 
 		manga_cell_image: "",
@@ -44,13 +46,9 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 			},
 		}
 	}
-	get_instance().get_manga_list(
-		get_search_url(
-			String::from("https://readcomicsfree.com"),
-			title,
-			genre,
-			page,
-		),
+	let instance = get_instance();
+	instance.get_manga_list(
+		get_search_url(instance.active_base_url(), title, genre, page),
 		None,
 	)
 }
@@ -2,23 +2,25 @@
 use aidoku::{
 	error::Result,
 	prelude::*,
-	std::{defaults::defaults_get, net::HttpMethod, net::Request, String, StringRef, Vec},
+	std::{
+		defaults::{defaults_get, defaults_set},
+		net::Request,
+		String, StringRef, Vec,
+	},
 	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaPageResult, MangaStatus,
 	MangaViewer, Page,
 };
-use wpcomics_template::helper::extract_f32_from_string;
-use wpcomics_template::{helper::urlencode, template::WPComicsSource};
+use common::net;
+use wpcomics_template::{
+	helper::{convert_vi_time, parse_chapter, urlencode},
+	template::WPComicsSource,
+};
 
 fn get_base_url() -> Result<String> {
 	defaults_get("baseURL")?
 		.as_string()
 		.map(|v| String::from(v.read().trim_end_matches('/')))
 }
-fn get_proxy_url() -> Result<String> {
-	defaults_get("proxy")?
-		.as_string()
-		.map(|v| String::from(v.read().trim_end_matches('/')))
-}
 fn get_visit_read_id() -> Result<String> {
 	defaults_get("visitReadId")?
 		.as_string()
@@ -27,20 +29,27 @@ fn get_visit_read_id() -> Result<String> {
 
 const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) GSA/300.0.598994205 Mobile/15E148 Safari/604";
 
-fn get_url_with_proxy(url: &str) -> String {
-	// if proxy == null -> skip
-	match get_proxy_url() {
-		Ok(proxy) => {
-			if proxy.is_empty() {
-				String::from(url)
-			} else {
-				format!("{}?url={}", proxy, url)
-			}
-		}
-		Err(_) => String::from(url),
+/// Moves the `proxy` setting of older versions, a `?url=` proxy, to the relay
+/// settings of the template.
+fn migrate_proxy_setting() {
+	let read = |key: &str| {
+		defaults_get(key)
+			.and_then(|v| v.as_string().map(|v| v.read()))
+			.map(|v| String::from(v.trim().trim_end_matches('/')))
+			.unwrap_or_default()
+	};
+	let proxy = read("proxy");
+	if proxy.starts_with("http") && read("relayURL").is_empty() {
+		defaults_set("relayURL", StringRef::from(format!("{proxy}?url=")).0);
+		defaults_set("proxy", StringRef::from("").0);
 	}
 }
 
+/// Chapter ids of older versions carry the proxy url in front of them.
+fn strip_proxy(id: &str) -> &str {
+	id.rsplit_once("?url=").map_or(id, |(_, id)| id)
+}
+
 fn get_instance() -> WPComicsSource {
 	WPComicsSource {
 		base_url: String::from(get_base_url().unwrap_or_default()),
@@ -57,35 +66,7 @@ fn get_instance() -> WPComicsSource {
 			"Hoàn Thành" => MangaStatus::Completed,
 			_ => MangaStatus::Unknown,
 		},
-		time_converter: |time_ago| {
-			let current_time = aidoku::std::current_date();
-			let time_arr = time_ago.split(' ').collect::<Vec<&str>>();
-			if time_arr.len() > 2 {
-				match time_arr[1] {
-					"giây" => current_time - time_arr[0].parse::<f64>().unwrap_or(0.0),
-					"phút" => current_time - time_arr[0].parse::<f64>().unwrap_or(0.0) * 60.0,
-					"giờ" => current_time - time_arr[0].parse::<f64>().unwrap_or(0.0) * 3600.0,
-					"ngày" => current_time - time_arr[0].parse::<f64>().unwrap_or(0.0) * 86400.0,
-					_ => current_time,
-				}
-			} else if *time_arr[0] == time_ago {
-				StringRef::from(time_ago)
-					.0
-					.as_date("dd/MM/yy", Some("en_US"), Some("Asia/Ho_Chi_Minh"))
-					.unwrap_or(0.0)
-			} else {
-				let modified_time = format!(
-					"{} {}/{}",
-					time_arr[0],
-					time_arr[1],
-					1970 + (current_time / 31536000.0) as i32
-				);
-				StringRef::from(modified_time)
-					.0
-					.as_date("HH:mm dd/MM/yyyy", Some("en_US"), Some("Asia/Ho_Chi_Minh"))
-					.unwrap_or(0.0)
-			}
-		},
+		time_converter: convert_vi_time,
 
 		next_page: ".page_redirect > a:nth-last-child(2) > p:not(.active)",
 		manga_cell: "ul.grid li",
@@ -118,6 +99,11 @@ fn get_instance() -> WPComicsSource {
 	}
 }
 
+#[initialize]
+fn initialize() {
+	migrate_proxy_setting();
+}
+
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	fn get_search_url(filters: Vec<Filter>, page: i32) -> String {
@@ -135,10 +121,10 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 							.read(),
 					);
 					if !title.is_empty() {
-						return get_url_with_proxy(&format!(
+						return format!(
 							"{}/tim-kiem-nang-cao?keyword={title}&page={page}",
 							get_base_url().unwrap_or_default()
-						));
+						);
 					}
 				}
 				FilterType::Genre => {
@@ -198,13 +184,13 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 				},
 			}
 		}
-		get_url_with_proxy(&format!(
+		format!(
 			"{}/tim-kiem-nang-cao.html?category={}&notcategory={}{}",
 			get_base_url().unwrap_or_default(),
 			included_tags.join(","),
 			excluded_tags.join(","),
 			query
-		))
+		)
 	}
 	let cookie_str = format!("visit-read={}", get_visit_read_id().unwrap_or_default());
 	let headers = &[("Cookie", cookie_str.as_str())];
@@ -218,11 +204,7 @@ fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
 
 #[get_manga_details]
 fn get_manga_details(id: String) -> Result<Manga> {
-	get_instance().get_manga_details(get_url_with_proxy(&format!(
-		"{}/{}",
-		get_base_url().unwrap(),
-		id
-	)))
+	get_instance().get_manga_details(format!("{}/{}", get_base_url().unwrap(), id))
 }
 
 #[get_chapter_list]
@@ -233,7 +215,7 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 	//     let chapter_id = format!("fake-chap-{}", i);
 	//     chapters.push(Chapter {
 	//         id: chapter_id.clone(),
-	//         title: get_url_with_proxy(&id),
+	//         title: id.clone(),
 	//         volume: -1.0,
 	//         chapter: i as f32,
 	//         date_updated: 0.0,
@@ -243,13 +225,7 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 	//     });
 	// }
 
-	let mut req = Request::new(
-		&get_url_with_proxy(&format!("{}/{}", get_base_url().unwrap(), id)),
-		HttpMethod::Get,
-	);
-	req = req.header("User-Agent", USER_AGENT);
-
-	let html = req.html()?;
+	let html = get_instance().fetch_html(&format!("{}/{}", get_base_url().unwrap(), id), None)?;
 
 	// // =================== fork from template.rs ====================
 	let title_untrimmed = (get_instance().manga_details_title_transformer)(
@@ -280,46 +256,22 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 			.rsplit('/')
 			.next()
 			.unwrap();
-		let mut chapter_title = chapter_node.select("div.name-chap a").text().read();
-		let title_raw = chapter_title.clone();
-		let numbers = extract_f32_from_string(String::from(title), String::from(&chapter_title));
-		let (volume, chapter) =
-			if numbers.len() > 1 && chapter_title.to_ascii_lowercase().contains("vol") {
-				(numbers[0], numbers[1])
-			} else if !numbers.is_empty() {
-				(-1.0, numbers[0])
-			} else {
-				(-1.0, -1.0)
-			};
-		if chapter >= 0.0 {
-			let splitter = format!(" {}", chapter);
-			let splitter2 = format!("#{}", chapter);
-			if chapter_title.contains(&splitter) {
-				let split = chapter_title.splitn(2, &splitter).collect::<Vec<&str>>();
-				chapter_title =
-					String::from(split[1]).replacen(|char| char == ':' || char == '-', "", 1);
-			} else if chapter_title.contains(&splitter2) {
-				let split = chapter_title.splitn(2, &splitter2).collect::<Vec<&str>>();
-				chapter_title =
-					String::from(split[1]).replacen(|char| char == ':' || char == '-', "", 1);
-			}
-		}
+		let title_raw = chapter_node.select("div.name-chap a").text().read();
+		let parsed = parse_chapter(&title_raw, title);
 		let date_updated =
 			(get_instance().time_converter)(chapter_node.select("div.time-chap").text().read());
 
-		chapter_title = format!("{}", chapter_title.trim());
-
 		chapters.push(Chapter {
-			id: get_url_with_proxy(&chapter_id),
-			title: String::from(if chapter_title.is_empty() {
-				title_raw.trim()
+			id: String::from(chapter_id),
+			title: if parsed.title.is_empty() {
+				String::from(title_raw.trim())
 			} else {
-				&chapter_title
-			}),
-			volume,
-			chapter,
+				parsed.title
+			},
+			volume: parsed.volume,
+			chapter: parsed.chapter,
 			date_updated,
-			url: get_url_with_proxy(&chapter_url),
+			url: chapter_url,
 			lang: String::from("en"),
 			..Default::default()
 		});
@@ -332,18 +284,15 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 	let mut pages: Vec<Page> = Vec::new();
 
-	let mut req = Request::new(
-		&get_url_with_proxy(&format!(
+	let html = get_instance().fetch_html(
+		&format!(
 			"{}/{}/{}",
 			get_base_url().unwrap(),
 			manga_id,
-			chapter_id
-		)),
-		HttpMethod::Get,
-	);
-	req = req.header("User-Agent", USER_AGENT);
-
-	let html = req.html()?;
+			strip_proxy(&chapter_id)
+		),
+		None,
+	)?;
 
 	for (at, page) in html.select("div.page-chapter > img").array().enumerate() {
 		let page_node = page.as_node().expect("node array");
@@ -381,7 +330,7 @@ fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 		}
 		pages.push(Page {
 			index: at as i32,
-			url: page_url,
+			url: net::relay_image_url(page_url),
 			base64: String::new(),
 			text: String::new(),
 		});
@@ -397,5 +346,5 @@ fn modify_image_request(request: Request) {
 
 #[handle_url]
 fn handle_url(url: String) -> Result<DeepLink> {
-	get_instance().handle_url(get_url_with_proxy(&url))
+	get_instance().handle_url(url)
 }
@@ -10,9 +10,11 @@ use wpcomics_template::{
 	template::{self, WPComicsSource},
 };
 
+const BASE_URL: &str = "https://xoxocomic.com";
+
 fn get_instance() -> WPComicsSource {
 	WPComicsSource {
-		base_url: String::from("https://xoxocomic.com"),
+		base_url: String::from(BASE_URL),
 		listing_mapping: listing_map,
 
 		manga_details_title_transformer: trunc_trailing_comic,
@@ -42,8 +44,9 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 			},
 		}
 	}
-	get_instance().get_manga_list(
-		get_search_url(String::from("https://xoxocomic.com"), title, genre, page),
+	let instance = get_instance();
+	instance.get_manga_list(
+		get_search_url(instance.active_base_url(), title, genre, page),
 		None,
 	)
 }
@@ -71,7 +74,7 @@ fn get_page_list(_manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 #[modify_image_request]
 fn modify_image_request(request: Request) {
 	template::modify_image_request(
-		String::from("https://xoxocomic.com"),
+		get_instance().active_base_url(),
 		String::from("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/101.0.4951.54 Safari/537.36 Edg/101.0.1210.39"),
 		request,
 	)
@@ -1,6 +1,7 @@
-use aidoku::{
-	prelude::format, std::html::Node, std::String, std::Vec, MangaContentRating, MangaViewer,
-};
+use aidoku::{prelude::format, std::html::Node, std::String, std::Vec};
+use common::date::DateParser;
+
+pub use common::chapter::parse_chapter;
 
 pub fn trunc_trailing_comic(title: String) -> String {
 	let temp = title.chars().rev().collect::<String>();
@@ -15,19 +16,6 @@ pub fn trunc_trailing_comic(title: String) -> String {
 	}
 }
 
-pub fn extract_f32_from_string(title: String, text: String) -> Vec<f32> {
-	text.replace(&title, "")
-		.chars()
-		.filter(|a| (*a >= '0' && *a <= '9') || *a == ' ' || *a == '.' || *a == '+')
-		.collect::<String>()
-		.split(' ')
-		.collect::<Vec<&str>>()
-		.into_iter()
-		.map(|a| a.parse::<f32>().unwrap_or(-1.0))
-		.filter(|a| *a >= 0.0)
-		.collect::<Vec<f32>>()
-}
-
 pub fn append_protocol(url: String) -> String {
 	if !url.starts_with("http") {
 		format!("{}{}", "https:", url)
@@ -36,6 +24,28 @@ pub fn append_protocol(url: String) -> String {
 	}
 }
 
+/// Returns the `scheme://host` part of an absolute url, or an empty string.
+pub fn get_origin(url: &str) -> String {
+	let Some((scheme, rest)) = url.split_once("://") else {
+		return String::new();
+	};
+	let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+	if host.is_empty() {
+		return String::new();
+	}
+	format!("{scheme}://{host}")
+}
+
+/// Returns the path of an absolute url without the query and trailing slash.
+pub fn get_path(url: &str) -> String {
+	let origin = get_origin(url);
+	let path = url[origin.len()..]
+		.split(['?', '#'])
+		.next()
+		.unwrap_or_default();
+	String::from(path.trim_end_matches('/'))
+}
+
 pub fn urlencode(string: String) -> String {
 	let mut result: Vec<u8> = Vec::with_capacity(string.len() * 3);
 	let hex = "0123456789abcdef".as_bytes();
@@ -133,25 +143,8 @@ pub fn text_with_newlines(node: Node) -> String {
 	}
 }
 
-pub fn category_parser(
-	categories: &Vec<String>,
-	default_nsfw: MangaContentRating,
-	default_viewer: MangaViewer,
-) -> (MangaContentRating, MangaViewer) {
-	let mut nsfw = default_nsfw;
-	let mut viewer = default_viewer;
-	for category in categories {
-		match category.as_str() {
-			"Smut" | "Mature" | "18+" => nsfw = MangaContentRating::Nsfw,
-			"Ecchi" | "16+" => {
-				nsfw = match nsfw {
-					MangaContentRating::Nsfw => MangaContentRating::Nsfw,
-					_ => MangaContentRating::Suggestive,
-				}
-			}
-			"Webtoon" | "Manhwa" | "Manhua" => viewer = MangaViewer::Scroll,
-			_ => continue,
-		}
-	}
-	(nsfw, viewer)
+/// Parses the dates of Vietnamese sites, "3 giờ trước", "14:30 12/05" and
+/// "12/05/24" alike.
+pub fn convert_vi_time(time_ago: String) -> f64 {
+	DateParser::for_lang("vi").parse(&time_ago).unwrap_or(0.0)
 }
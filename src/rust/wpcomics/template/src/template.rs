@@ -1,19 +1,42 @@
 use aidoku::{
-	error::Result, prelude::*, std::html::Node, std::net::HttpMethod, std::net::Request,
-	std::String, std::StringRef, std::Vec, Chapter, DeepLink, Listing, Manga, MangaContentRating,
-	MangaPageResult, MangaStatus, MangaViewer, Page,
+	error::Result,
+	prelude::*,
+	std::defaults::{defaults_get, defaults_set},
+	std::html::Node,
+	std::json,
+	std::net::HttpMethod,
+	std::net::Request,
+	std::String,
+	std::StringRef,
+	std::Vec,
+	Chapter, DeepLink, Listing, Manga, MangaContentRating, MangaPageResult, MangaStatus,
+	MangaViewer, Page,
 };
 
-use crate::helper::{append_protocol, extract_f32_from_string, text_with_newlines};
+use common::{
+	chapter::parse_chapter, classify::Classifier, date::DateParser, error::SourceError, net,
+};
+
+pub use common::classify::Hint;
+
+use crate::helper::{append_protocol, get_origin, get_path, text_with_newlines};
 
 pub struct WPComicsSource {
+	/// Default domain of the source. The `baseURL` setting takes precedence
+	/// over it, see [`WPComicsSource::active_base_url`].
 	pub base_url: String,
+	/// Other domains of the same site, tried in order when the active one
+	/// fails. Users can add more through the `mirrors` setting.
+	pub mirrors: &'static [&'static str],
 	pub cookie: Option<&'static str>,
 	pub listing_mapping: fn(String) -> String,
 	pub status_mapping: fn(String) -> MangaStatus,
 	pub time_converter: fn(String) -> f64,
 	pub nsfw: MangaContentRating,
 	pub viewer: MangaViewer,
+	/// Tags of the site that mean something else than usual, checked before
+	/// the shared keywords of [`Classifier`].
+	pub classifier_overrides: &'static [(&'static str, Hint)],
 
 	pub next_page: &'static str,
 	pub manga_cell: &'static str,
@@ -44,6 +67,10 @@ pub struct WPComicsSource {
 	pub chapter_date_selector: &'static str,
 	pub chapter_anchor_selector: &'static str,
 	pub chapter_parse_id: fn(String) -> String,
+	/// Loads the chapter list through the `ComicService.asmx` endpoints of
+	/// NetTruyen-style sites, which the details page only shows part of for
+	/// long series. Falls back to the details page when they don't answer.
+	pub chapter_list_ajax: bool,
 
 	pub manga_viewer_page: &'static str,
 	pub manga_viewer_page_attr: &'static str,
@@ -53,28 +80,145 @@ pub struct WPComicsSource {
 	pub user_agent: Option<&'static str>,
 }
 
+/// Elements of the details page holding the id the AJAX endpoints expect.
+const AJAX_COMIC_ID_SELECTOR: &str = "input#item_id, input[name=comicId], [data-id].follow-link";
+/// Upper bound on the pages requested from `ProcessChapterList`, in case a
+/// site keeps answering with new rows.
+const AJAX_CHAPTER_PAGE_LIMIT: i32 = 200;
+
 static mut CACHED_MANGA_ID: Option<String> = None;
 static mut CACHED_MANGA: Option<Vec<u8>> = None;
 
-fn cache_manga_page(data: &WPComicsSource, url: &str) {
+fn cache_manga_page(data: &WPComicsSource, url: &str) -> Result<()> {
 	if unsafe { CACHED_MANGA_ID.is_some() } && unsafe { CACHED_MANGA_ID.clone().unwrap() } == url {
-		return;
+		return Ok(());
 	}
 
+	let page = data.fetch(url, None)?;
 	unsafe {
-		let mut req = Request::new(url, HttpMethod::Get);
-		if let Some(user_agent) = data.user_agent {
-			req = req.header("User-Agent", user_agent);
-		}
-		CACHED_MANGA = Some(req.data());
+		CACHED_MANGA = Some(page);
 		CACHED_MANGA_ID = Some(String::from(url));
 	};
+	Ok(())
+}
+
+fn read_setting(key: &str) -> String {
+	defaults_get(key)
+		.and_then(|v| v.as_string().map(|v| v.read()))
+		.map(|v| String::from(v.trim().trim_end_matches('/')))
+		.unwrap_or_default()
 }
 
 impl WPComicsSource {
+	/// The domain requests go to: the `baseURL` setting if it is set,
+	/// otherwise [`WPComicsSource::base_url`].
+	pub fn active_base_url(&self) -> String {
+		let base_url = read_setting("baseURL");
+		if base_url.starts_with("http") {
+			base_url
+		} else {
+			String::from(self.base_url.trim_end_matches('/'))
+		}
+	}
+
+	/// Every domain known to belong to the site, the active one first.
+	fn known_base_urls(&self) -> Vec<String> {
+		let mut urls: Vec<String> = Vec::new();
+		let mut push = |url: &str| {
+			let url = String::from(url.trim().trim_end_matches('/'));
+			if url.starts_with("http") && !urls.contains(&url) {
+				urls.push(url);
+			}
+		};
+		push(&self.active_base_url());
+		push(&self.base_url);
+		for mirror in self.mirrors {
+			push(mirror);
+		}
+		for mirror in read_setting("mirrors").split([',', '\n']) {
+			push(mirror);
+		}
+		urls
+	}
+
+	/// Moves a url on one of the site's domains to the active one, so ids and
+	/// urls stored with an old domain keep working. Other urls, such as
+	/// proxied ones, are left untouched.
+	pub fn rewrite_url(&self, url: &str) -> String {
+		let origin = get_origin(url);
+		let known = self.known_base_urls();
+		if origin.is_empty() || !known.iter().any(|base| get_origin(base) == origin) {
+			return String::from(url);
+		}
+		format!("{}{}", get_origin(&known[0]), &url[origin.len()..])
+	}
+
+	/// Stores the domain that answered as the active one. A `baseURL` set by
+	/// the user is never replaced by the built-in default domain.
+	fn adopt_base_url(&self, base_url: &str) {
+		if base_url == self.active_base_url() {
+			return;
+		}
+		let user_set = read_setting("baseURL").starts_with("http");
+		if user_set && base_url == get_origin(self.base_url) {
+			return;
+		}
+		defaults_set("baseURL", StringRef::from(base_url).0);
+	}
+
+	/// Loads a page from the active domain. Only when the domain can't be
+	/// reached or redirects elsewhere are the mirrors tried, in order; any
+	/// other answer, such as a 404, is returned as is. The domain that
+	/// answered becomes the active one, and so does the domain the site
+	/// redirected to, detected through the canonical url of the page. Fails
+	/// with [`SourceError::Unreachable`] when no domain answers.
+	pub fn fetch(&self, url: &str, headers: Option<&[(&str, &str)]>) -> Result<Vec<u8>> {
+		let url = self.rewrite_url(url);
+		let origin = get_origin(&url);
+		let known = self.known_base_urls();
+		let is_known = !origin.is_empty() && known.iter().any(|base| get_origin(base) == origin);
+
+		let mut candidates: Vec<String> = Vec::new();
+		candidates.push(url.clone());
+		if is_known {
+			for base_url in known.iter().skip(1) {
+				candidates.push(format!("{}{}", get_origin(base_url), &url[origin.len()..]));
+			}
+		}
+
+		for candidate in candidates.iter() {
+			// a challenge page is an error rather than a dead mirror
+			let (status, data) = net::send_checked(self.create_request(candidate, headers))?;
+			if status <= 0 || (300..400).contains(&status) {
+				continue;
+			}
+			if is_known && (200..300).contains(&status) {
+				let mut adopted = get_origin(candidate);
+				if let Ok(html) = Node::new(&data) {
+					let canonical = html.select("link[rel=canonical]").attr("href").read();
+					let canonical_origin = get_origin(&canonical);
+					if !canonical_origin.is_empty()
+						&& canonical_origin != adopted
+						&& get_path(&canonical) == get_path(candidate)
+					{
+						adopted = canonical_origin;
+					}
+				}
+				self.adopt_base_url(&adopted);
+			}
+			return Ok(data);
+		}
+
+		Err(SourceError::Unreachable.into())
+	}
+
+	pub fn fetch_html(&self, url: &str, headers: Option<&[(&str, &str)]>) -> Result<Node> {
+		Node::new(&self.fetch(url, headers)?)
+	}
+
 	pub fn create_request(&self, url: &str, headers: Option<&[(&str, &str)]>) -> Request {
 		// 通常のリクエスト
-		let mut req = Request::new(url, HttpMethod::Get);
+		let mut req = net::request(url, HttpMethod::Get);
 		if let Some(cookie) = &self.cookie {
 			req = req.header("Cookie", &cookie);
 		}
@@ -90,34 +234,11 @@ impl WPComicsSource {
 	}
 
 	fn category_parser(&self, categories: &Vec<String>) -> (MangaContentRating, MangaViewer) {
-		#[allow(clippy::needless_match)]
-		let mut nsfw = match self.nsfw {
-			MangaContentRating::Safe => MangaContentRating::Safe,
-			MangaContentRating::Suggestive => MangaContentRating::Suggestive,
-			MangaContentRating::Nsfw => MangaContentRating::Nsfw,
-		};
-		#[allow(clippy::needless_match)]
-		let mut viewer = match self.viewer {
-			MangaViewer::Rtl => MangaViewer::Rtl,
-			MangaViewer::Ltr => MangaViewer::Ltr,
-			MangaViewer::Scroll => MangaViewer::Scroll,
-			MangaViewer::Vertical => MangaViewer::Vertical,
-			_ => MangaViewer::Rtl,
-		};
-		for category in categories {
-			match category.as_str() {
-				"Smut" | "Mature" | "18+" => nsfw = MangaContentRating::Nsfw,
-				"Ecchi" | "16+" => {
-					nsfw = match nsfw {
-						MangaContentRating::Nsfw => MangaContentRating::Nsfw,
-						_ => MangaContentRating::Suggestive,
-					}
-				}
-				"Webtoon" | "Manhwa" | "Manhua" => viewer = MangaViewer::Scroll,
-				_ => continue,
-			}
-		}
-		(nsfw, viewer)
+		Classifier::new("")
+			.with_overrides(self.classifier_overrides)
+			.with_viewer(Hint::from_viewer(&self.viewer))
+			.with_rating(Hint::from_rating(&self.nsfw))
+			.classify(categories, "", "")
 	}
 	pub fn get_manga_list(
 		&self,
@@ -126,7 +247,8 @@ impl WPComicsSource {
 	) -> Result<MangaPageResult> {
 		let mut has_next_page = !self.next_page.is_empty();
 
-		let html = self.create_request(&search_url, headers).html()?;
+		let html = self.fetch_html(&search_url, headers)?;
+		let base_url = self.active_base_url();
 
 		let node = html.select(self.manga_cell);
 		let elems = node.array();
@@ -146,7 +268,7 @@ impl WPComicsSource {
 			if !url.contains("http://") && !url.contains("https://") {
 				url = format!(
 					"{}{}{url}",
-					self.base_url,
+					base_url,
 					if url.starts_with("/") { "" } else { "/" }
 				);
 			}
@@ -180,7 +302,7 @@ impl WPComicsSource {
 	pub fn get_manga_listing(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
 		let url = format!(
 			"{}/{}{}{page}{}",
-			self.base_url,
+			self.active_base_url(),
 			(self.listing_mapping)(listing.name),
 			self.manga_listing_pagination,
 			self.manga_listing_extension
@@ -189,7 +311,8 @@ impl WPComicsSource {
 	}
 
 	pub fn get_manga_details(&self, url: String) -> Result<Manga> {
-		cache_manga_page(self, url.as_str());
+		let url = self.rewrite_url(&url);
+		cache_manga_page(self, url.as_str())?;
 		let details = unsafe { Node::new(&CACHED_MANGA.clone().unwrap())? };
 		let title = details.select(self.manga_details_title).text().read();
 		let mut cover = details
@@ -198,7 +321,7 @@ impl WPComicsSource {
 			.read();
 
 		if cover.starts_with("/") {
-			cover = format!("{}{}", self.base_url, cover).replace("//", "/");
+			cover = format!("{}{}", self.active_base_url(), cover).replace("//", "/");
 		}
 
 		let cover = append_protocol(cover);
@@ -246,89 +369,241 @@ impl WPComicsSource {
 		})
 	}
 
-	pub fn get_chapter_list(&self, id: String) -> Result<Vec<Chapter>> {
-		let mut skipped_first = false;
+	fn absolute_url(&self, url: String) -> String {
+		if url.contains("http://") || url.contains("https://") {
+			return url;
+		}
+		format!(
+			"{}{}{}",
+			self.active_base_url(),
+			if url.starts_with("/") { "" } else { "/" },
+			url
+		)
+	}
+
+	fn parse_chapter(
+		&self,
+		manga_title: &str,
+		chapter_url: String,
+		chapter_title: String,
+		date_updated: f64,
+	) -> Chapter {
+		let chapter_id = (self.chapter_parse_id)(chapter_url.clone());
+		let parsed = parse_chapter(&chapter_title, manga_title);
+		Chapter {
+			id: chapter_id,
+			title: parsed.title,
+			volume: parsed.volume,
+			chapter: parsed.chapter,
+			date_updated,
+			url: chapter_url,
+			lang: String::from("en"),
+			..Default::default()
+		}
+	}
+
+	fn parse_chapter_nodes(
+		&self,
+		manga_title: &str,
+		nodes: Node,
+		skip_first: bool,
+	) -> Vec<Chapter> {
 		let mut chapters: Vec<Chapter> = Vec::new();
-		cache_manga_page(self, id.as_str());
-		let html = unsafe { Node::new(&CACHED_MANGA.clone().unwrap())? };
-		let title_untrimmed = (self.manga_details_title_transformer)(
-			html.select(self.manga_details_title).text().read(),
-		);
-		let title = title_untrimmed.trim();
-		for chapter in html.select(self.manga_details_chapters).array() {
-			if self.chapter_skip_first && !skipped_first {
-				skipped_first = true;
-				continue;
-			}
+		for chapter in nodes.array().skip(if skip_first { 1 } else { 0 }) {
 			let chapter_node = chapter.as_node().expect("node array");
-			let mut chapter_url = chapter_node
-				.select(self.chapter_anchor_selector)
-				.attr("href")
-				.read();
-			if !chapter_url.contains("http://") && !chapter_url.contains("https://") {
-				chapter_url = format!(
-					"{}{}{}",
-					self.base_url,
-					if chapter_url.starts_with("/") {
-						""
-					} else {
-						"/"
-					},
-					chapter_url
-				);
-			}
-			let chapter_id = (self.chapter_parse_id)(chapter_url.clone());
-			let mut chapter_title = chapter_node
+			let chapter_url = self.absolute_url(
+				chapter_node
+					.select(self.chapter_anchor_selector)
+					.attr("href")
+					.read(),
+			);
+			let chapter_title = chapter_node
 				.select(self.chapter_anchor_selector)
 				.text()
 				.read();
-			let numbers =
-				extract_f32_from_string(String::from(title), String::from(&chapter_title));
-			let (volume, chapter) =
-				if numbers.len() > 1 && chapter_title.to_ascii_lowercase().contains("vol") {
-					(numbers[0], numbers[1])
-				} else if !numbers.is_empty() {
-					(-1.0, numbers[0])
-				} else {
-					(-1.0, -1.0)
-				};
-			if chapter >= 0.0 {
-				let splitter = format!(" {}", chapter);
-				let splitter2 = format!("#{}", chapter);
-				if chapter_title.contains(&splitter) {
-					let split = chapter_title.splitn(2, &splitter).collect::<Vec<&str>>();
-					chapter_title =
-						String::from(split[1]).replacen(|char| char == ':' || char == '-', "", 1);
-				} else if chapter_title.contains(&splitter2) {
-					let split = chapter_title.splitn(2, &splitter2).collect::<Vec<&str>>();
-					chapter_title =
-						String::from(split[1]).replacen(|char| char == ':' || char == '-', "", 1);
-				}
-			}
 			let date_updated = (self.time_converter)(
 				chapter_node
 					.select(self.chapter_date_selector)
 					.text()
 					.read(),
 			);
-			chapters.push(Chapter {
-				id: chapter_id,
-				title: String::from(chapter_title.trim()),
-				volume,
-				chapter,
+			chapters.push(self.parse_chapter(
+				manga_title,
+				chapter_url,
+				chapter_title,
 				date_updated,
-				url: chapter_url,
-				lang: String::from("en"),
-				..Default::default()
-			});
+			));
+		}
+		chapters
+	}
+
+	/// Converts the dates of the AJAX chapter list, which are either in the
+	/// format of the details page or ISO 8601.
+	fn convert_ajax_date(&self, date: String) -> f64 {
+		if date.contains('T') {
+			let date = date.split('.').next().unwrap_or_default();
+			return StringRef::from(date)
+				.0
+				.as_date("yyyy-MM-dd'T'HH:mm:ss", None, None)
+				.unwrap_or(0.0);
+		}
+		(self.time_converter)(date)
+	}
+
+	/// Loads the whole list from the `ChapterList` endpoint, which answers with
+	/// every chapter of the series as JSON.
+	fn get_ajax_chapter_list(
+		&self,
+		manga_url: &str,
+		manga_title: &str,
+		comic_id: &str,
+	) -> Result<Vec<Chapter>> {
+		let slug = get_path(manga_url);
+		let slug = slug.rsplit('/').next().unwrap_or_default();
+		let url = format!(
+			"{}/Comic/Services/ComicService.asmx/ChapterList?slug={}&comicId={}",
+			self.active_base_url(),
+			slug,
+			comic_id
+		);
+		let headers = [
+			("X-Requested-With", "XMLHttpRequest"),
+			("Referer", manga_url),
+		];
+		let data = self.fetch(&url, Some(&headers))?;
+		let json = json::parse(data.as_slice())?.as_object()?;
+		let mut chapters: Vec<Chapter> = Vec::new();
+		for item in json.get("data").as_array()? {
+			let Ok(item) = item.as_object() else {
+				continue;
+			};
+			let chapter_slug = item
+				.get("chapter_slug")
+				.as_string()
+				.map(|v| v.read())
+				.unwrap_or_default();
+			if chapter_slug.is_empty() {
+				continue;
+			}
+			let chapter_url = format!("{}/{}", self.rewrite_url(manga_url), chapter_slug);
+			let chapter_title = item
+				.get("chapter_name")
+				.as_string()
+				.map(|v| v.read())
+				.unwrap_or_default();
+			let date_updated = self.convert_ajax_date(
+				item.get("updated_at")
+					.as_string()
+					.map(|v| v.read())
+					.unwrap_or_default(),
+			);
+			chapters.push(self.parse_chapter(
+				manga_title,
+				chapter_url,
+				chapter_title,
+				date_updated,
+			));
 		}
 		Ok(chapters)
 	}
 
+	/// Pages through the `ProcessChapterList` endpoint, which answers with the
+	/// same rows as the details page, one page of the list at a time.
+	fn get_paged_chapter_list(
+		&self,
+		manga_url: &str,
+		manga_title: &str,
+		comic_id: &str,
+	) -> Result<Vec<Chapter>> {
+		let headers = [
+			("X-Requested-With", "XMLHttpRequest"),
+			("Referer", manga_url),
+		];
+		let mut chapters: Vec<Chapter> = Vec::new();
+		for page in 1..=AJAX_CHAPTER_PAGE_LIMIT {
+			let url = format!(
+				"{}/Comic/Services/ComicService.asmx/ProcessChapterList?comicId={}&currentPage={}",
+				self.active_base_url(),
+				comic_id,
+				page
+			);
+			let data = self.fetch(&url, Some(&headers))?;
+			// the rows come either as is or wrapped in a JSON object
+			let fragment = match json::parse(data.as_slice()).and_then(|v| v.as_object()) {
+				Ok(object) => ["chapters", "response", "data"]
+					.iter()
+					.find_map(|key| object.get(key).as_string().ok())
+					.map(|v| v.read())
+					.unwrap_or_default(),
+				Err(_) => String::from_utf8_lossy(&data).into_owned(),
+			};
+			let html = Node::new_fragment(fragment.as_bytes())?;
+			let found = self.parse_chapter_nodes(manga_title, html.select("li"), false);
+			let count = chapters.len();
+			for chapter in found {
+				if !chapters.iter().any(|c| c.id == chapter.id) {
+					chapters.push(chapter);
+				}
+			}
+			// past the last page the endpoint repeats it or returns nothing
+			if chapters.len() == count {
+				break;
+			}
+		}
+		Ok(chapters)
+	}
+
+	pub fn get_chapter_list(&self, id: String) -> Result<Vec<Chapter>> {
+		let id = self.rewrite_url(&id);
+		cache_manga_page(self, id.as_str())?;
+		let html = unsafe { Node::new(&CACHED_MANGA.clone().unwrap())? };
+		let title_untrimmed = (self.manga_details_title_transformer)(
+			html.select(self.manga_details_title).text().read(),
+		);
+		let title = title_untrimmed.trim();
+		if self.chapter_list_ajax {
+			let comic_id = html.select(AJAX_COMIC_ID_SELECTOR).first();
+			let comic_id = [
+				comic_id.attr("value").read(),
+				comic_id.attr("data-id").read(),
+			]
+			.into_iter()
+			.find(|v| !v.is_empty())
+			.unwrap_or_default();
+			if !comic_id.is_empty() {
+				if let Ok(chapters) = self.get_ajax_chapter_list(&id, title, &comic_id) {
+					if !chapters.is_empty() {
+						return Ok(chapters);
+					}
+				}
+				if let Ok(chapters) = self.get_paged_chapter_list(&id, title, &comic_id) {
+					if !chapters.is_empty() {
+						return Ok(chapters);
+					}
+				}
+			}
+		}
+		Ok(self.parse_chapter_nodes(
+			title,
+			html.select(self.manga_details_chapters),
+			self.chapter_skip_first,
+		))
+	}
+
+	/// The pages of a chapter, with a failure the user can act on shown as a
+	/// text page, see [`common::error::pages_or_notice`].
 	pub fn get_page_list(&self, chapter_id: String) -> Result<Vec<Page>> {
+		common::error::pages_or_notice(self.fetch_page_list(chapter_id))
+	}
+
+	fn fetch_page_list(&self, chapter_id: String) -> Result<Vec<Page>> {
 		let mut pages: Vec<Page> = Vec::new();
-		let url = format!("{}{}", &chapter_id, self.manga_viewer_page_url_suffix);
-		let html = self.create_request(&url, None).html()?;
+		let url = format!(
+			"{}{}",
+			self.rewrite_url(&chapter_id),
+			self.manga_viewer_page_url_suffix
+		);
+		let html = self.fetch_html(&url, None)?;
 		for (at, page) in html.select(self.manga_viewer_page).array().enumerate() {
 			let page_node = page.as_node().expect("node array");
 			let mut page_url = page_node.attr(self.manga_viewer_page_attr).read();
@@ -337,7 +612,7 @@ impl WPComicsSource {
 			}
 			pages.push(Page {
 				index: at as i32,
-				url: (self.page_url_transformer)(page_url),
+				url: net::relay_image_url((self.page_url_transformer)(page_url)),
 				base64: String::new(),
 				text: String::new(),
 			});
@@ -346,7 +621,8 @@ impl WPComicsSource {
 	}
 
 	pub fn handle_url(&self, url: String) -> Result<DeepLink> {
-		cache_manga_page(self, url.as_str());
+		let url = self.rewrite_url(&url);
+		cache_manga_page(self, url.as_str())?;
 		let html = unsafe { Node::new(CACHED_MANGA.clone().unwrap())? };
 		if html.select(self.manga_viewer_page).array().is_empty() {
 			let node = html.select(".breadcrumb li");
@@ -361,7 +637,7 @@ impl WPComicsSource {
 			if !manga_id.contains("http://") && !manga_id.contains("https://") {
 				manga_id = format!(
 					"{}{}{}",
-					self.base_url,
+					self.active_base_url(),
 					if manga_id.starts_with("/") { "" } else { "/" },
 					manga_id
 				);
@@ -386,7 +662,7 @@ impl WPComicsSource {
 		if let Some(user_agent) = self.user_agent {
 			request = request.header("User-Agent", user_agent);
 		}
-		request.header("Referer", &format!("{}/", self.base_url));
+		request.header("Referer", &format!("{}/", self.active_base_url()));
 	}
 }
 
@@ -394,6 +670,7 @@ impl Default for WPComicsSource {
 	fn default() -> WPComicsSource {
 		WPComicsSource {
 			base_url: String::new(),
+			mirrors: &[],
 			cookie: None,
 			listing_mapping: |str| str,
 			status_mapping: |status| match status.as_str() {
@@ -404,13 +681,13 @@ impl Default for WPComicsSource {
 				_ => MangaStatus::Unknown,
 			},
 			time_converter: |str| {
-				StringRef::from(str)
-					.0
-					.as_date("MM/dd/yyyy", Some("en_US"), None)
+				DateParser::new(&["MM/dd/yyyy"], Some("en_US"))
+					.parse(&str)
 					.unwrap_or(0.0)
 			},
 			nsfw: MangaContentRating::Safe,
 			viewer: MangaViewer::Ltr,
+			classifier_overrides: &[],
 
 			next_page: "li > a[rel=next]",
 			manga_cell: "div.items > div.row > div.item > figure.clearfix",
@@ -440,6 +717,7 @@ impl Default for WPComicsSource {
 			chapter_anchor_selector: "div.chapter > a",
 			chapter_date_selector: "div.col-xs-4",
 			chapter_parse_id: |url| url,
+			chapter_list_ajax: false,
 
 			manga_viewer_page: "div.page-chapter > img",
 			manga_viewer_page_attr: "data-original",
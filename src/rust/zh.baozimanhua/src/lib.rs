@@ -15,10 +15,17 @@ use aidoku::{
 use chinese_number::{ChineseCountMethod, ChineseToNumber};
 use parser::{Artists, DivComicsCard};
 use regex::Regex;
-use url::Url;
+use url::{converter, Url};
 
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	let mut result = fetch_manga_list(filters, page)?;
+	converter().manga_page(&mut result);
+
+	Ok(result)
+}
+
+fn fetch_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	let manga_list_url = Url::from((filters, page));
 	if let Url::Filters(_) = manga_list_url {
 		let filters_obj = manga_list_url.get().json()?.as_object()?;
@@ -86,7 +93,14 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 }
 
 #[get_manga_listing]
-fn get_manga_listing(listing: Listing, _: i32) -> Result<MangaPageResult> {
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	let mut result = fetch_manga_listing(listing, page)?;
+	converter().manga_page(&mut result);
+
+	Ok(result)
+}
+
+fn fetch_manga_listing(listing: Listing, _: i32) -> Result<MangaPageResult> {
 	if listing.name == "最新上架" {
 		let manga = Url::New.get().html()?.get_manga_list()?;
 
@@ -184,7 +198,7 @@ fn get_manga_details(id: String) -> Result<Manga> {
 		_ => MangaStatus::Unknown,
 	};
 
-	Ok(Manga {
+	let mut manga = Manga {
 		id,
 		cover,
 		title,
@@ -195,11 +209,21 @@ fn get_manga_details(id: String) -> Result<Manga> {
 		categories,
 		status,
 		..Default::default()
-	})
+	};
+	converter().manga(&mut manga);
+
+	Ok(manga)
 }
 
 #[get_chapter_list]
 fn get_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
+	let mut chapters = fetch_chapter_list(manga_id)?;
+	converter().chapters(&mut chapters);
+
+	Ok(chapters)
+}
+
+fn fetch_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
 	let get_res_chapter = |value: ValueRef| {
 		let a = value.as_node()?;
 		let url = a.attr("abs:href").read();
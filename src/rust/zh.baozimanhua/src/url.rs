@@ -6,8 +6,22 @@ use aidoku::{
 	Filter, FilterType,
 };
 use alloc::string::ToString;
+use common::zh::{Converter, Script};
 use core::fmt::Display;
 
+/// The `cn` subdomain is stuck behind Cloudflare, so Simplified Chinese is
+/// produced by converting what the `www` one serves.
+pub fn converter() -> Converter {
+	let charset_index = defaults_get("charset")
+		.and_then(|value| value.as_int())
+		.unwrap_or(1);
+
+	Converter {
+		site: Script::Traditional,
+		target: (charset_index == 0).then_some(Script::Simplified),
+	}
+}
+
 pub enum Url<'a> {
 	/// https://{subdomain}.baozimh.com
 	///
@@ -229,7 +243,7 @@ impl From<(Vec<Filter>, i32)> for Url<'_> {
 					};
 
 					let mut search_query = QueryParameters::new();
-					search_query.push("q", Some(&search_str));
+					search_query.push("q", Some(&converter().query(&search_str)));
 
 					return Url::Search(search_query);
 				}
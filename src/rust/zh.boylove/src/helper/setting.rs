@@ -1,8 +1,9 @@
 use super::url::{Charset, Url};
-use aidoku::std::defaults::defaults_get;
+use aidoku::std::{defaults::defaults_get, String};
+use common::zh::{Converter, Script};
 
-pub fn change_charset() {
-	let charset = defaults_get("isTC")
+fn get_charset() -> Charset {
+	defaults_get("isTC")
 		.and_then(|value| {
 			if value.as_bool()? {
 				return Ok(Charset::Traditional);
@@ -10,7 +11,22 @@ pub fn change_charset() {
 
 			Ok(Charset::Simplified)
 		})
-		.unwrap_or_default();
+		.unwrap_or_default()
+}
+
+pub fn change_charset() {
+	let charset = get_charset();
 
 	Url::Charset { charset }.get().send();
 }
+
+/// Converts a search keyword to the charset the site is set to, which is the
+/// only one its search matches.
+pub fn normalize_keyword(keyword: &str) -> String {
+	let site = match get_charset() {
+		Charset::Simplified => Script::Simplified,
+		Charset::Traditional => Script::Traditional,
+	};
+
+	Converter { site, target: None }.query(keyword)
+}
@@ -1,3 +1,4 @@
+use super::setting::normalize_keyword;
 use aidoku::{
 	helpers::uri::{encode_uri_component, QueryParameters},
 	prelude::format,
@@ -116,7 +117,7 @@ impl From<(Vec<Filter>, i32)> for Url<'_> {
 
 				FilterType::Title => {
 					let keyword = match filter.value.as_string() {
-						Ok(str_ref) => str_ref.read(),
+						Ok(str_ref) => normalize_keyword(&str_ref.read()),
 						Err(_) => continue,
 					};
 					let query = SearchQuery { keyword, page };
@@ -0,0 +1,146 @@
+use crate::{
+	helper::to_aidoku_error,
+	parser::{ApiResponse as _, BookshelfResponse as _, JsonObj as _},
+};
+use aidoku::{
+	error::Result,
+	helpers::uri::QueryParameters,
+	prelude::format,
+	std::{
+		current_date,
+		defaults::{defaults_get, defaults_set},
+		net::{HttpMethod, Request},
+		String, StringRef,
+	},
+	MangaPageResult,
+};
+use alloc::string::ToString as _;
+use base64ct::{Base64, Encoding as _};
+
+const API_URL: &str = "https://api.mangacopy.com";
+
+/// The app version sent along with the login, which the API checks.
+const APP_VERSION: &str = "2.2.0";
+
+const USERNAME_KEY: &str = "username";
+const PASSWORD_KEY: &str = "password";
+const TOKEN_KEY: &str = "token";
+/// Set when the last login failed, until the next one.
+const LOGIN_FAILED_KEY: &str = "loginFailed";
+
+/// The number of manga that a single bookshelf response contains.
+const LIMIT: i32 = 20;
+
+fn read_setting(key: &str) -> String {
+	defaults_get(key)
+		.and_then(|value| value.as_string())
+		.map(|str_ref| str_ref.read().trim().into())
+		.unwrap_or_default()
+}
+
+fn set_token(token: &str) {
+	defaults_set(TOKEN_KEY, StringRef::from(token).0);
+}
+
+pub fn get_token() -> Option<String> {
+	let token = read_setting(TOKEN_KEY);
+
+	(!token.is_empty()).then_some(token)
+}
+
+fn set_login_failed(failed: bool) {
+	defaults_set(
+		LOGIN_FAILED_KEY,
+		StringRef::from(if failed { "1" } else { "" }).0,
+	);
+}
+
+/// Logs in with the username and password from the settings and keeps the
+/// token. Clearing either of them logs out.
+///
+/// A failed login keeps the previous token, if any, and is reported by
+/// [`get_bookshelf`].
+pub fn login() {
+	let username = read_setting(USERNAME_KEY);
+	let password = read_setting(PASSWORD_KEY);
+	if username.is_empty() || password.is_empty() {
+		set_token("");
+		set_login_failed(false);
+		return;
+	}
+
+	match request_token(&username, &password) {
+		Ok(token) => {
+			set_token(&token);
+			set_login_failed(false);
+		}
+		Err(_) => set_login_failed(true),
+	}
+}
+
+fn request_token(username: &str, password: &str) -> Result<String> {
+	// the app sends the password salted and base64-encoded
+	let salt = (current_date() * 1000.0) as u64 % 9000 + 1000;
+	let encoded_password = Base64::encode_string(format!("{password}-{salt}").as_bytes());
+
+	let mut body = QueryParameters::new();
+	body.push("username", Some(username));
+	body.push("password", Some(&encoded_password));
+	body.push_encoded("salt", Some(&salt.to_string()));
+	body.push_encoded("platform", Some("3"));
+	body.push_encoded("authorization", Some("Token+"));
+	body.push_encoded("version", Some(APP_VERSION));
+	body.push_encoded("source", Some("copyApp"));
+	let body = body.to_string();
+
+	Request::new(format!("{API_URL}/api/v3/login"), HttpMethod::Post)
+		.header(
+			"Content-Type",
+			"application/x-www-form-urlencoded;charset=utf-8",
+		)
+		.header("platform", "3")
+		.body(body.as_bytes())
+		.json()?
+		.get_results()?
+		.get_as_string("token")
+}
+
+pub trait Authorized {
+	/// Adds the token of the logged-in account, which unlocks the chapters
+	/// reserved for members.
+	fn authorized(self) -> Self;
+}
+
+impl Authorized for Request {
+	fn authorized(self) -> Self {
+		match get_token() {
+			Some(token) => self
+				.header("Authorization", &format!("Token {token}"))
+				.header("Cookie", &format!("token={token}")),
+			None => self,
+		}
+	}
+}
+
+/// The manga collected by the logged-in account, most recently updated first.
+pub fn get_bookshelf(page: i32) -> Result<MangaPageResult> {
+	if !read_setting(LOGIN_FAILED_KEY).is_empty() {
+		return Err(to_aidoku_error("登入失敗，請檢查設定中的帳號和密碼"));
+	}
+	if get_token().is_none() {
+		return Err(to_aidoku_error("請先在設定中登入帳號"));
+	}
+
+	let mut query = QueryParameters::new();
+	let offset = page.checked_sub(1).unwrap_or(0).saturating_mul(LIMIT);
+	query.push_encoded("offset", Some(&offset.to_string()));
+	query.push_encoded("limit", Some(&LIMIT.to_string()));
+	query.push_encoded("free_type", Some("1"));
+	query.push_encoded("ordering", Some("-datetime_updated"));
+
+	Request::get(format!("{API_URL}/api/v3/member/collect/comics?{query}"))
+		.header("platform", "3")
+		.authorized()
+		.json()?
+		.get_bookshelf_page()
+}
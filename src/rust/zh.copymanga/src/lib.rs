@@ -1,5 +1,6 @@
 #![no_std]
 extern crate alloc;
+mod account;
 mod decryptor;
 mod helper;
 mod parser;
@@ -8,28 +9,46 @@ mod url;
 use aidoku::{
 	error::Result,
 	prelude::{
-		format, get_chapter_list, get_manga_details, get_manga_list, get_page_list, handle_url,
+		format, get_chapter_list, get_manga_details, get_manga_list, get_manga_listing,
+		get_page_list, handle_notification, handle_url,
 	},
 	std::{defaults::defaults_get, String, Vec},
-	Chapter, DeepLink, Filter, Manga, MangaPageResult, MangaStatus, Page,
+	Chapter, DeepLink, Filter, Listing, Manga, MangaPageResult, MangaStatus, Page,
 };
 use alloc::string::ToString;
+use common::zh::{Converter, Script};
 use decryptor::EncryptedString;
 use parser::{Element, JsonObj, JsonString, MangaListResponse, NodeArrValue, Part, UuidString};
 use url::Url;
 use uuid::Uuid;
 
+/// The site shows everything in Traditional Chinese.
+const SITE_SCRIPT: Script = Script::Traditional;
+
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	let manga_list_url = Url::from((filters, page));
 
-	if let Url::Filters { .. } = manga_list_url {
-		let filters_page = manga_list_url.get_html()?;
-		return filters_page.get_page_result();
-	}
+	let mut result = if let Url::Filters { .. } = manga_list_url {
+		manga_list_url.get_html()?.get_page_result()?
+	} else {
+		manga_list_url.get_json()?.get_page_result()?
+	};
+	Converter::new(SITE_SCRIPT).manga_page(&mut result);
+
+	Ok(result)
+}
+
+#[expect(clippy::needless_pass_by_value)]
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	let mut result = match listing.name.as_str() {
+		"我的書架" => account::get_bookshelf(page)?,
+		_ => return Ok(MangaPageResult::default()),
+	};
+	Converter::new(SITE_SCRIPT).manga_page(&mut result);
 
-	let search_json = manga_list_url.get_json()?;
-	search_json.get_page_result()
+	Ok(result)
 }
 
 #[get_manga_details]
@@ -67,7 +86,7 @@ fn get_manga_details(manga_id: String) -> Result<Manga> {
 		_ => MangaStatus::Unknown,
 	};
 
-	Ok(Manga {
+	let mut manga = Manga {
 		id: manga_id,
 		cover,
 		title,
@@ -78,7 +97,10 @@ fn get_manga_details(manga_id: String) -> Result<Manga> {
 		categories,
 		status,
 		..Default::default()
-	})
+	};
+	Converter::new(SITE_SCRIPT).manga(&mut manga);
+
+	Ok(manga)
 }
 
 #[get_chapter_list]
@@ -136,7 +158,7 @@ fn get_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
 		}
 	}
 
-	let chapters = sorted_chapters
+	let mut chapters = sorted_chapters
 		.iter()
 		.map(|(chapter_id, title, res_date_updated)| {
 			let part = title.parse::<Part>()?;
@@ -161,7 +183,8 @@ fn get_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
 			})
 		})
 		.rev()
-		.collect::<Result<_>>()?;
+		.collect::<Result<Vec<_>>>()?;
+	Converter::new(SITE_SCRIPT).chapters(&mut chapters);
 
 	Ok(chapters)
 }
@@ -170,15 +193,23 @@ fn get_chapter_list(manga_id: String) -> Result<Vec<Chapter>> {
 fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 	let mut pages = Vec::<Page>::new();
 
-	let page_arr = Url::Chapter {
+	let content_key = Url::Chapter {
 		manga_id: &manga_id,
 		chapter_id: &chapter_id,
 	}
 	.get_html()?
-	.get_attr("div.imageData", "contentkey")
-	.decrypt()?
-	.json()?
-	.as_array()?;
+	.get_attr("div.imageData", "contentkey");
+	if content_key.is_empty() {
+		// chapters reserved for members come without images
+		let reason = if account::get_token().is_some() {
+			"無法取得章節圖片"
+		} else {
+			"此章節需要登入帳號"
+		};
+		return Err(helper::to_aidoku_error(reason));
+	}
+
+	let page_arr = content_key.decrypt()?.json()?.as_array()?;
 
 	let image_format = defaults_get("imageFormat").and_then(|v| v.as_string().map(|v| v.read()))?;
 
@@ -203,6 +234,13 @@ fn get_page_list(manga_id: String, chapter_id: String) -> Result<Vec<Page>> {
 	Ok(pages)
 }
 
+#[handle_notification]
+fn handle_notification(notification: String) {
+	if notification == "login" {
+		account::login();
+	}
+}
+
 #[handle_url]
 fn handle_url(url: String) -> Result<DeepLink> {
 	let parts = url.split('/').skip(3).collect::<Vec<_>>();
@@ -69,50 +69,102 @@ impl MangaArr for ArrayRef {
 	fn get_manga_list(self) -> Result<Vec<Manga>> {
 		let mut manga = Vec::<Manga>::new();
 		for manga_value in self {
-			let manga_obj = manga_value.as_object()?;
-
-			let manga_id = manga_obj.get_as_string("path_word")?;
-
-			let cover = manga_obj
-				.get_as_string("cover")?
-				.replace(".328x422.jpg", "");
-
-			let title = manga_obj.get_as_string("name")?;
-
-			let artist = manga_obj
-				.get("author")
-				.as_array()?
-				.filter_map(|value| value.as_object().ok())
-				.filter_map(|obj| obj.get("name").as_string().ok())
-				.map(|str_ref| str_ref.read())
-				.collect::<Vec<_>>()
-				.join("、");
-
-			let manga_url = Url::Manga { id: &manga_id }.to_string();
-
-			let status_code = manga_obj.get("status").as_int().unwrap_or(-1);
-			let status = match status_code {
-				0 => MangaStatus::Ongoing,
-				1 | 2 => MangaStatus::Completed,
-				_ => MangaStatus::Unknown,
-			};
-
-			manga.push(Manga {
-				id: manga_id,
-				cover,
-				title,
-				author: artist.clone(),
-				artist,
-				url: manga_url,
-				status,
-				..Default::default()
-			});
+			manga.push(manga_value.as_object()?.get_manga()?);
 		}
 
 		Ok(manga)
 	}
 }
 
+trait MangaObj {
+	fn get_manga(&self) -> Result<Manga>;
+}
+
+impl MangaObj for ObjectRef {
+	fn get_manga(&self) -> Result<Manga> {
+		let manga_id = self.get_as_string("path_word")?;
+
+		let cover = self.get_as_string("cover")?.replace(".328x422.jpg", "");
+
+		let title = self.get_as_string("name")?;
+
+		let artist = self
+			.get("author")
+			.as_array()?
+			.filter_map(|value| value.as_object().ok())
+			.filter_map(|obj| obj.get("name").as_string().ok())
+			.map(|str_ref| str_ref.read())
+			.collect::<Vec<_>>()
+			.join("、");
+
+		let manga_url = Url::Manga { id: &manga_id }.to_string();
+
+		let status_code = self.get("status").as_int().unwrap_or(-1);
+		let status = match status_code {
+			0 => MangaStatus::Ongoing,
+			1 | 2 => MangaStatus::Completed,
+			_ => MangaStatus::Unknown,
+		};
+
+		Ok(Manga {
+			id: manga_id,
+			cover,
+			title,
+			author: artist.clone(),
+			artist,
+			url: manga_url,
+			status,
+			..Default::default()
+		})
+	}
+}
+
+pub trait ApiResponse {
+	/// Returns the `results` of an API response, or the error message it
+	/// carries instead.
+	fn get_results(self) -> Result<ObjectRef>;
+}
+
+impl ApiResponse for ValueRef {
+	fn get_results(self) -> Result<ObjectRef> {
+		let response_obj = self.as_object()?;
+
+		let code = response_obj.get("code").as_int().unwrap_or(200);
+		if code != 200 {
+			let message = response_obj
+				.get_as_string("message")
+				.unwrap_or_else(|_| format!("API error {code}"));
+			return Err(to_aidoku_error(message));
+		}
+
+		response_obj.get("results").as_object()
+	}
+}
+
+pub trait BookshelfResponse {
+	fn get_bookshelf_page(self) -> Result<MangaPageResult>;
+}
+
+impl BookshelfResponse for ValueRef {
+	fn get_bookshelf_page(self) -> Result<MangaPageResult> {
+		let results_obj = self.get_results()?;
+
+		let manga = results_obj
+			.get("list")
+			.as_array()?
+			// each item of the collection wraps the manga in `comic`
+			.map(|value| value.as_object()?.get("comic").as_object()?.get_manga())
+			.collect::<Result<Vec<_>>>()?;
+
+		let total = results_obj.get("total").as_int()?;
+		let limit = results_obj.get("limit").as_int()?;
+		let offset = results_obj.get("offset").as_int()?;
+		let has_more = (offset + limit) < total;
+
+		Ok(MangaPageResult { manga, has_more })
+	}
+}
+
 pub trait Element {
 	fn get_attr(&self, selector: &str, attr: &str) -> String;
 	fn get_text(&self, selector: &str) -> String;
@@ -1,3 +1,4 @@
+use crate::account::Authorized as _;
 use aidoku::{
 	error::Result,
 	helpers::uri::QueryParameters,
@@ -6,6 +7,7 @@ use aidoku::{
 	Filter, FilterType,
 };
 use alloc::{borrow::ToOwned as _, string::ToString};
+use common::zh::Converter;
 use core::fmt::{Display, Formatter, Result as FmtResult};
 use strum_macros::{Display, IntoStaticStr};
 
@@ -336,11 +338,13 @@ impl Url<'_> {
 	}
 
 	fn get(self) -> Request {
-		Request::get(self.to_string()).header(
-			"User-Agent",
-			"Mozilla/5.0 (Macintosh; Intel Mac OS X 14_7_4) \
-			 AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.3 Safari/605.1.15",
-		)
+		Request::get(self.to_string())
+			.header(
+				"User-Agent",
+				"Mozilla/5.0 (Macintosh; Intel Mac OS X 14_7_4) \
+				 AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.3 Safari/605.1.15",
+			)
+			.authorized()
 	}
 }
 
@@ -389,7 +393,7 @@ impl From<(Vec<Filter>, i32)> for Url<'_> {
 
 				FilterType::Title => {
 					let keyword = match filter.value.as_string() {
-						Ok(str_ref) => str_ref.read(),
+						Ok(str_ref) => Converter::new(crate::SITE_SCRIPT).query(&str_ref.read()),
 						Err(_) => continue,
 					};
 					let search = Search::new(page, keyword);
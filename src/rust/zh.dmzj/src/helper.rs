@@ -1,4 +1,12 @@
-use aidoku::{std::net::HttpMethod, std::net::Request, std::String, std::Vec};
+use aidoku::{
+	error::{AidokuError, AidokuErrorKind},
+	prelude::*,
+	std::defaults::defaults_get,
+	std::net::HttpMethod,
+	std::net::Request,
+	std::String,
+	std::Vec,
+};
 
 use prost::bytes::Bytes;
 
@@ -75,34 +83,101 @@ pub fn get(url: &str) -> Request {
 
 const KEY:&str = "MIICeAIBADANBgkqhkiG9w0BAQEFAASCAmIwggJeAgEAAoGBAK8nNR1lTnIfIes6oRWJNj3mB6OssDGx0uGMpgpbVCpf6+VwnuI2stmhZNoQcM417Iz7WqlPzbUmu9R4dEKmLGEEqOhOdVaeh9Xk2IPPjqIu5TbkLZRxkY3dJM1htbz57d/roesJLkZXqssfG5EJauNc+RcABTfLb4IiFjSMlTsnAgMBAAECgYEAiz/pi2hKOJKlvcTL4jpHJGjn8+lL3wZX+LeAHkXDoTjHa47g0knYYQteCbv+YwMeAGupBWiLy5RyyhXFoGNKbbnvftMYK56hH+iqxjtDLnjSDKWnhcB7089sNKaEM9Ilil6uxWMrMMBH9v2PLdYsqMBHqPutKu/SigeGPeiB7VECQQDizVlNv67go99QAIv2n/ga4e0wLizVuaNBXE88AdOnaZ0LOTeniVEqvPtgUk63zbjl0P/pzQzyjitwe6HoCAIpAkEAxbOtnCm1uKEp5HsNaXEJTwE7WQf7PrLD4+BpGtNKkgja6f6F4ld4QZ2TQ6qvsCizSGJrjOpNdjVGJ7bgYMcczwJBALvJWPLmDi7ToFfGTB0EsNHZVKE66kZ/8Stx+ezueke4S556XplqOflQBjbnj2PigwBN/0afT+QZUOBOjWzoDJkCQClzo+oDQMvGVs9GEajS/32mJ3hiWQZrWvEzgzYRqSf3XVcEe7PaXSd8z3y3lACeeACsShqQoc8wGlaHXIJOHTcCQQCZw5127ZGs8ZDTSrogrH73Kw/HvX55wGAeirKYcv28eauveCG7iyFR0PFB/P/EDZnyb+ifvyEFlucPUI0+Y87F";
 
-fn decode(base64: &str) -> Vec<u8> {
-	let key_byte = Base64::decode_vec(KEY).unwrap();
-	let private_key = rsa::RsaPrivateKey::from_pkcs8_der(&key_byte).unwrap();
-	let r = Base64::decode_vec(base64).unwrap();
+/// Why a v4 response couldn't be used.
+#[derive(Debug)]
+pub enum DecodeError {
+	/// The request failed.
+	Request,
+	/// The embedded private key doesn't parse.
+	Key,
+	/// The response isn't valid base64.
+	Base64,
+	/// A block doesn't decrypt with the key.
+	Decrypt,
+	/// The decrypted bytes aren't the expected message.
+	Protobuf(prost::DecodeError),
+	/// The API answered with an error, e.g. for a title it hides.
+	Api(i32),
+	/// The API answered without the data asked for.
+	Empty,
+}
+
+impl DecodeError {
+	/// Whether the V3 API may still have what the V4 API didn't give. Only a
+	/// broken key, which fails every V4 request, is reported instead.
+	pub fn falls_back(&self) -> bool {
+		!matches!(self, DecodeError::Key)
+	}
+}
+
+impl From<DecodeError> for AidokuError {
+	fn from(_: DecodeError) -> Self {
+		AidokuError {
+			reason: AidokuErrorKind::JsonParseError,
+		}
+	}
+}
+
+/// The result of a V4 call, or `None` when the V3 API should be tried instead.
+pub fn v4_or_fallback<T>(result: Result<T, DecodeError>) -> aidoku::error::Result<Option<T>> {
+	match result {
+		Ok(value) => Ok(Some(value)),
+		Err(err) if err.falls_back() => Ok(None),
+		Err(err) => Err(err.into()),
+	}
+}
+
+fn decode(base64: &str) -> Result<Vec<u8>, DecodeError> {
+	let key_byte = Base64::decode_vec(KEY).map_err(|_| DecodeError::Key)?;
+	let private_key =
+		rsa::RsaPrivateKey::from_pkcs8_der(&key_byte).map_err(|_| DecodeError::Key)?;
+	let r = Base64::decode_vec(base64.trim()).map_err(|_| DecodeError::Base64)?;
 	const BLOCK_SIZE: usize = 128;
-	let iter = r.chunks(BLOCK_SIZE);
-
-	let mut rr = Vec::new();
-	for ptr in iter {
-		rr = [
-			rr,
-			private_key
-				.decrypt(rsa::PaddingScheme::PKCS1v15Encrypt, ptr)
-				.unwrap(),
-		]
-		.concat();
+
+	let mut rr = Vec::with_capacity(r.len());
+	for ptr in r.chunks(BLOCK_SIZE) {
+		let block = private_key
+			.decrypt(rsa::PaddingScheme::PKCS1v15Encrypt, ptr)
+			.map_err(|_| DecodeError::Decrypt)?;
+		rr.extend_from_slice(&block);
 	}
-	rr
+	Ok(rr)
+}
+
+pub fn decode_as_comic_detail(base64: &str) -> Result<protobuf::ComicDetailResponse, DecodeError> {
+	prost::Message::decode(Bytes::from(decode(base64)?)).map_err(DecodeError::Protobuf)
 }
 
-pub fn decode_as_comic_detail(
-	base64: &str,
-) -> Result<protobuf::ComicDetailResponse, prost::DecodeError> {
-	prost::Message::decode(Bytes::from(decode(base64)))
+pub fn decode_as_chapter_images(base64: &str) -> Result<protobuf::ResponseDto, DecodeError> {
+	prost::Message::decode(Bytes::from(decode(base64)?)).map_err(DecodeError::Protobuf)
+}
+
+/// The uid the v4 API is queried with when no account is set.
+const ANONYMOUS_UID: &str = "2665531";
+
+fn read_setting(key: &str) -> String {
+	defaults_get(key)
+		.and_then(|v| v.as_string())
+		.map(|v| String::from(v.read().trim()))
+		.unwrap_or_default()
 }
 
-/*
-pub fn decode_as_chapter_images(base64: &str) -> Result<protobuf::ResponseDto, prost::DecodeError> {
-	prost::Message::decode(Bytes::from(decode(base64)))
+/// The uid and token of the account from the settings, if both are set.
+pub fn get_account() -> Option<(String, String)> {
+	let uid = read_setting("uid");
+	let token = read_setting("token");
+	if uid.is_empty() || token.is_empty() {
+		None
+	} else {
+		Some((uid, token))
+	}
+}
+
+/// The query identifying the user to the v4 API. With an account, the API also
+/// lists the chapters it hides from anonymous users in some regions.
+pub fn user_query() -> String {
+	match get_account() {
+		Some((uid, token)) => format!("uid={}&dmzj_token={}", encode_uri(&uid), encode_uri(&token)),
+		None => format!("uid={}", ANONYMOUS_UID),
+	}
 }
-*/
@@ -6,16 +6,19 @@
 use core::ops::Deref;
 
 use aidoku::{
-	error::Result,
+	error::{AidokuError, AidokuErrorKind, Result},
 	prelude::*,
 	std::net::{HttpMethod, Request},
 	std::{json, ArrayRef, String, Vec},
-	Chapter, DeepLink, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
-	MangaViewer, Page,
+	Chapter, DeepLink, Filter, FilterType, Listing, Manga, MangaContentRating, MangaPageResult,
+	MangaStatus, MangaViewer, Page,
 };
+use common::zh::{Converter, Script};
 
 mod helper;
 
+use helper::DecodeError;
+
 const BASE_URL: &str = "https://m.dmzj.com";
 const V3_API_URL: &str = "https://v3api.dmzj.com";
 const V4_API_URL: &str = "https://nnv4api.dmzj.com";
@@ -31,12 +34,21 @@ const FILTER_GENRE: [i32; 42] = [
 	6219, 3328, 3365, 3326, 3325,
 ];
 
+/// The site and its APIs only serve Simplified Chinese.
+const SITE_SCRIPT: Script = Script::Simplified;
+
 const FILTER_STATUS: [i32; 3] = [0, 2309, 2310];
 const FILTER_READER: [i32; 4] = [0, 3262, 3263, 3264];
 const FILTER_TYPE: [i32; 7] = [0, 2304, 2305, 2306, 2307, 2308, 8453];
 
 #[get_manga_list]
 pub fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
+	let mut result = fetch_manga_list(filters, page)?;
+	Converter::new(SITE_SCRIPT).manga_page(&mut result);
+	Ok(result)
+}
+
+fn fetch_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	let mut is_keyword: bool = false;
 	let mut keyword: String = String::new();
 	let mut sort: i32 = 0;
@@ -45,7 +57,7 @@ pub fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult
 	for filter in filters {
 		match filter.kind {
 			FilterType::Title => {
-				let title = filter.value.as_string()?.read();
+				let title = Converter::new(SITE_SCRIPT).query(&filter.value.as_string()?.read());
 				is_keyword = !title.is_empty();
 
 				if is_keyword {
@@ -94,12 +106,13 @@ pub fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult
 			}
 
 			let req = helper::get(&url);
-			let r = req.string().unwrap();
+			let r = req.string().unwrap_or_default();
 			let rr = r.strip_prefix("var g_search_data = ");
 
 			match rr {
 				Some(rr) => {
-					break json::parse(rr.strip_suffix(';').unwrap().as_bytes())?.as_array()?
+					let rr = rr.trim_end().strip_suffix(';').unwrap_or(rr);
+					break json::parse(rr.as_bytes())?.as_array()?;
 				}
 				_ => index += 1,
 			}
@@ -187,13 +200,114 @@ pub fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult
 	})
 }
 
+#[get_manga_listing]
+fn get_manga_listing(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	let mut result = match listing.name.as_str() {
+		"我的订阅" => get_subscriptions(page)?,
+		_ => MangaPageResult::default(),
+	};
+	Converter::new(SITE_SCRIPT).manga_page(&mut result);
+	Ok(result)
+}
+
+/// The titles the account from the settings subscribed to.
+fn get_subscriptions(page: i32) -> Result<MangaPageResult> {
+	let (uid, token) = helper::get_account().ok_or(AidokuError {
+		reason: AidokuErrorKind::DefaultNotFound,
+	})?;
+
+	let url = format!(
+		"{}/UCenter/subscribe?uid={}&sub_type=1&letter=all&page={}&type=0&dmzj_token={}",
+		V3_API_URL,
+		helper::encode_uri(&uid),
+		helper::i32_to_string(page - 1),
+		helper::encode_uri(&token)
+	);
+	let data = helper::get(&url).json()?.as_array()?;
+
+	let mut manga_arr: Vec<Manga> = Vec::new();
+	for it in data {
+		let it = it.as_object()?;
+		manga_arr.push(Manga {
+			id: helper::i32_to_string(it.get("id").as_int()? as i32),
+			cover: it.get("sub_img").as_string()?.read(),
+			title: it.get("name").as_string()?.read(),
+			status: match it.get("status").as_string().map(|s| s.read()).as_deref() {
+				Ok("连载中") => MangaStatus::Ongoing,
+				Ok("已完结") => MangaStatus::Completed,
+				_ => MangaStatus::Unknown,
+			},
+			viewer: MangaViewer::Rtl,
+			..Default::default()
+		});
+	}
+
+	let len = manga_arr.len();
+	Ok(MangaPageResult {
+		manga: manga_arr,
+		has_more: len != 0,
+	})
+}
+
+/// Reads a title from the V4 API.
+fn get_v4_comic_detail(
+	id: &str,
+) -> core::result::Result<helper::protobuf::ComicDetailInfoResponse, DecodeError> {
+	let url = format!(
+		"{}/comic/detail/{}?{}",
+		V4_API_URL,
+		id,
+		helper::user_query()
+	);
+	let body = helper::get(&url)
+		.string()
+		.map_err(|_| DecodeError::Request)?;
+
+	let pb = helper::decode_as_comic_detail(&body)?;
+	if pb.errno != 0 {
+		return Err(DecodeError::Api(pb.errno));
+	}
+	pb.data.ok_or(DecodeError::Empty)
+}
+
+/// Reads the images of a chapter, given as `{manga_id}/{chapter_id}`, from the
+/// V4 API.
+fn get_v4_chapter_images(id: &str) -> core::result::Result<Vec<String>, DecodeError> {
+	let url = format!(
+		"{}/comic/chapter/{}?{}",
+		V4_API_URL,
+		id,
+		helper::user_query()
+	);
+	let body = helper::get(&url)
+		.string()
+		.map_err(|_| DecodeError::Request)?;
+
+	let pb = helper::decode_as_chapter_images(&body)?;
+	if pb.errno != 0 {
+		return Err(DecodeError::Api(pb.errno));
+	}
+	let data = pb.data.ok_or(DecodeError::Empty)?;
+	let images = if data.images.is_empty() {
+		data.low_res_images
+	} else {
+		data.images
+	};
+	if images.is_empty() {
+		return Err(DecodeError::Empty);
+	}
+	Ok(images)
+}
+
 #[get_manga_details]
 fn get_manga_details(id: String) -> Result<Manga> {
-	let url = format!("{}/comic/detail/{}?uid=2665531", V4_API_URL, &id);
+	let mut manga = fetch_manga_details(id)?;
+	Converter::new(SITE_SCRIPT).manga(&mut manga);
+	Ok(manga)
+}
 
-	let pb = helper::decode_as_comic_detail(&helper::get(&url).string()?).unwrap();
-	if pb.errno == 0 {
-		let pb_data = pb.data.unwrap();
+fn fetch_manga_details(id: String) -> Result<Manga> {
+	if let Some(pb_data) = helper::v4_or_fallback(get_v4_comic_detail(&id))? {
 		return Ok(Manga {
 			id: id.clone(),
 			cover: pb_data.cover,
@@ -208,9 +322,9 @@ fn get_manga_details(id: String) -> Result<Manga> {
 			description: pb_data.description,
 			url: format!("{}/info/{}.html", BASE_URL, id),
 			categories: pb_data.types.iter().map(|s| s.tag_name.clone()).collect(),
-			status: match pb_data.status[0].tag_name.as_str() {
-				"连载中" => MangaStatus::Ongoing,
-				"已完结" => MangaStatus::Completed,
+			status: match pb_data.status.first().map(|s| s.tag_name.as_str()) {
+				Some("连载中") => MangaStatus::Ongoing,
+				Some("已完结") => MangaStatus::Completed,
 				_ => MangaStatus::Unknown,
 			},
 			nsfw: MangaContentRating::Safe,
@@ -222,7 +336,7 @@ fn get_manga_details(id: String) -> Result<Manga> {
 			},
 		});
 	} else {
-		// Try V3 api
+		// Fall back to the V3 API
 
 		let url = format!("{}/dynamic/comicinfo/{}.json", API_URL, id);
 
@@ -266,13 +380,10 @@ fn get_manga_details(id: String) -> Result<Manga> {
 fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 	// Try V4 API first
 
-	let url = format!("{}/comic/detail/{}?uid=2665531", V4_API_URL, &id);
-
-	let pb = helper::decode_as_comic_detail(&helper::get(&url).string()?).unwrap();
-
 	let mut chapters = Vec::new();
-	if pb.errno == 0 && !pb.data.as_ref().unwrap().chapters.is_empty() {
-		let pb_data = pb.data.unwrap();
+	if let Some(pb_data) = helper::v4_or_fallback(get_v4_comic_detail(&id))?
+		.filter(|data| !data.chapters.is_empty())
+	{
 		let mut volume = 0;
 		let has_multi_chapter = pb_data.chapters.len() >= 2;
 		for chapter_list in pb_data.chapters {
@@ -296,7 +407,7 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 			}
 		}
 	} else {
-		// Try V3 API
+		// Fall back to the V3 API
 
 		let url = format!("{}/dynamic/comicinfo/{}.json", API_URL, id);
 		let json = helper::get(&url).json()?.as_object()?;
@@ -320,11 +431,28 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 			});
 		}
 	}
+	Converter::new(SITE_SCRIPT).chapters(&mut chapters);
 	Ok(chapters)
 }
 
 #[get_page_list]
 fn get_page_list(_manga_id: String, id: String) -> Result<Vec<Page>> {
+	// Chapters hidden from anonymous users are only readable through the V4 API.
+	if helper::get_account().is_some() {
+		if let Some(images) = helper::v4_or_fallback(get_v4_chapter_images(&id))? {
+			return Ok(images
+				.iter()
+				.enumerate()
+				.map(|(index, url)| Page {
+					index: index as i32,
+					url: helper::encode_uri(url),
+					base64: String::new(),
+					text: String::new(),
+				})
+				.collect());
+		}
+	}
+
 	// Not Tested
 	// Maybe only use the first one.
 	let url = [
@@ -398,23 +526,16 @@ fn get_page_list(_manga_id: String, id: String) -> Result<Vec<Page>> {
 			.replace("http:", "https:")
 			.replace("dmzj1.com", "dmzj.com");
 
-		let thumb_url = {
-			if !id.is_empty() {
-				let initial = image_url
-					.strip_prefix("https://images.dmzj.com/")
-					.unwrap()
-					.get(0..1)
-					.unwrap();
-
-				format!("{}/{}/{}/{}.jpg", IMAGE_SMALL_URL, initial, id, index)
-			} else {
-				String::new()
-			}
-		};
+		let thumb_url = image_url
+			.strip_prefix("https://images.dmzj.com/")
+			.and_then(|path| path.get(0..1))
+			.filter(|_| !id.is_empty())
+			.map(|initial| format!("{}/{}/{}/{}.jpg", IMAGE_SMALL_URL, initial, id, index))
+			.unwrap_or_default();
 
 		pages.push(Page {
 			index: index as i32,
-			url: helper::encode_uri(match thumb {
+			url: helper::encode_uri(match thumb && !thumb_url.is_empty() {
 				true => &thumb_url,
 				false => &image_url,
 			}),
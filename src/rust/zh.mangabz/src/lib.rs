@@ -7,9 +7,10 @@ use aidoku::{
 	std::{net::Request, String, Vec},
 	Chapter, DeepLink, Filter, Manga, MangaPageResult, Page,
 };
+use common::zh::Converter;
 
 mod parser;
-use parser::{get_filtered_url, request_get, BASE_URL, USER_AGENT};
+use parser::{get_filtered_url, request_get, BASE_URL, SITE_SCRIPT, USER_AGENT};
 
 extern crate alloc;
 use alloc::string::ToString;
@@ -19,7 +20,9 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	let url = get_filtered_url(filters, page);
 	let html = request_get(url).html()?;
 
-	parser::get_manga_list(html)
+	let mut result = parser::get_manga_list(html)?;
+	Converter::new(SITE_SCRIPT).manga_page(&mut result);
+	Ok(result)
 }
 
 #[get_manga_details]
@@ -27,7 +30,9 @@ fn get_manga_details(id: String) -> Result<Manga> {
 	let url = format!("{}{}bz/", BASE_URL, id);
 	let html = request_get(url).html()?;
 
-	parser::get_manga_details(html, id)
+	let mut manga = parser::get_manga_details(html, id)?;
+	Converter::new(SITE_SCRIPT).manga(&mut manga);
+	Ok(manga)
 }
 
 #[get_chapter_list]
@@ -35,7 +40,9 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 	let url = format!("{}{}bz/", BASE_URL, id);
 	let html = request_get(url).html()?;
 
-	parser::get_chapter_list(html)
+	let mut chapters = parser::get_chapter_list(html)?;
+	Converter::new(SITE_SCRIPT).chapters(&mut chapters);
+	Ok(chapters)
 }
 
 #[get_page_list]
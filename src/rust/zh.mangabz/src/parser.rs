@@ -8,9 +8,15 @@ use aidoku::{
 
 extern crate alloc;
 use alloc::string::ToString;
+use common::{
+	packer,
+	zh::{Converter, Script},
+};
 
 pub const BASE_URL: &str = "https://mangabz.com/";
 pub const USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 13_3_1) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/113.0.0.0 Safari/537.36";
+/// The site shows everything in Traditional Chinese.
+pub const SITE_SCRIPT: Script = Script::Traditional;
 const GENRE: [u8; 10] = [0, 31, 26, 1, 2, 25, 11, 17, 15, 34];
 const SORT: [u8; 2] = [10, 2];
 
@@ -48,7 +54,8 @@ pub fn get_filtered_url(filters: Vec<Filter>, page: i32) -> String {
 			FilterType::Title => {
 				if let Ok(filter_value) = filter.value.as_string() {
 					is_searching = true;
-					query.push("title", Some(filter_value.read().as_str()));
+					let title = Converter::new(SITE_SCRIPT).query(&filter_value.read());
+					query.push("title", Some(title.as_str()));
 				}
 			}
 			FilterType::Select => {
@@ -227,7 +234,7 @@ pub fn get_page_list(url: String) -> Result<Vec<Page>> {
 
 	loop {
 		let content = request_get(format!("{}{}", url, page)).string()?;
-		let urls = decode(content);
+		let urls = decode(content)?;
 		for url in urls.clone() {
 			if url == last_url {
 				break;
@@ -242,7 +249,7 @@ pub fn get_page_list(url: String) -> Result<Vec<Page>> {
 			});
 			page += 1;
 		}
-		if urls.len() == 1 {
+		if urls.len() <= 1 {
 			break;
 		}
 	}
@@ -250,76 +257,29 @@ pub fn get_page_list(url: String) -> Result<Vec<Page>> {
 	Ok(pages)
 }
 
-fn decode(encoded: String) -> Vec<String> {
-	let mut urls: Vec<String> = Vec::new();
-
-	let packed = encoded
-		.substring_after("return p;}")
-		.expect("packed")
-		.to_string();
-
-	let k: Vec<&str> = packed
-		.substring_after(",\'")
-		.expect("k")
-		.substring_before("\'.")
-		.expect("k")
-		.split('|')
-		.collect();
-
-	let chapter = decoded_with_k(
-		packed
-			.substring_after("=\"")
-			.expect("base")
-			.substring_before("\";")
-			.expect("base")
-			.to_string(),
-		k.clone(),
-	);
-	let query = decoded_with_k(
-		packed
-			.substring_before_last("\\\'")
-			.expect("query")
-			.substring_after_last("\\\'")
-			.expect("query")
-			.to_string(),
-		k.clone(),
-	);
-
-	let pages: Vec<&str> = packed
-		.substring_after("=[")
-		.expect("pages")
-		.substring_before("];")
-		.expect("pages")
+/// Reads the image urls out of a `chapterimage.ashx` response, a packed
+/// script that unpacks to
+/// `var pix="<base>";var pvalue=["<file>",...];...pvalue[i]=pix+pvalue[i]+'<query>'`.
+fn decode(encoded: String) -> Result<Vec<String>> {
+	let script = packer::unpack(&encoded)?;
+
+	let base = script
+		.substring_after("pix=\"")
+		.and_then(|v| v.substring_before("\""))
+		.unwrap_or_default();
+	let query = script
+		.substring_after("pvalue[i]+'")
+		.and_then(|v| v.substring_before("'"))
+		.unwrap_or_default();
+	let files = script
+		.substring_after("pvalue=[")
+		.and_then(|v| v.substring_before("]"))
+		.unwrap_or_default();
+
+	Ok(files
 		.split(',')
-		.collect();
-	for item in pages {
-		let page = decoded_with_k(item.replace('\"', "").to_string(), k.clone());
-		urls.push(format!("{}{}{}", chapter, page, query));
-	}
-
-	urls
-}
-
-fn decoded_with_k(encoded: String, k: Vec<&str>) -> String {
-	let mut decoded = String::new();
-
-	for char in encoded.chars() {
-		let mut str;
-
-		if char.is_digit(36) {
-			str = if char.is_ascii_uppercase() {
-				k[(char as usize) - 29].to_string()
-			} else {
-				k[char.to_digit(36).expect("base 36") as usize].to_string()
-			};
-			if str.is_empty() {
-				str = char.to_string();
-			}
-		} else {
-			str = char.to_string();
-		}
-		decoded.push_str(str.as_str());
-	}
-
-	decoded
+		.map(|file| file.trim().trim_matches('"'))
+		.filter(|file| !file.is_empty())
+		.map(|file| format!("{}{}{}", base, file, query))
+		.collect())
 }
@@ -1,7 +1,6 @@
 #![no_std]
 extern crate alloc;
 
-mod decoder;
 mod helper;
 mod parser;
 
@@ -10,6 +9,7 @@ use aidoku::{
 	std::{defaults::defaults_get, net::HttpMethod, net::Request, String, Vec},
 	Chapter, Filter, Manga, MangaPageResult, Page,
 };
+use common::zh::Script;
 
 const USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/138.0.0.0 Safari/537.36";
 
@@ -24,6 +24,14 @@ fn get_base_url() -> &'static str {
 	}
 }
 
+/// Each host only finds titles searched in its own script.
+fn get_site_script() -> Script {
+	match get_base_url() {
+		"https://tw.manhuagui.com" => Script::Traditional,
+		_ => Script::Simplified,
+	}
+}
+
 #[get_manga_list]
 fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	let mut url = String::new();
@@ -1,12 +1,11 @@
-use crate::{
-	decoder::{decompress_from_base64, Decoder},
-	helper::{self, encode_uri},
-};
+use crate::helper::{self, encode_uri};
+use common::{lzstring::decompress_string_from_base64, packer, zh};
 
 use aidoku::{
 	error::Result,
 	prelude::*,
 	std::html::Node,
+	std::json,
 	std::Vec,
 	std::{net::HttpMethod, net::Request, String},
 	Chapter, Filter, FilterType, Manga, MangaContentRating, MangaPageResult, MangaStatus,
@@ -247,9 +246,7 @@ pub fn get_chapter_list(html: Node) -> Result<Vec<Chapter>> {
 	let hidden = html.html().read().contains("__VIEWSTATE");
 	if hidden {
 		let compressed = html.select("#__VIEWSTATE").attr("value").read();
-		let decompressed =
-			String::from_utf16(&decompress_from_base64(compressed.as_str()).unwrap_or_default())
-				.unwrap_or_default();
+		let decompressed = decompress_string_from_base64(compressed.as_str()).unwrap_or_default();
 		div = Node::new_fragment(decompressed.as_bytes()).unwrap_or(div);
 	}
 
@@ -339,14 +336,26 @@ pub fn get_page_list(base_url: String) -> Result<Vec<Page>> {
 		.header("User-Agent", crate::USER_AGENT)
 		.header("Accept-Language", "zh-CN,zh;q=0.9,en-US;q=0.8,en;q=0.7")
 		.header("Cookie", "device_view=pc");
-	let html = request.html()?;
-
-	let decoder = Decoder::new(html.html().read());
-	let (path, pages_str) = decoder.decode();
+	let document = request.string()?;
 
-	for (index, str) in pages_str.into_iter().enumerate() {
-		let encoded_path = helper::encode_uri(&path);
-		let url = format!("https://i.hamreus.com{}{}", encoded_path, str);
+	// the reader data is set by a packed script run through an escaped eval
+	let script = match document.find("window[\"\\x65\\x76\\x61\\x6c\"]") {
+		Some(start) => &document[start..],
+		None => document.as_str(),
+	};
+	let script = packer::unpack(script)?;
+	let data = script
+		.split_once(".imgData(")
+		.and_then(|(_, rest)| rest.split_once(").preInit()"))
+		.map(|(data, _)| data)
+		.unwrap_or_default();
+	let data = json::parse(data.as_bytes())?.as_object()?;
+
+	let path = data.get("path").as_string()?.read();
+	let encoded_path = helper::encode_uri(&path);
+	for (index, file) in data.get("files").as_array()?.enumerate() {
+		let file = file.as_string()?.read();
+		let url = format!("https://i.hamreus.com{}{}", encoded_path, file);
 		pages.push(Page {
 			index: index as i32,
 			url,
@@ -375,8 +384,8 @@ pub fn get_filtered_url(filters: Vec<Filter>, page: i32, url: &mut String) {
 		match filter.kind {
 			FilterType::Title => {
 				if let Ok(filter_value) = filter.value.as_string() {
-					search_string
-						.push_str(encode_uri(&filter_value.read().to_lowercase()).as_str());
+					let keyword = zh::convert(&filter_value.read(), crate::get_site_script());
+					search_string.push_str(encode_uri(&keyword.to_lowercase()).as_str());
 					is_searching = true;
 				}
 			}
@@ -11,6 +11,7 @@ use aidoku::{
 };
 
 use alloc::{string::ToString, vec};
+use common::zh::{Converter, Script};
 mod helper;
 
 const FILTER_GENRE: [i32; 30] = [
@@ -22,6 +23,9 @@ const FILTER_SORT: [i32; 3] = [0, 1, 2];
 
 const API_URL: &str = "http://mangaapi.manhuaren.com";
 
+/// The API only serves Simplified Chinese.
+const SITE_SCRIPT: Script = Script::Simplified;
+
 #[modify_image_request]
 fn modify_image_request(request: Request) {
 	request
@@ -41,7 +45,7 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 	for filter in filters {
 		match filter.kind {
 			FilterType::Title => {
-				query = filter.value.as_string()?.read();
+				query = Converter::new(SITE_SCRIPT).query(&filter.value.as_string()?.read());
 			}
 			FilterType::Select => {
 				let index = filter.value.as_int()? as usize;
@@ -67,7 +71,7 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 		}
 	}
 
-	if query.is_empty() {
+	let mut result = if query.is_empty() {
 		get_manga_list_by_filter(
 			ListFilter {
 				status,
@@ -75,10 +79,12 @@ fn get_manga_list(filters: Vec<Filter>, page: i32) -> Result<MangaPageResult> {
 				sort,
 			},
 			page,
-		)
+		)?
 	} else {
-		get_manga_list_by_query(query, page)
-	}
+		get_manga_list_by_query(query, page)?
+	};
+	Converter::new(SITE_SCRIPT).manga_page(&mut result);
+	Ok(result)
 }
 
 #[get_manga_details]
@@ -111,7 +117,7 @@ fn get_manga_details(id: String) -> Result<Manga> {
 		helper::stringref_unwrap_or_empty(manga.get("shareIcon").as_string()),
 	);
 
-	Ok(Manga {
+	let mut result = Manga {
 		id: match manga.get("mangaId").as_int() {
 			Ok(str) => str.to_string(),
 			Err(_) => id,
@@ -130,7 +136,9 @@ fn get_manga_details(id: String) -> Result<Manga> {
 		},
 		nsfw: MangaContentRating::Safe,
 		viewer: MangaViewer::Vertical,
-	})
+	};
+	Converter::new(SITE_SCRIPT).manga(&mut result);
+	Ok(result)
 }
 
 #[get_chapter_list]
@@ -152,6 +160,7 @@ fn get_chapter_list(id: String) -> Result<Vec<Chapter>> {
 	chapter_arr.append(&mut parse_chapters(&manga, "mangaEpisode"));
 	chapter_arr.append(&mut parse_chapters(&manga, "mangaWords"));
 	chapter_arr.append(&mut parse_chapters(&manga, "mangaRolls"));
+	Converter::new(SITE_SCRIPT).chapters(&mut chapter_arr);
 
 	Ok(chapter_arr)
 }